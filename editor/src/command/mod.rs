@@ -480,6 +480,7 @@ impl<F: EntityGetter> SetPropertyCommand<F> {
                                 }
                                 SetFieldError::InvalidValue {
                                     field_type_name,
+                                    value_type_name,
                                     value,
                                 } => {
                                     err!(
@@ -487,7 +488,7 @@ impl<F: EntityGetter> SetPropertyCommand<F> {
                                     Target property: {}. Value: {}!",
                                         self.path,
                                         field_type_name,
-                                        value.type_name()
+                                        value_type_name
                                     );
 
                                     value
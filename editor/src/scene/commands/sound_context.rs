@@ -66,6 +66,8 @@ macro_rules! define_sound_context_command {
 define_sound_context_command! {
     SetDistanceModelCommand("Set Distance Model", DistanceModel, distance_model, set_distance_model);
     SetRendererCommand("Set Renderer", Renderer, renderer, set_renderer);
+    SetSpeedOfSoundCommand("Set Speed Of Sound", f32, speed_of_sound, set_speed_of_sound);
+    SetMasterGainCommand("Set Master Gain", f32, master_gain, set_master_gain);
 }
 
 #[derive(Debug)]
@@ -102,3 +104,137 @@ impl CommandTrait for SetHrtfRendererHrirSphereResource {
         self.swap(&mut context.scene.graph.sound_context);
     }
 }
+
+#[derive(Debug)]
+pub struct SetSoundContextPausedCommand {
+    paused: bool,
+}
+
+impl SetSoundContextPausedCommand {
+    pub fn new(paused: bool) -> Self {
+        Self { paused }
+    }
+
+    fn swap(&mut self, sound_context: &mut SoundContext) {
+        let mut state = sound_context.state();
+        let old = state.is_paused();
+        state.pause(self.paused);
+        self.paused = old;
+    }
+}
+
+impl CommandTrait for SetSoundContextPausedCommand {
+    fn name(&mut self, _context: &dyn CommandContext) -> String {
+        "Pause/Resume Sound Context".to_owned()
+    }
+
+    fn execute(&mut self, context: &mut dyn CommandContext) {
+        let context = context.get_mut::<GameSceneContext>();
+        self.swap(&mut context.scene.graph.sound_context);
+    }
+
+    fn revert(&mut self, context: &mut dyn CommandContext) {
+        let context = context.get_mut::<GameSceneContext>();
+        self.swap(&mut context.scene.graph.sound_context);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        command::{CommandGroup, CommandTrait},
+        fyrox::{
+            asset::{io::FsResourceIo, manager::ResourceManager},
+            core::pool::Handle,
+            engine::SerializationContext,
+            scene::{
+                sound::{context::SoundContext, DistanceModel, HrtfRenderer, Renderer},
+                Scene,
+            },
+        },
+        message::MessageSender,
+        scene::{
+            clipboard::Clipboard,
+            commands::{
+                sound_context::{
+                    SetDistanceModelCommand, SetMasterGainCommand, SetRendererCommand,
+                    SetSoundContextPausedCommand,
+                },
+                GameSceneContext,
+            },
+            Selection,
+        },
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn test_set_master_gain_symmetry() {
+        let mut sound_context = SoundContext::default();
+        assert_eq!(sound_context.state().master_gain(), 1.0);
+
+        let mut command = SetMasterGainCommand::new(0.25);
+
+        // Execute.
+        command.swap(&mut sound_context);
+        assert_eq!(sound_context.state().master_gain(), 0.25);
+
+        // Revert.
+        command.swap(&mut sound_context);
+        assert_eq!(sound_context.state().master_gain(), 1.0);
+
+        // Execute again.
+        command.swap(&mut sound_context);
+        assert_eq!(sound_context.state().master_gain(), 0.25);
+    }
+
+    #[test]
+    fn test_set_paused_symmetry() {
+        let mut sound_context = SoundContext::default();
+        assert!(!sound_context.state().is_paused());
+
+        let mut command = SetSoundContextPausedCommand::new(true);
+
+        // Execute.
+        command.swap(&mut sound_context);
+        assert!(sound_context.state().is_paused());
+
+        // Revert.
+        command.swap(&mut sound_context);
+        assert!(!sound_context.state().is_paused());
+    }
+
+    #[test]
+    fn test_sound_context_command_group() {
+        let mut scene = Scene::new();
+        let mut group =
+            CommandGroup::from(vec![]).with_custom_name("Set Distance Model And Renderer");
+        group.push(SetDistanceModelCommand::new(DistanceModel::None));
+        group.push(SetRendererCommand::new(Renderer::HrtfRenderer(
+            HrtfRenderer::default(),
+        )));
+
+        GameSceneContext::exec(
+            &mut Selection::default(),
+            &mut scene,
+            &mut Handle::NONE,
+            &mut Clipboard::default(),
+            MessageSender::default(),
+            ResourceManager::new(Arc::new(FsResourceIo), Default::default()),
+            Arc::new(SerializationContext::new()),
+            |ctx| {
+                group.execute(ctx);
+                {
+                    let state = ctx.scene.graph.sound_context.state();
+                    assert_eq!(state.distance_model(), DistanceModel::None);
+                    assert!(matches!(state.renderer_ref(), Renderer::HrtfRenderer(_)));
+                }
+
+                // A single revert must restore both values.
+                group.revert(ctx);
+                let state = ctx.scene.graph.sound_context.state();
+                assert_eq!(state.distance_model(), DistanceModel::InverseDistance);
+                assert!(matches!(state.renderer_ref(), Renderer::Default));
+            },
+        );
+    }
+}
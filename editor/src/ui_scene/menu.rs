@@ -21,14 +21,14 @@
 use crate::{
     fyrox::graph::{BaseSceneGraph, SceneGraphNode},
     fyrox::{
-        core::pool::Handle,
+        core::{log::Log, pool::Handle},
         gui::{
             menu::MenuItemMessage,
             message::UiMessage,
             popup::{Placement, PopupBuilder, PopupMessage},
             stack_panel::StackPanelBuilder,
             widget::{WidgetBuilder, WidgetMessage},
-            BuildContext, RcUiNodeHandle, UiNode,
+            BuildContext, RcUiNodeHandle, UiNode, UserInterface,
         },
     },
     menu::{create_menu_item, create_menu_item_shortcut, ui::UiMenu},
@@ -79,6 +79,28 @@ fn resource_path_of_first_selected_node(
     None
 }
 
+/// Checks whether the first selected widget is the current root of the user interface.
+fn is_root_selected(editor_selection: &Selection, ui: &UserInterface) -> bool {
+    editor_selection
+        .as_ui()
+        .and_then(|selection| selection.widgets.first())
+        .is_some_and(|first| *first == ui.root())
+}
+
+/// Creates a message that opens the asset at the given path. Scenes and UI prefabs are loaded
+/// in the editor, any other asset is shown in the asset browser.
+fn open_asset_message(path: PathBuf) -> Message {
+    if utils::is_native_scene(&path) {
+        Message::LoadScene(path)
+    } else {
+        Log::info(format!(
+            "{} is not a scene, it will be shown in the asset browser.",
+            path.display()
+        ));
+        Message::ShowInAssetBrowser(path)
+    }
+}
+
 impl WidgetContextMenu {
     pub fn new(
         widget_constructors_container: &WidgetConstructorContainer,
@@ -183,7 +205,11 @@ impl WidgetContextMenu {
                     }
                 } else if message.destination() == self.make_root {
                     if let Some(selection) = editor_selection.as_ui() {
-                        if let Some(first) = selection.widgets.first() {
+                        if let Some(first) = selection
+                            .widgets
+                            .first()
+                            .filter(|first| **first != ui_scene.ui.root())
+                        {
                             sender.do_command(SetUiRootCommand {
                                 root: *first,
                                 link_scheme: Default::default(),
@@ -196,9 +222,7 @@ impl WidgetContextMenu {
                         ui_scene,
                         &engine.resource_manager,
                     ) {
-                        if utils::is_native_scene(&path) {
-                            sender.send(Message::LoadScene(path));
-                        }
+                        sender.send(open_asset_message(path));
                     }
                 }
             } else if let Some(PopupMessage::Placement(Placement::Cursor(target))) = message.data()
@@ -211,8 +235,59 @@ impl WidgetContextMenu {
                         self.paste,
                         WidgetMessage::Enabled(!ui_scene.clipboard.is_empty()),
                     );
+
+                    // The root widget cannot be made root again.
+                    engine.user_interfaces.first_mut().send(
+                        self.make_root,
+                        WidgetMessage::Enabled(!is_root_selected(editor_selection, &ui_scene.ui)),
+                    );
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        fyrox::{
+            core::algebra::Vector2,
+            gui::{button::ButtonBuilder, widget::WidgetBuilder, UserInterface},
+        },
+        scene::Selection,
+        ui_scene::{
+            menu::{is_root_selected, open_asset_message},
+            selection::UiSelection,
+        },
+        Message,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_open_non_scene_asset() {
+        let path = PathBuf::from("test_data/texture.png");
+        match open_asset_message(path.clone()) {
+            Message::ShowInAssetBrowser(shown) => assert_eq!(shown, path),
+            Message::LoadScene(_) => panic!("a non-scene asset must not be loaded as a scene"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_make_root_disabled_for_root() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let button = ButtonBuilder::new(WidgetBuilder::new()).build(&mut ui.build_ctx());
+
+        let root_selection = Selection::new(UiSelection {
+            widgets: vec![ui.root()],
+        });
+        assert!(is_root_selected(&root_selection, &ui));
+
+        let button_selection = Selection::new(UiSelection {
+            widgets: vec![button],
+        });
+        assert!(!is_root_selected(&button_selection, &ui));
+
+        assert!(!is_root_selected(&Selection::new_empty(), &ui));
+    }
+}
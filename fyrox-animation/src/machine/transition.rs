@@ -86,6 +86,10 @@ macro_rules! define_two_args_node {
                 ""
             }
 
+            fn type_source_path(&self) -> &'static str {
+                file!()
+            }
+
             fn assembly_name(&self) -> &'static str {
                 env!("CARGO_PKG_NAME")
             }
@@ -99,6 +103,7 @@ macro_rules! define_two_args_node {
                     {
                         static METADATA: FieldMetadata = FieldMetadata {
                             name: "Lhs",
+                            aliases: &[],
                             display_name: "Lhs",
                             tag: "",
                             read_only: false,
@@ -118,6 +123,7 @@ macro_rules! define_two_args_node {
                     {
                         static METADATA: FieldMetadata = FieldMetadata {
                             name: "Rhs",
+                            aliases: &[],
                             display_name: "Rhs",
                             tag: "",
                             read_only: false,
@@ -141,6 +147,7 @@ macro_rules! define_two_args_node {
                     {
                         static METADATA: FieldMetadata = FieldMetadata {
                             name: "Lhs",
+                            aliases: &[],
                             display_name: "Lhs",
                             tag: "",
                             read_only: false,
@@ -160,6 +167,7 @@ macro_rules! define_two_args_node {
                     {
                         static METADATA: FieldMetadata = FieldMetadata {
                             name: "Rhs",
+                            aliases: &[],
                             display_name: "Rhs",
                             tag: "",
                             read_only: false,
@@ -269,6 +277,10 @@ impl<T: EntityId> Reflect for NotNode<T> {
         ""
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
@@ -281,6 +293,7 @@ impl<T: EntityId> Reflect for NotNode<T> {
         func(&[{
             static METADATA: FieldMetadata = FieldMetadata {
                 name: "Lhs",
+                aliases: &[],
                 display_name: "Lhs",
                 tag: "",
                 read_only: false,
@@ -303,6 +316,7 @@ impl<T: EntityId> Reflect for NotNode<T> {
         func(&mut [{
             static METADATA: FieldMetadata = FieldMetadata {
                 name: "Lhs",
+                aliases: &[],
                 display_name: "Lhs",
                 tag: "",
                 read_only: false,
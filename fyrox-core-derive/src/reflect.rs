@@ -28,7 +28,7 @@ use convert_case::{Case, Casing};
 use darling::ast;
 use proc_macro2::TokenStream as TokenStream2;
 use prop::Property;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::Index;
 
 pub fn impl_reflect(ty_args: &args::TypeArgs) -> TokenStream2 {
@@ -114,6 +114,8 @@ fn quote_field_prop(
 
     let tag = field.tag.clone().unwrap_or_default();
 
+    let aliases = &field.alias;
+
     let read_only = field.read_only;
 
     let immutable_collection = field.immutable_collection;
@@ -128,6 +130,7 @@ fn quote_field_prop(
         {
             static METADATA: FieldMetadata = FieldMetadata {
                 name: #prop_key_name,
+                aliases: &[#(#aliases),*],
                 display_name: #display_name,
                 tag: #tag,
                 doc: #doc,
@@ -163,6 +166,10 @@ fn impl_reflect_struct(ty_args: &args::TypeArgs, field_args: &args::Fields) -> T
     let fields = fields.collect::<Vec<_>>();
     let field_muts = field_muts.collect::<Vec<_>>();
 
+    if ty_args.transparent {
+        return self::impl_reflect_transparent(ty_args, &fields[0], &field_muts[0]);
+    }
+
     let metadata_ref = gen_fields_metadata_body(&props, &fields, field_args, false);
     let metadata_mut = gen_fields_metadata_body(&props, &field_muts, field_args, true);
 
@@ -179,6 +186,79 @@ fn impl_reflect_struct(ty_args: &args::TypeArgs, field_args: &args::Fields) -> T
     )
 }
 
+/// New-type struct that delegates all the field-related methods to its only field.
+fn impl_reflect_transparent(
+    ty_args: &args::TypeArgs,
+    field: &TokenStream2,
+    field_mut: &TokenStream2,
+) -> TokenStream2 {
+    let extra = quote! {
+        fn field(&self, name: &str, func: &mut dyn FnMut(Option<&dyn Reflect>)) {
+            Reflect::field(#field, name, func)
+        }
+
+        fn field_mut(&mut self, name: &str, func: &mut dyn FnMut(Option<&mut dyn Reflect>)) {
+            Reflect::field_mut(#field_mut, name, func)
+        }
+
+        fn as_array(&self, func: &mut dyn FnMut(Option<&dyn ReflectArray>)) {
+            Reflect::as_array(#field, func)
+        }
+
+        fn as_array_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectArray>)) {
+            Reflect::as_array_mut(#field_mut, func)
+        }
+
+        fn as_list(&self, func: &mut dyn FnMut(Option<&dyn ReflectList>)) {
+            Reflect::as_list(#field, func)
+        }
+
+        fn as_list_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectList>)) {
+            Reflect::as_list_mut(#field_mut, func)
+        }
+
+        fn as_hash_map(&self, func: &mut dyn FnMut(Option<&dyn ReflectHashMap>)) {
+            Reflect::as_hash_map(#field, func)
+        }
+
+        fn as_hash_map_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectHashMap>)) {
+            Reflect::as_hash_map_mut(#field_mut, func)
+        }
+
+        fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+            Reflect::as_enum(#field, func)
+        }
+
+        fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+            Reflect::as_enum_mut(#field_mut, func)
+        }
+
+        fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+            Reflect::as_option(#field, func)
+        }
+
+        fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+            Reflect::as_option_mut(#field_mut, func)
+        }
+
+        fn field_count(&self) -> usize {
+            Reflect::field_count(#field)
+        }
+    };
+
+    self::gen_impl_ext(
+        ty_args,
+        None,
+        quote! {
+            Reflect::fields_ref(#field, func)
+        },
+        quote! {
+            Reflect::fields_mut(#field_mut, func)
+        },
+        extra,
+    )
+}
+
 fn struct_set_field_body(ty_args: &args::TypeArgs) -> Option<TokenStream2> {
     let props = prop::props(ty_args)
         .filter(|p| p.field.setter.is_some())
@@ -188,7 +268,11 @@ fn struct_set_field_body(ty_args: &args::TypeArgs) -> Option<TokenStream2> {
         return None;
     }
 
-    let prop_values = props.iter().map(|p| &p.value);
+    let prop_values = props.iter().map(|p| {
+        let value = &p.value;
+        let aliases = &p.field.alias;
+        quote! { #value #(| #aliases)* }
+    });
 
     let set_fields = props.iter().map(|p| {
         let setter = p.field.setter.as_ref().unwrap();
@@ -201,7 +285,7 @@ fn struct_set_field_body(ty_args: &args::TypeArgs) -> Option<TokenStream2> {
                 Err(current) => {
                     let mut field_type_name = "(none)";
                     self.field_mut(name, &mut |field| { field_type_name = field.unwrap().type_name() });
-                    Err(SetFieldError::InvalidValue{field_type_name, value: current})
+                    Err(SetFieldError::InvalidValue{field_type_name, value_type_name: current.type_name(), value: current})
                 }
             })
         }}
@@ -219,6 +303,7 @@ fn struct_set_field_body(ty_args: &args::TypeArgs) -> Option<TokenStream2> {
                     match field {
                         Some(f) => func(f.set(value).map_err(|value| SetFieldError::InvalidValue {
                             field_type_name: f.type_name(),
+                            value_type_name: value.type_name(),
                             value,
                         })),
                         None => func(Err(SetFieldError::NoSuchField {
@@ -293,27 +378,182 @@ fn impl_reflect_enum(ty_args: &args::TypeArgs, variant_args: &[args::VariantArgs
         }
     };
 
-    self::gen_impl(
+    let extra = quote! {
+        fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+            func(Some(self))
+        }
+
+        fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+            func(Some(self))
+        }
+    };
+
+    let reflect_impl = self::gen_impl_ext(
         ty_args,
         None,
         fields_metadata_ref_body,
         fields_metadata_mut_body,
-    )
+        extra,
+    );
+
+    let reflect_enum_impl = self::impl_reflect_enum_trait(ty_args, variant_args);
+
+    quote! {
+        #reflect_impl
+
+        #reflect_enum_impl
+    }
+}
+
+/// `impl ReflectEnum for .. { .. }`
+fn impl_reflect_enum_trait(
+    ty_args: &args::TypeArgs,
+    variant_args: &[args::VariantArgs],
+) -> TokenStream2 {
+    let ty_ident = &ty_args.ident;
+    let generics = ty_args.impl_generics();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let variant_names = variant_args
+        .iter()
+        .map(|v| v.ident.to_string())
+        .collect::<Vec<_>>();
+
+    let patterns = variant_args
+        .iter()
+        .map(|v| {
+            let variant_ident = &v.ident;
+            match v.fields.style {
+                ast::Style::Struct => quote!(Self::#variant_ident { .. }),
+                ast::Style::Tuple => quote!(Self::#variant_ident(..)),
+                ast::Style::Unit => quote!(Self::#variant_ident),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let indices = 0..variant_args.len();
+
+    let probe_items = self::probe_default_items();
+
+    // Fields of the new variant are created using "autoref specialization" - fields that implement
+    // `Default` get their default values, otherwise the variant cannot be created.
+    let constructors = variant_args.iter().map(|v| {
+        let variant_ident = &v.ident;
+        let defaults = v.fields.iter().map(|f| {
+            let probe = self::probe_default(&f.ty);
+            quote!(#probe?)
+        });
+        match v.fields.style {
+            ast::Style::Struct => {
+                let idents = v.fields.iter().map(|f| f.ident.as_ref().unwrap());
+                quote!((|| Some(Self::#variant_ident { #( #idents: #defaults, )* }))())
+            }
+            ast::Style::Tuple => quote!((|| Some(Self::#variant_ident( #( #defaults, )* )))()),
+            ast::Style::Unit => quote!(Some(Self::#variant_ident)),
+        }
+    });
+
+    quote! {
+        #[allow(warnings)]
+        impl #impl_generics ReflectEnum for #ty_ident #ty_generics #where_clause {
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #( #patterns => #variant_names, )*
+                }
+            }
+
+            fn variant_index(&self) -> usize {
+                match *self {
+                    #( #patterns => #indices, )*
+                }
+            }
+
+            fn variant_names(&self) -> &'static [&'static str] {
+                &[#( #variant_names ),*]
+            }
+
+            fn set_variant(&mut self, name: &str) -> bool {
+                #probe_items
+
+                if self.variant_name() == name {
+                    return true;
+                }
+
+                let new_value: Option<Self> = match name {
+                    #( #variant_names => #constructors, )*
+                    _ => None,
+                };
+
+                match new_value {
+                    Some(new_value) => {
+                        *self = new_value;
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+/// Items, that are needed for [`probe_default`] to work. Must be emitted into the same scope.
+fn probe_default_items() -> TokenStream2 {
+    quote! {
+        struct DefaultProbe<T>(::core::marker::PhantomData<T>);
+
+        trait ProbeDefault<T> {
+            fn probe_default(&self) -> Option<T>;
+        }
+
+        impl<T: Default> ProbeDefault<T> for &DefaultProbe<T> {
+            fn probe_default(&self) -> Option<T> {
+                Some(T::default())
+            }
+        }
+
+        trait ProbeNone<T> {
+            fn probe_default(&self) -> Option<T>;
+        }
+
+        impl<T> ProbeNone<T> for DefaultProbe<T> {
+            fn probe_default(&self) -> Option<T> {
+                None
+            }
+        }
+    }
+}
+
+/// An expression of `Option<#ty>` type, that is `Some(Default::default())` if the type implements
+/// `Default` and `None` otherwise ("autoref specialization").
+fn probe_default(ty: &impl ToTokens) -> TokenStream2 {
+    quote!((&&DefaultProbe::<#ty>(::core::marker::PhantomData)).probe_default())
 }
 
-#[allow(clippy::too_many_arguments)]
 fn gen_impl(
     ty_args: &args::TypeArgs,
     set_field: Option<TokenStream2>,
     metadata_ref: TokenStream2,
     metadata_mut: TokenStream2,
+) -> TokenStream2 {
+    self::gen_impl_ext(ty_args, set_field, metadata_ref, metadata_mut, quote!())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn gen_impl_ext(
+    ty_args: &args::TypeArgs,
+    set_field: Option<TokenStream2>,
+    metadata_ref: TokenStream2,
+    metadata_mut: TokenStream2,
+    extra: TokenStream2,
 ) -> TokenStream2 {
     let ty_ident = &ty_args.ident;
     let generics = ty_args.impl_generics();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let as_list_impl = ty_args.as_list_impl();
+    let as_option_impl = ty_args.as_option_impl();
     let as_array_impl = ty_args.as_array_impl();
+    let field_count_impl = ty_args.field_count_impl();
 
     let doc = args::fetch_doc_comment(&ty_args.attrs);
     let assembly_name = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
@@ -327,10 +567,18 @@ fn gen_impl(
     });
 
     let try_clone_box = if ty_args.non_cloneable {
+        let probe_items = self::probe_default_items();
+        let probe = self::probe_default(&quote!(Self));
         quote! {
             fn try_clone_box(&self) -> Option<Box<dyn Reflect>> {
                 None
             }
+
+            fn try_default_box(&self) -> Option<Box<dyn Reflect>> {
+                #probe_items
+
+                #probe.map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
         }
     } else {
         quote! {
@@ -340,6 +588,18 @@ fn gen_impl(
         }
     };
 
+    let reflect_partial_eq = ty_args.partial_eq.then(|| {
+        quote! {
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                let mut result = None;
+                other.as_any(&mut |other| {
+                    result = other.downcast_ref::<Self>().map(|other| PartialEq::eq(self, other))
+                });
+                result
+            }
+        }
+    });
+
     let types = ty_args
         .derived_type
         .iter()
@@ -358,6 +618,8 @@ fn gen_impl(
 
             #try_clone_box
 
+            #reflect_partial_eq
+
             fn type_name(&self) -> &'static str {
                 std::any::type_name::<Self>()
             }
@@ -378,6 +640,10 @@ fn gen_impl(
                 #doc
             }
 
+            fn type_source_path(&self) -> &'static str {
+                file!()
+            }
+
             fn assembly_name(&self) -> &'static str {
                 #assembly_name
             }
@@ -428,6 +694,12 @@ fn gen_impl(
             #as_array_impl
 
             #as_list_impl
+
+            #as_option_impl
+
+            #field_count_impl
+
+            #extra
         }
     }
 }
@@ -57,21 +57,58 @@ pub struct TypeArgs {
     #[darling(default, rename = "ReflectList")]
     pub impl_as_list: bool,
 
+    #[darling(default, rename = "ReflectOption")]
+    pub impl_as_option: bool,
+
     #[darling(multiple)]
     pub derived_type: Vec<Path>,
 
     #[darling(default)]
     pub non_cloneable: bool,
+
+    /// `#[reflect(partial_eq)]`
+    ///
+    /// Implements `Reflect::reflect_partial_eq` using `PartialEq` implementation of the type.
+    #[darling(default)]
+    pub partial_eq: bool,
+
+    /// `#[reflect(transparent)]`
+    ///
+    /// **SINGLE-FIELD STRUCT ONLY**
+    ///
+    /// Delegates field access (and container API) to the only field of a new-type, while keeping
+    /// the type name and doc comments of the wrapper itself. It is opt-in, because the default
+    /// derive already exposes the inner field with its own metadata (`"0"` for tuple structs) and
+    /// delegation removes that field (and its metadata) from reflection paths entirely.
+    #[darling(default)]
+    pub transparent: bool,
 }
 
 impl TypeArgs {
     pub fn validate(&mut self) {
         match &mut self.data {
             ast::Data::Enum(vs) => {
+                assert!(
+                    !self.transparent,
+                    "`transparent` can only be used on structs"
+                );
+
                 vs.iter_mut()
                     .for_each(|v| v.fields.fields.iter_mut().for_each(|f| f.validate()));
             }
             ast::Data::Struct(s) => {
+                if self.transparent {
+                    assert_eq!(
+                        s.fields.iter().filter(|f| !f.hidden).count(),
+                        1,
+                        "`transparent` requires exactly one non-hidden field"
+                    );
+                    assert!(
+                        !(self.impl_as_array || self.impl_as_list),
+                        "can't use both `transparent` and `ReflectArray` or `ReflectList`"
+                    );
+                }
+
                 s.fields.iter_mut().for_each(|f| f.validate());
             }
         }
@@ -84,6 +121,21 @@ impl TypeArgs {
         let clause = generics.make_where_clause();
 
         clause.predicates.push(parse_quote! { Self: 'static });
+
+        // Generic types usually derive `Debug` and `Clone` which are conditional on type parameters,
+        // so make the impl conditional as well instead of requiring explicit bounds.
+        if self.generics.type_params().next().is_some() {
+            clause
+                .predicates
+                .push(parse_quote! { Self: ::core::fmt::Debug });
+            if !self.non_cloneable {
+                clause.predicates.push(parse_quote! { Self: Clone });
+            }
+            if self.partial_eq {
+                clause.predicates.push(parse_quote! { Self: PartialEq });
+            }
+        }
+
         if let Some(bounds) = &self.bounds {
             clause.predicates.extend(bounds.iter().cloned());
         }
@@ -124,6 +176,66 @@ impl TypeArgs {
         }
     }
 
+    pub fn as_option_impl(&self) -> TokenStream2 {
+        if !self.impl_as_option {
+            return quote!();
+        }
+
+        quote! {
+            fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+                func(Some(self))
+            }
+
+            fn as_option_mut(&mut self,  func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+                func(Some(self))
+            }
+        }
+    }
+
+    pub fn field_count_impl(&self) -> TokenStream2 {
+        // Array-like types report the number of their items, which is done by the default
+        // implementation. Transparent types delegate the call to their inner field.
+        if self.impl_as_array || self.impl_as_list || self.transparent {
+            return quote!();
+        }
+
+        let body = if self.hide_all {
+            quote!(0)
+        } else {
+            match &self.data {
+                ast::Data::Struct(data) => {
+                    let count = data.fields.iter().filter(|f| !f.hidden).count();
+                    quote!(#count)
+                }
+                ast::Data::Enum(variants) => {
+                    let arms = variants.iter().map(|v| {
+                        let variant_ident = &v.ident;
+                        let count = v.fields.iter().filter(|f| !f.hidden).count();
+                        let pattern = match v.fields.style {
+                            ast::Style::Struct => quote!(Self::#variant_ident { .. }),
+                            ast::Style::Tuple => quote!(Self::#variant_ident(..)),
+                            ast::Style::Unit => quote!(Self::#variant_ident),
+                        };
+                        quote!(#pattern => #count,)
+                    });
+
+                    quote! {
+                        match self {
+                            #( #arms )*
+                            _ => 0,
+                        }
+                    }
+                }
+            }
+        };
+
+        quote! {
+            fn field_count(&self) -> usize {
+                #body
+            }
+        }
+    }
+
     pub fn as_array_impl(&self) -> TokenStream2 {
         if !self.impl_as_array {
             return quote!();
@@ -153,6 +265,13 @@ pub struct FieldArgs {
     #[darling(default)]
     pub name: Option<String>,
 
+    /// `#[reflect(alias = "<old name>")]`
+    ///
+    /// Alternative name of the property, could be specified multiple times. Allows accessing the
+    /// field by its old name.
+    #[darling(multiple)]
+    pub alias: Vec<String>,
+
     /// A list of forwarded attributes (only doc comments).
     pub attrs: Vec<Attribute>,
 
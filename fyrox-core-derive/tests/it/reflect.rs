@@ -348,6 +348,7 @@ fn reflect_fields_list_of_enum() {
 fn default_prop_metadata() -> FieldMetadata<'static> {
     FieldMetadata {
         name: "",
+        aliases: &[],
         display_name: "",
         read_only: false,
         immutable_collection: false,
@@ -372,12 +373,14 @@ fn inspect_default() {
 
     let the_field_metadata = FieldMetadata {
         name: "the_field",
+        aliases: &[],
         display_name: "The Field",
         ..default_prop_metadata()
     };
 
     let another_field_metadata = FieldMetadata {
         name: "another_field",
+        aliases: &[],
         display_name: "Another Field",
         ..default_prop_metadata()
     };
@@ -427,6 +430,7 @@ fn inspect_attributes() {
 
     let x_metadata = FieldMetadata {
         name: "x",
+        aliases: &[],
         display_name: "Super X",
         ..default_prop_metadata()
     };
@@ -439,6 +443,7 @@ fn inspect_attributes() {
         FieldRef {
             metadata: &FieldMetadata {
                 name: "y",
+                aliases: &[],
                 display_name: "Y",
                 read_only: true,
                 immutable_collection: false,
@@ -470,6 +475,7 @@ fn inspect_struct() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "0",
+                        aliases: &[],
                         display_name: "0",
                         ..default_prop_metadata()
                     },
@@ -478,6 +484,7 @@ fn inspect_struct() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "1",
+                        aliases: &[],
                         display_name: "1",
                         ..default_prop_metadata()
                     },
@@ -521,6 +528,7 @@ fn inspect_enum() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "Named@x",
+                        aliases: &[],
                         display_name: "X",
                         ..default_prop_metadata()
                     },
@@ -532,6 +540,7 @@ fn inspect_enum() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "Named@y",
+                        aliases: &[],
                         display_name: "Y",
                         ..default_prop_metadata()
                     },
@@ -543,6 +552,7 @@ fn inspect_enum() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "Named@z",
+                        aliases: &[],
                         display_name: "Z",
                         ..default_prop_metadata()
                     },
@@ -564,6 +574,7 @@ fn inspect_enum() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "Tuple@0",
+                        aliases: &[],
                         display_name: "0",
                         ..default_prop_metadata()
                     },
@@ -575,6 +586,7 @@ fn inspect_enum() {
                 FieldRef {
                     metadata: &FieldMetadata {
                         name: "Tuple@1",
+                        aliases: &[],
                         display_name: "1",
                         ..default_prop_metadata()
                     },
@@ -702,3 +714,174 @@ fn test_hash_map() {
         assert_eq!(*result.unwrap(), 555)
     });
 }
+
+#[test]
+fn reflect_generic_struct() {
+    #[derive(Reflect, Clone, Debug)]
+    struct Pair<A, B>
+    where
+        A: Default,
+    {
+        first: A,
+        second: Vec<B>,
+    }
+
+    let mut pair = Pair {
+        first: 1u32,
+        second: vec![Struct {
+            field: 2,
+            hidden: 0,
+        }],
+    };
+
+    assert_eq!(Pair::<u32, Struct>::FIRST, "first");
+    pair.get_field::<u32>(Pair::<u32, Struct>::FIRST, &mut |field| {
+        assert_eq!(field, Some(&1))
+    });
+    pair.get_field_mut::<u32>(Pair::<u32, Struct>::FIRST, &mut |field| *field.unwrap() = 3);
+    pair.get_field::<u32>(Pair::<u32, Struct>::FIRST, &mut |field| {
+        assert_eq!(field, Some(&3))
+    });
+    pair.get_resolve_path::<usize>("second[0].field", &mut |result| {
+        assert_eq!(result, Ok(&2));
+    });
+
+    let clone = pair.try_clone_box().unwrap();
+    assert!(clone.is::<Pair<u32, Struct>>());
+}
+
+#[test]
+fn reflect_transparent() {
+    #[derive(Reflect, Clone, Debug)]
+    struct Opaque(Struct);
+
+    /// Transparent wrapper.
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(transparent)]
+    struct Transparent(Struct);
+
+    let opaque = Opaque(Struct {
+        field: 1,
+        hidden: 0,
+    });
+    opaque.fields_ref(&mut |fields| {
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, Opaque::F_0);
+    });
+    opaque.get_resolve_path::<usize>("0.field", &mut |result| {
+        assert_eq!(result, Ok(&1));
+    });
+
+    let mut transparent = Transparent(Struct {
+        field: 2,
+        hidden: 0,
+    });
+    assert_eq!(transparent.doc(), " Transparent wrapper.");
+    assert!((&transparent as &dyn Reflect).is::<Transparent>());
+    transparent.fields_ref(&mut |fields| {
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, Struct::FIELD);
+    });
+    transparent.get_field_mut::<usize>(Struct::FIELD, &mut |field| *field.unwrap() = 3);
+    transparent.get_resolve_path::<usize>("field", &mut |result| {
+        assert_eq!(result, Ok(&3));
+    });
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(transparent)]
+    struct TransparentList(Vec<usize>);
+
+    let list = TransparentList(vec![1, 2, 3]);
+    list.get_resolve_path::<usize>("[1]", &mut |result| {
+        assert_eq!(result, Ok(&2));
+    });
+}
+
+#[test]
+fn reflect_source_location() {
+    let value = Struct {
+        field: 0,
+        hidden: 0,
+    };
+    let value = &value as &dyn Reflect;
+
+    assert_eq!(value.type_source_path(), file!());
+    assert_eq!(value.type_source_path(), Struct::source_path());
+    assert_eq!(value.assembly_name(), Struct::type_assembly_name());
+}
+
+#[test]
+fn reflect_field_count() {
+    let s = Struct {
+        field: 0,
+        hidden: 0,
+    };
+    assert_eq!(s.field_count(), 1);
+    assert_eq!(Tuple(0, 1).field_count(), 2);
+
+    assert_eq!(Enum::Named { field: 0 }.field_count(), 1);
+    assert_eq!(Enum::Tuple(0).field_count(), 1);
+    assert_eq!(Enum::Unit.field_count(), 0);
+
+    assert_eq!(vec![1, 2, 3].field_count(), 3);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(transparent)]
+    struct TransparentList(Vec<usize>);
+
+    assert_eq!(TransparentList(vec![1, 2]).field_count(), 2);
+}
+
+#[test]
+fn reflect_enum() {
+    #[derive(Reflect, Clone, Debug)]
+    struct NoDefault;
+
+    #[derive(Reflect, Clone, Debug)]
+    enum Shape {
+        Point,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+        Custom(NoDefault),
+    }
+
+    let mut shape = Shape::Circle(2.0);
+    assert_eq!(shape.variant_name(), "Circle");
+    assert_eq!(shape.variant_index(), 1);
+    assert_eq!(shape.variant_count(), 4);
+    assert_eq!(
+        shape.variant_names(),
+        &["Point", "Circle", "Rect", "Custom"]
+    );
+
+    // Switching to the active variant keeps its fields.
+    assert!(shape.set_variant("Circle"));
+    assert!(matches!(shape, Shape::Circle(radius) if radius == 2.0));
+
+    assert!(shape.set_variant("Rect"));
+    assert!(matches!(
+        shape,
+        Shape::Rect { width, height } if width == 0.0 && height == 0.0
+    ));
+    assert_eq!(shape.variant_index(), 2);
+
+    assert!(shape.set_variant("Point"));
+    assert!(matches!(shape, Shape::Point));
+
+    // Fields without `Default` implementation cannot be created.
+    assert!(!shape.set_variant("Custom"));
+    assert!(!shape.set_variant("Unknown"));
+    assert!(matches!(shape, Shape::Point));
+
+    // Access through `dyn Reflect`.
+    let reflect = &mut shape as &mut dyn Reflect;
+    let mut name = None;
+    reflect.as_enum(&mut |e| name = e.map(|e| e.variant_name()));
+    assert_eq!(name, Some("Point"));
+    reflect.as_enum_mut(&mut |e| assert!(e.unwrap().set_variant("Circle")));
+    assert!(matches!(shape, Shape::Circle(radius) if radius == 0.0));
+
+    let mut is_enum = true;
+    Tuple(0, 1).as_enum(&mut |e| is_enum = e.is_some());
+    assert!(!is_enum);
+}
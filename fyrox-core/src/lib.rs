@@ -310,6 +310,16 @@ pub fn hash_combine(lhs: u64, rhs: u64) -> u64 {
 /// input path is not valid, does not exist, or there is some other issues with it.
 /// The last component of the path is permitted to not exist, so long as the rest of the path exists.
 pub fn make_relative_path<P: AsRef<Path>>(path: P) -> Result<PathBuf, std::io::Error> {
+    make_relative_path_to(path, std::env::current_dir()?)
+}
+
+/// Strip the given base directory from file name. Works the same as [`make_relative_path`], but
+/// uses the given base directory instead of the working directory. The base directory must exist.
+pub fn make_relative_path_to<P, B>(path: P, base: B) -> Result<PathBuf, std::io::Error>
+where
+    P: AsRef<Path>,
+    B: AsRef<Path>,
+{
     let path = path.as_ref();
     if path.as_os_str() == "." {
         return Ok(path.to_path_buf());
@@ -339,7 +349,7 @@ pub fn make_relative_path<P: AsRef<Path>>(path: P) -> Result<PathBuf, std::io::E
             ))
         })?
         .join(file_name);
-    match canon_path.strip_prefix(std::env::current_dir()?.canonicalize()?) {
+    match canon_path.strip_prefix(base.as_ref().canonicalize()?) {
         Ok(relative_path) => Ok(replace_slashes(relative_path)),
         Err(err) => Err(std::io::Error::other(format!(
             "unable to strip prefix from '{}'! Reason: {err}",
@@ -585,7 +595,7 @@ mod test {
 
     use crate::{
         append_extension, cmp_strings_case_insensitive, combine_uuids, hash_combine,
-        make_relative_path, transmute_vec_as_bytes,
+        make_relative_path, make_relative_path_to, transmute_vec_as_bytes,
         visitor::{Visit, Visitor},
         BiDirHashMap,
     };
@@ -756,6 +766,17 @@ mod test {
         make_relative_path(Path::new("Cargo.toml").canonicalize().unwrap()).unwrap();
     }
 
+    #[test]
+    fn test_make_relative_path_to() {
+        let path = Path::new("src").join("lib.rs").canonicalize().unwrap();
+        assert_eq!(
+            make_relative_path_to(&path, "src").unwrap(),
+            Path::new("lib.rs")
+        );
+        assert!(make_relative_path_to(Path::new("Cargo.toml"), "src").is_err());
+        assert!(make_relative_path_to(&path, "fake_dir").is_err());
+    }
+
     #[test]
     fn tests_case_insensitive_str_comparison() {
         assert!(cmp_strings_case_insensitive("FooBar", "FOOBaR"));
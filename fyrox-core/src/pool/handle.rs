@@ -85,6 +85,7 @@ impl<T: Reflect> ReflectHandle for Handle<T> {
 
 static INDEX_METADATA: FieldMetadata = FieldMetadata {
     name: "Index",
+    aliases: &[],
     display_name: "Index",
     tag: "",
     read_only: false,
@@ -98,6 +99,7 @@ static INDEX_METADATA: FieldMetadata = FieldMetadata {
 
 static GENERATION_METADATA: FieldMetadata = FieldMetadata {
     name: "Generation",
+    aliases: &[],
     display_name: "Generation",
     tag: "",
     read_only: false,
@@ -137,6 +139,10 @@ impl<T: Reflect> Reflect for Handle<T> {
         ""
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
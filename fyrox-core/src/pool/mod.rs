@@ -217,6 +217,10 @@ where
         Ok(Box::new(this))
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
@@ -27,14 +27,17 @@ pub use fyrox_core_derive::Reflect;
 use std::ops::Deref;
 use std::{
     any::{Any, TypeId},
+    borrow::Cow,
+    collections::HashMap,
     fmt::{self, Debug, Display, Formatter},
     mem::ManuallyDrop,
 };
 
 pub mod prelude {
     pub use super::{
-        FieldMetadata, FieldMut, FieldRef, FieldValue, Reflect, ReflectArray, ReflectHashMap,
-        ReflectInheritableVariable, ReflectList, ResolvePath, SetFieldByPathError, SetFieldError,
+        FieldMetadata, FieldMut, FieldRef, FieldValue, Reflect, ReflectArray, ReflectEnum,
+        ReflectHashMap, ReflectInheritableVariable, ReflectList, ReflectOption, ResolvePath,
+        ResolvedPath, SetFieldByPathError, SetFieldError,
     };
 }
 
@@ -110,6 +113,15 @@ pub struct FieldMetadata<'s> {
     /// A name of the property.
     pub name: &'s str,
 
+    /// Alternative names of the property (usually its old names). The property could be accessed
+    /// by any of them as well as by its actual name, which keeps old paths working.
+    ///
+    /// # Notes
+    ///
+    /// This field was added after the rest of the fields, so every struct literal of the metadata
+    /// must specify it now. Use [`Self::new`] with struct update syntax to avoid such breakage.
+    pub aliases: &'s [&'s str],
+
     /// A human-readable name of the property.
     pub display_name: &'s str,
 
@@ -140,6 +152,35 @@ pub struct FieldMetadata<'s> {
     pub precision: Option<usize>,
 }
 
+impl<'s> FieldMetadata<'s> {
+    /// Creates metadata of a property with the given name. The display name of the property is the
+    /// same as its name, every other field has its default value (empty string, `false` or `None`).
+    /// It could be used with struct update syntax to fill the fields you don't need, for example
+    /// `FieldMetadata { doc: "Some doc", ..FieldMetadata::new("foo") }`. This way the code keeps
+    /// compiling when new fields are added to the metadata.
+    pub const fn new(name: &'s str) -> Self {
+        Self {
+            name,
+            aliases: &[],
+            display_name: name,
+            tag: "",
+            doc: "",
+            read_only: false,
+            immutable_collection: false,
+            min_value: None,
+            max_value: None,
+            step: None,
+            precision: None,
+        }
+    }
+
+    /// Checks whether the given name is either the actual name of the property or one of its
+    /// aliases.
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
 pub struct FieldRef<'a, 'b> {
     /// A reference to field's metadata.
     pub metadata: &'a FieldMetadata<'b>,
@@ -251,6 +292,14 @@ where
 /// The derive macro is available under `#[reflect(...)]` attribute that can be placed on both
 /// the type and its fields.
 ///
+/// ### Generic types
+///
+/// The macro supports generic types with any amount of type parameters and `where` clauses. Every
+/// reflectable field adds a `FieldType: Reflect` bound to the generated impl, and for generic types
+/// the impl additionally requires `Self: Debug + Clone` (`Self: Debug` only for non-cloneable types),
+/// so the bounds of derived `Debug` and `Clone` impls are picked up automatically. Fields, that are
+/// hidden or accessed via `deref` do not add any bounds, use `#[reflect(bounds)]` in this case.
+///
 /// ### Type attributes
 ///
 /// - `#[reflect(hide_all)]` - hide all fields from reflection.
@@ -258,8 +307,19 @@ where
 /// `#[reflect(bounds = "T: Reflect + Clone")]`
 /// - `#[reflect(non_cloneable)]` - prevent the macro from generating an implementation of
 /// [`Self::try_clone_box`] trait for your type. Could be useful for non-cloneable types.
+/// - `#[reflect(partial_eq)]` - implements [`Self::reflect_partial_eq`] using [`PartialEq`]
+/// implementation of your type.
 /// - `#[reflect(derived_type = "Type")]` - marks the type for which the attribute is added as a
 /// subtype for the `Type`.
+/// - `#[reflect(transparent)]` - only for structs with a single (non-hidden) field. Delegates field
+/// access (including array, list and hash map API) to the inner field, but keeps the type name and
+/// doc comments of the wrapper itself. Without this attribute the inner field is exposed as a
+/// regular field with its own metadata (`"0"` for tuple structs). The attribute is opt-in on
+/// purpose: the default keeps an extra path component (`"0.field"`) with the wrapper's field
+/// metadata, which is what most new-types need, while transparent delegation removes that component
+/// (`"field"`, `"[1]"`), so the wrapper could be used as a drop-in replacement of the inner type
+/// (for example, in place of `newtype_reflect!`). Such a wrapper has no field of its own, so its
+/// metadata is available only via [`Self::doc`] and [`Self::type_name`].
 ///
 /// ### Field attributes
 ///
@@ -291,7 +351,9 @@ where
 /// By default, the proc macro adds an implementation of [`Self::try_clone_box`] with the assumption
 /// that your type implements the [`Clone`] trait. Not all types can implement this trait, in this
 /// case, add `#[reflect(non_cloneable)]` attribute for your type. This will force the implementation
-/// of [`Self::try_clone_box`] to return `None`.
+/// of [`Self::try_clone_box`] to return `None`. If such type implements [`Default`], the proc macro
+/// also implements [`Self::try_default_box`], which allows `dyn Reflect::reflect_clone_deep` to
+/// clone the value field-by-field.
 ///
 /// ## Additional Trait Bounds
 ///
@@ -310,6 +372,22 @@ pub trait Reflect: ReflectBase {
 
     fn try_clone_box(&self) -> Option<Box<dyn Reflect>>;
 
+    /// Creates a new instance of the type of this value with its default value. It is used to
+    /// re-assemble values, that cannot be cloned directly (see `dyn Reflect::reflect_clone_deep`).
+    /// The proc-macro (`#[derive(Reflect)]`) implements it for non-cloneable types, that implement
+    /// [`Default`].
+    fn try_default_box(&self) -> Option<Box<dyn Reflect>> {
+        None
+    }
+
+    /// Compares the value with the other value using [`PartialEq`] implementation of the type. Returns
+    /// `None` if the types of the values do not match, or if the type does not support comparison.
+    /// The proc-macro (`#[derive(Reflect)]`) implements it only for the types marked with
+    /// `#[reflect(partial_eq)]` attribute.
+    fn reflect_partial_eq(&self, _other: &dyn Reflect) -> Option<bool> {
+        None
+    }
+
     fn query_derived_types(&self) -> &'static [TypeId];
 
     fn type_name(&self) -> &'static str;
@@ -332,6 +410,16 @@ pub trait Reflect: ReflectBase {
 
     fn set(&mut self, value: Box<dyn Reflect>) -> Result<Box<dyn Reflect>, Box<dyn Reflect>>;
 
+    /// Returns a path to the source file, where the type of this instance is defined. Unlike
+    /// [`Self::source_path`], this method is object-safe and could be used on `dyn Reflect` (for
+    /// example, to open the definition of a type in an IDE). **WARNING:** You should use proc-macro
+    /// (`#[derive(Reflect)]`) to ensure that this method will return correct path. Alternatively,
+    /// you can use `file!()` as an implementation. The default implementation returns an empty
+    /// string, which means that the path is unknown.
+    fn type_source_path(&self) -> &'static str {
+        ""
+    }
+
     /// Returns a parent assembly name of the type that implements this trait. **WARNING:** You should use
     /// proc-macro (`#[derive(Reflect)]`) to ensure that this method will return correct assembly
     /// name. In other words - there's no guarantee, that any implementation other than proc-macro
@@ -363,6 +451,7 @@ pub trait Reflect: ReflectBase {
             match field {
                 Some(f) => func(f.set(value).map_err(|value| SetFieldError::InvalidValue {
                     field_type_name: f.type_name(),
+                    value_type_name: value.type_name(),
                     value,
                 })),
                 None => func(Err(SetFieldError::NoSuchField {
@@ -378,7 +467,7 @@ pub trait Reflect: ReflectBase {
             func(
                 fields
                     .iter()
-                    .find(|field| field.name == name)
+                    .find(|field| field.matches_name(name))
                     .map(|field| field.value.field_value_as_reflect()),
             )
         });
@@ -389,12 +478,32 @@ pub trait Reflect: ReflectBase {
             func(
                 fields
                     .iter_mut()
-                    .find(|field| field.name == name)
+                    .find(|field| field.matches_name(name))
                     .map(|field| field.value.field_value_as_reflect_mut()),
             )
         });
     }
 
+    /// Returns the number of top-level fields of the value. For arrays, lists and hash maps it
+    /// returns the number of their items. The proc-macro (`#[derive(Reflect)]`) generates an
+    /// implementation that does not call [`Self::fields_ref`], the default implementation counts
+    /// the fields provided by it.
+    fn field_count(&self) -> usize {
+        let mut count = None;
+        self.as_array(&mut |array| count = array.map(|array| array.reflect_len()));
+        if count.is_none() {
+            self.as_list(&mut |list| count = list.map(|list| list.reflect_len()));
+        }
+        if count.is_none() {
+            self.as_hash_map(&mut |map| count = map.map(|map| map.reflect_len()));
+        }
+        count.unwrap_or_else(|| {
+            let mut count = 0;
+            self.fields_ref(&mut |fields| count = fields.len());
+            count
+        })
+    }
+
     fn as_array(&self, func: &mut dyn FnMut(Option<&dyn ReflectArray>)) {
         func(None)
     }
@@ -433,6 +542,26 @@ pub trait Reflect: ReflectBase {
         func(None)
     }
 
+    /// Provides access to the enum-specific API of the value (see [`ReflectEnum`]). The proc-macro
+    /// (`#[derive(Reflect)]`) implements it for every enum.
+    fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+        func(None)
+    }
+
+    fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+        func(None)
+    }
+
+    /// Provides access to the option-specific API of the value (see [`ReflectOption`]). It is
+    /// implemented for [`Option`].
+    fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+        func(None)
+    }
+
+    fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+        func(None)
+    }
+
     fn as_handle(&self, func: &mut dyn FnMut(Option<&dyn ReflectHandle>)) {
         func(None)
     }
@@ -490,6 +619,75 @@ pub trait ReflectHashMap: Reflect {
     fn reflect_get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)>;
     fn reflect_get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)>;
     fn reflect_remove(&mut self, key: &dyn Reflect, func: &mut dyn FnMut(Option<Box<dyn Reflect>>));
+
+    /// Removes an entry at the given index (in the same order as [`Self::reflect_get_at`]) and
+    /// passes its key and value to the given callback. The default implementation clones the key
+    /// at the index and removes the entry using [`Self::reflect_remove`], so it fails if the key
+    /// is non-cloneable.
+    #[allow(clippy::type_complexity)]
+    fn reflect_remove_at(
+        &mut self,
+        index: usize,
+        func: &mut dyn FnMut(Option<(Box<dyn Reflect>, Box<dyn Reflect>)>),
+    ) {
+        let Some(key) = self
+            .reflect_get_at(index)
+            .and_then(|(key, _)| key.try_clone_box())
+        else {
+            func(None);
+            return;
+        };
+
+        let mut removed = None;
+        self.reflect_remove(&*key, &mut |value| removed = value);
+        func(removed.map(|value| (key, value)))
+    }
+}
+
+/// [`Reflect`] sub trait for working with enums. It allows to query the active variant and to switch
+/// between variants without knowing the actual type of the enum, which is useful for generic editors.
+pub trait ReflectEnum: Reflect {
+    /// Returns the name of the active variant.
+    fn variant_name(&self) -> &'static str;
+
+    /// Returns the index of the active variant (in declaration order).
+    fn variant_index(&self) -> usize;
+
+    /// Returns the names of all variants (in declaration order).
+    fn variant_names(&self) -> &'static [&'static str];
+
+    /// Returns the total number of variants.
+    fn variant_count(&self) -> usize {
+        self.variant_names().len()
+    }
+
+    /// Switches the value to the variant with the given name, the fields of the new variant are
+    /// initialized with their default values. Does nothing if the variant is already active.
+    /// Returns `false` if there's no variant with the given name or if some field of the variant
+    /// does not implement [`Default`] (this includes fields of generic types, that are not bound by
+    /// `Default`).
+    fn set_variant(&mut self, name: &str) -> bool;
+}
+
+/// [`Reflect`] sub trait for working with optional values. It allows editors to check whether a
+/// value is present and to toggle between `None` and `Some` without knowing the actual type of the
+/// value. The payload of `Some` could also be accessed by path, for example `field.Some@0`.
+pub trait ReflectOption: Reflect {
+    /// Returns `true` if the value is present.
+    fn reflect_is_some(&self) -> bool;
+
+    /// Returns a reference to the inner value, if any.
+    fn reflect_inner(&self) -> Option<&dyn Reflect>;
+
+    /// Returns a reference to the inner value, if any.
+    fn reflect_inner_mut(&mut self) -> Option<&mut dyn Reflect>;
+
+    /// Sets the value to `None` and returns the previous inner value, if any.
+    fn reflect_set_none(&mut self) -> Option<Box<dyn Reflect>>;
+
+    /// Sets the value to `Some` with the given inner value. Returns the given value back if its
+    /// type does not match the type of the inner value.
+    fn reflect_set_some(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>>;
 }
 
 pub trait ReflectInheritableVariable: Reflect {
@@ -529,7 +727,7 @@ pub trait ReflectInheritableVariable: Reflect {
 }
 
 /// An error returned from a failed path string query.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReflectPathError<'a> {
     // syntax errors
     UnclosedBrackets { s: &'a str },
@@ -587,6 +785,22 @@ pub trait ResolvePath {
         func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
     );
 
+    /// Same as [`Self::resolve_path`], but uses a pre-parsed path. Use it to access the same
+    /// property repeatedly (for example, every frame).
+    fn resolve_cached<'p>(
+        &self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
+    );
+
+    /// Same as [`Self::resolve_path_mut`], but uses a pre-parsed path. Use it to access the same
+    /// property repeatedly (for example, every frame).
+    fn resolve_cached_mut<'p>(
+        &mut self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
+    );
+
     fn get_resolve_path<'p, T: Reflect>(
         &self,
         path: &'p str,
@@ -644,9 +858,27 @@ impl<T: Reflect> ResolvePath for T {
     ) {
         (self as &mut dyn Reflect).resolve_path_mut(path, func)
     }
+
+    fn resolve_cached<'p>(
+        &self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        (self as &dyn Reflect).resolve_cached(path, func)
+    }
+
+    fn resolve_cached_mut<'p>(
+        &mut self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        (self as &mut dyn Reflect).resolve_cached_mut(path, func)
+    }
 }
 
-/// Splits property path into individual components.
+/// Splits property path into individual components. Special characters (`.`, `[`, `]`) could be
+/// escaped using backslash (for example, `settings[config\.main]` or `a\.b`), the components are
+/// returned as is (escaped), use [`Component::unescaped`] to get the actual name of a component.
 pub fn path_to_components(path: &str) -> Vec<Component> {
     let mut components = Vec::new();
     let mut current_path = path;
@@ -735,6 +967,196 @@ fn try_fetch_by_str_path_mut(
     }
 }
 
+/// Tries to parse the given path into every integer key type and fetch a value with the key. Stops
+/// on the first match and returns `true` if a value was found.
+macro_rules! try_fetch_by_integer_key {
+    ($hash_map:expr, $path:expr, $get:ident, $func:expr, $($ty:ty),*) => {{
+        let mut found = false;
+        $(
+            if !found {
+                if let Ok(key) = $path.parse::<$ty>() {
+                    $hash_map.$get(&key as &dyn Reflect, &mut |result| {
+                        if let Some(value) = result {
+                            found = true;
+                            $func(Some(value));
+                        }
+                    });
+                }
+            }
+        )*
+        found
+    }};
+}
+
+fn is_integer_key(path: &str) -> bool {
+    let digits = path.strip_prefix('-').unwrap_or(path);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Fetches a value from the hash map by the key parsed from the given path. Integer keys are tried
+/// first, then string keys.
+fn try_fetch_by_key_ref(
+    hash_map: &dyn ReflectHashMap,
+    path: &str,
+    func: &mut dyn FnMut(Option<&dyn Reflect>),
+) {
+    if is_integer_key(path)
+        && try_fetch_by_integer_key!(
+            hash_map,
+            path,
+            reflect_get,
+            func,
+            u8,
+            u16,
+            u32,
+            u64,
+            usize,
+            i8,
+            i16,
+            i32,
+            i64,
+            isize
+        )
+    {
+        return;
+    }
+
+    try_fetch_by_str_path_ref(hash_map, path, func)
+}
+
+/// Mutable version of [`try_fetch_by_key_ref`].
+fn try_fetch_by_key_mut(
+    hash_map: &mut dyn ReflectHashMap,
+    path: &str,
+    func: &mut dyn FnMut(Option<&mut dyn Reflect>),
+) {
+    if is_integer_key(path)
+        && try_fetch_by_integer_key!(
+            hash_map,
+            path,
+            reflect_get_mut,
+            func,
+            u8,
+            u16,
+            u32,
+            u64,
+            usize,
+            i8,
+            i16,
+            i32,
+            i64,
+            isize
+        )
+    {
+        return;
+    }
+
+    try_fetch_by_str_path_mut(hash_map, path, func)
+}
+
+/// Escapes special characters (`.`, `[`, `]` and `\`) of the given path component with backslashes,
+/// so the component could be used in a path. Allocates only if the component contains at least one
+/// special character.
+fn escape_path_component(component: &str) -> Cow<'_, str> {
+    const SPECIAL: [char; 4] = ['.', '[', ']', '\\'];
+
+    if !component.contains(SPECIAL) {
+        return Cow::Borrowed(component);
+    }
+
+    let mut escaped = String::with_capacity(component.len() + 1);
+    for c in component.chars() {
+        if SPECIAL.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    Cow::Owned(escaped)
+}
+
+/// Removes escaping backslashes from the given path component. Allocates only if the component
+/// contains at least one backslash.
+fn unescape_path_component(component: &str) -> Cow<'_, str> {
+    if !component.contains('\\') {
+        return Cow::Borrowed(component);
+    }
+
+    let mut unescaped = String::with_capacity(component.len());
+    let mut chars = component.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                unescaped.push(escaped);
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+/// Splits the given path at the last field separator (`.`) that is not escaped and not inside an
+/// index (`[..]`). Returns a parent path and a field name.
+fn split_last_field(path: &str) -> Option<(&str, &str)> {
+    let mut separator = None;
+    let mut in_brackets = false;
+    let mut bytes = path.bytes().enumerate();
+    while let Some((i, b)) = bytes.next() {
+        match b {
+            b'\\' => {
+                bytes.next();
+            }
+            b'[' => in_brackets = true,
+            b']' => in_brackets = false,
+            b'.' if !in_brackets => separator = Some(i),
+            _ => (),
+        }
+    }
+    separator.map(|i| (&path[..i], &path[(i + 1)..]))
+}
+
+/// Resolves the given `(path, sub_path)` pairs relative to `reflect`. Sub paths are grouped by
+/// their first component, so each distinct component is resolved only once.
+#[allow(clippy::type_complexity)]
+fn resolve_paths_grouped<'p>(
+    reflect: &dyn Reflect,
+    entries: Vec<(&'p str, &'p str)>,
+    func: &mut dyn FnMut(&'p str, Result<&dyn Reflect, ReflectPathError<'p>>),
+) {
+    let mut parsed = Vec::with_capacity(entries.len());
+    for (path, sub_path) in entries {
+        match Component::next(sub_path) {
+            Ok((component, rest)) => parsed.push((component, path, rest)),
+            Err(err) => func(path, Err(err)),
+        }
+    }
+
+    parsed.sort_by(|(a, ..), (b, ..)| a.key().cmp(&b.key()));
+
+    for group in parsed.chunk_by(|(a, ..), (b, ..)| a.key() == b.key()) {
+        group[0].0.resolve(reflect, &mut |result| match result {
+            Ok(child) => {
+                let mut children = Vec::new();
+                for (_, path, rest) in group {
+                    if rest.is_empty() {
+                        func(path, Ok(child));
+                    } else {
+                        children.push((*path, *rest));
+                    }
+                }
+                if !children.is_empty() {
+                    resolve_paths_grouped(child, children, func);
+                }
+            }
+            Err(err) => {
+                for (_, path, _) in group {
+                    func(path, Err(err.clone()));
+                }
+            }
+        });
+    }
+}
+
 /// Simple path parser / reflect path component
 pub enum Component<'p> {
     Field(&'p str),
@@ -742,6 +1164,29 @@ pub enum Component<'p> {
 }
 
 impl<'p> Component<'p> {
+    /// Returns the name of the field (or the index) with escaping backslashes removed.
+    pub fn unescaped(&self) -> Cow<'p, str> {
+        match self {
+            Self::Field(path) | Self::Index(path) => unescape_path_component(path),
+        }
+    }
+
+    /// Returns a key, that is equal for the same components.
+    fn key(&self) -> (bool, &'p str) {
+        match self {
+            Self::Field(path) => (false, path),
+            Self::Index(path) => (true, path),
+        }
+    }
+
+    /// Parses the unescaped name of an index component as an array index.
+    fn parse_index(&self, name: &str) -> Option<usize> {
+        match self {
+            Self::Field(_) => None,
+            Self::Index(_) => name.parse().ok(),
+        }
+    }
+
     fn next(mut path: &'p str) -> Result<(Self, &'p str), ReflectPathError<'p>> {
         // Discard the first comma:
         if path.bytes().next() == Some(b'.') {
@@ -750,6 +1195,13 @@ impl<'p> Component<'p> {
 
         let mut bytes = path.bytes().enumerate();
         while let Some((i, b)) = bytes.next() {
+            // Skip escaped characters. Continuation bytes of multibyte characters never match any
+            // of the special characters, so it is enough to skip just one byte.
+            if b == b'\\' {
+                bytes.next();
+                continue;
+            }
+
             if b == b'.' {
                 let (l, r) = path.split_at(i);
                 return Ok((Self::Field(l), &r[1..]));
@@ -762,8 +1214,17 @@ impl<'p> Component<'p> {
                     return Ok((Self::Field(l), r));
                 }
 
-                // find ']'
-                if let Some((end, _)) = bytes.find(|(_, b)| *b == b']') {
+                // find unescaped ']'
+                let mut escaped = false;
+                if let Some((end, _)) = bytes.find(|(_, b)| {
+                    if escaped {
+                        escaped = false;
+                        false
+                    } else {
+                        escaped = *b == b'\\';
+                        *b == b']'
+                    }
+                }) {
                     let l = &path[1..end];
                     let r = &path[end + 1..];
                     return Ok((Self::Index(l), r));
@@ -781,26 +1242,35 @@ impl<'p> Component<'p> {
         &self,
         reflect: &dyn Reflect,
         func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        let name = self.unescaped();
+        self.resolve_parsed(&name, self.parse_index(&name), reflect, func)
+    }
+
+    fn resolve_parsed(
+        &self,
+        name: &str,
+        index: Option<usize>,
+        reflect: &dyn Reflect,
+        func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
     ) {
         match self {
-            Self::Field(path) => reflect.field(path, &mut |field| {
+            Self::Field(path) => reflect.field(name, &mut |field| {
                 func(field.ok_or(ReflectPathError::UnknownField { s: path }))
             }),
             Self::Index(path) => {
                 reflect.as_array(&mut |result| match result {
-                    Some(array) => match path.parse::<usize>() {
-                        Ok(index) => match array.reflect_index(index) {
+                    Some(array) => match index {
+                        Some(index) => match array.reflect_index(index) {
                             None => func(Err(ReflectPathError::NoItemForIndex { s: path })),
                             Some(value) => func(Ok(value)),
                         },
-                        Err(_) => func(Err(ReflectPathError::InvalidIndexSyntax { s: path })),
+                        None => func(Err(ReflectPathError::InvalidIndexSyntax { s: path })),
                     },
                     None => reflect.as_hash_map(&mut |result| match result {
-                        Some(hash_map) => {
-                            try_fetch_by_str_path_ref(hash_map, path, &mut |result| {
-                                func(result.ok_or(ReflectPathError::NoItemForIndex { s: path }))
-                            })
-                        }
+                        Some(hash_map) => try_fetch_by_key_ref(hash_map, name, &mut |result| {
+                            func(result.ok_or(ReflectPathError::NoItemForIndex { s: path }))
+                        }),
                         None => func(Err(ReflectPathError::NotAnArray)),
                     }),
                 });
@@ -812,31 +1282,40 @@ impl<'p> Component<'p> {
         &self,
         reflect: &mut dyn Reflect,
         func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        let name = self.unescaped();
+        self.resolve_parsed_mut(&name, self.parse_index(&name), reflect, func)
+    }
+
+    fn resolve_parsed_mut(
+        &self,
+        name: &str,
+        index: Option<usize>,
+        reflect: &mut dyn Reflect,
+        func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
     ) {
         match self {
-            Self::Field(path) => reflect.field_mut(path, &mut |field| {
+            Self::Field(path) => reflect.field_mut(name, &mut |field| {
                 func(field.ok_or(ReflectPathError::UnknownField { s: path }))
             }),
             Self::Index(path) => {
                 let mut succeeded = true;
                 reflect.as_array_mut(&mut |array| match array {
-                    Some(list) => match path.parse::<usize>() {
-                        Ok(index) => match list.reflect_index_mut(index) {
+                    Some(list) => match index {
+                        Some(index) => match list.reflect_index_mut(index) {
                             None => func(Err(ReflectPathError::NoItemForIndex { s: path })),
                             Some(value) => func(Ok(value)),
                         },
-                        Err(_) => func(Err(ReflectPathError::InvalidIndexSyntax { s: path })),
+                        None => func(Err(ReflectPathError::InvalidIndexSyntax { s: path })),
                     },
                     None => succeeded = false,
                 });
 
                 if !succeeded {
                     reflect.as_hash_map_mut(&mut |result| match result {
-                        Some(hash_map) => {
-                            try_fetch_by_str_path_mut(hash_map, path, &mut |result| {
-                                func(result.ok_or(ReflectPathError::NoItemForIndex { s: path }))
-                            })
-                        }
+                        Some(hash_map) => try_fetch_by_key_mut(hash_map, name, &mut |result| {
+                            func(result.ok_or(ReflectPathError::NoItemForIndex { s: path }))
+                        }),
                         None => func(Err(ReflectPathError::NotAnArray)),
                     })
                 }
@@ -845,6 +1324,95 @@ impl<'p> Component<'p> {
     }
 }
 
+/// A path component with pre-computed name and index.
+struct ResolvedComponent<'p> {
+    component: Component<'p>,
+    name: Cow<'p, str>,
+    index: Option<usize>,
+}
+
+/// A property path, that is parsed once and then could be used to access the same property many
+/// times without re-parsing the path string (see [`ResolvePath::resolve_cached`]).
+pub struct ResolvedPath<'p> {
+    path: &'p str,
+    components: Vec<ResolvedComponent<'p>>,
+}
+
+impl<'p> ResolvedPath<'p> {
+    /// Parses the given path. See [`path_to_components`] for the path syntax.
+    pub fn new(path: &'p str) -> Result<Self, ReflectPathError<'p>> {
+        let mut components = Vec::new();
+        let mut current_path = path;
+        loop {
+            let (component, sub_path) = Component::next(current_path)?;
+            let name = component.unescaped();
+            let index = component.parse_index(&name);
+            components.push(ResolvedComponent {
+                component,
+                name,
+                index,
+            });
+            if sub_path.is_empty() {
+                break;
+            }
+            current_path = sub_path;
+        }
+        Ok(Self { path, components })
+    }
+
+    /// Returns the source path string.
+    pub fn path(&self) -> &'p str {
+        self.path
+    }
+
+    /// Returns the parsed components of the path.
+    pub fn components(&self) -> impl Iterator<Item = &Component<'p>> {
+        self.components.iter().map(|c| &c.component)
+    }
+}
+
+fn resolve_components<'p>(
+    reflect: &dyn Reflect,
+    components: &[ResolvedComponent<'p>],
+    func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
+) {
+    let Some((first, rest)) = components.split_first() else {
+        func(Ok(reflect));
+        return;
+    };
+
+    first.component.resolve_parsed(
+        &first.name,
+        first.index,
+        reflect,
+        &mut |result| match result {
+            Ok(child) => resolve_components(child, rest, func),
+            Err(err) => func(Err(err)),
+        },
+    )
+}
+
+fn resolve_components_mut<'p>(
+    reflect: &mut dyn Reflect,
+    components: &[ResolvedComponent<'p>],
+    func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
+) {
+    let Some((first, rest)) = components.split_first() else {
+        func(Ok(reflect));
+        return;
+    };
+
+    first.component.resolve_parsed_mut(
+        &first.name,
+        first.index,
+        reflect,
+        &mut |result| match result {
+            Ok(child) => resolve_components_mut(child, rest, func),
+            Err(err) => func(Err(err)),
+        },
+    )
+}
+
 impl ResolvePath for dyn Reflect {
     fn resolve_path<'p>(
         &self,
@@ -885,6 +1453,22 @@ impl ResolvePath for dyn Reflect {
             Err(err) => func(Err(err)),
         }
     }
+
+    fn resolve_cached<'p>(
+        &self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        resolve_components(self, &path.components, func)
+    }
+
+    fn resolve_cached_mut<'p>(
+        &mut self,
+        path: &ResolvedPath<'p>,
+        func: &mut dyn FnMut(Result<&mut dyn Reflect, ReflectPathError<'p>>),
+    ) {
+        resolve_components_mut(self, &path.components, func)
+    }
 }
 
 #[derive(Debug)]
@@ -893,8 +1477,12 @@ pub enum SetFieldError {
         name: String,
         value: Box<dyn Reflect>,
     },
+    /// The type of the value does not match the type of the field.
     InvalidValue {
+        /// A name of the expected type (the type of the field).
         field_type_name: &'static str,
+        /// A name of the actual type of the value.
+        value_type_name: &'static str,
         value: Box<dyn Reflect>,
     },
 }
@@ -904,15 +1492,19 @@ impl std::error::Error for SetFieldError {}
 impl Display for SetFieldError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            SetFieldError::NoSuchField { name, value } => {
-                write!(f, "No such field as {name:?} for value {value:?}")
-            }
+            SetFieldError::NoSuchField { name, value } => write!(
+                f,
+                "No such field as {name:?}, the value of type {} was rejected",
+                value.type_name()
+            ),
             SetFieldError::InvalidValue {
                 field_type_name,
+                value_type_name,
                 value,
             } => write!(
                 f,
-                "Invalid value for field type {field_type_name}: {value:?}"
+                "Invalid value for field type {field_type_name}: {value:?} \
+                (value type is {value_type_name})"
             ),
         }
     }
@@ -931,20 +1523,31 @@ pub enum SetFieldByPathError<'p> {
     SetFieldError(SetFieldError),
 }
 
-impl std::error::Error for SetFieldByPathError<'_> {}
+impl std::error::Error for SetFieldByPathError<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SetFieldByPathError::SetFieldError(set_field_error) => Some(set_field_error),
+            _ => None,
+        }
+    }
+}
 
 impl Display for SetFieldByPathError<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            SetFieldByPathError::InvalidPath { value, reason } => {
-                write!(f, "Invalid path: {value:?}. Reason: {reason}")
-            }
+            SetFieldByPathError::InvalidPath { value, reason } => write!(
+                f,
+                "Invalid path: {reason}, the value of type {} was rejected",
+                value.type_name()
+            ),
             SetFieldByPathError::InvalidValue {
                 field_type_name,
                 value,
-            } => {
-                write!(f, "Invalid value: {value:?}. Type: {field_type_name}")
-            }
+            } => write!(
+                f,
+                "Invalid value of type {} for field type {field_type_name}",
+                value.type_name()
+            ),
             SetFieldByPathError::SetFieldError(set_field_error) => Display::fmt(set_field_error, f),
         }
     }
@@ -979,57 +1582,307 @@ impl dyn Reflect {
         self.as_any_mut(&mut |any| func(any.downcast_mut::<T>()))
     }
 
-    /// Sets a field by its path in the given entity. This method always uses [`Reflect::set_field`] which means,
-    /// that it will always call custom property setters.
-    #[inline]
-    pub fn set_field_by_path<'p>(
-        &mut self,
-        path: &'p str,
-        value: Box<dyn Reflect>,
-        func: &mut dyn FnMut(Result<Box<dyn Reflect>, SetFieldByPathError<'p>>),
+    /// Resolves multiple paths at once. Paths with common prefix are grouped, so shared ancestors
+    /// are resolved only once. The result for each path is the same as [`ResolvePath::resolve_path`]
+    /// would give, but the callback is not guaranteed to be called in the order of the paths.
+    #[allow(clippy::type_complexity)]
+    pub fn resolve_paths<'p>(
+        &self,
+        paths: &[&'p str],
+        func: &mut dyn FnMut(&'p str, Result<&dyn Reflect, ReflectPathError<'p>>),
     ) {
-        if let Some(separator_position) = path.rfind('.') {
-            let mut opt_value = Some(value);
-            let parent_path = &path[..separator_position];
-            let field = &path[(separator_position + 1)..];
-            self.resolve_path_mut(parent_path, &mut |result| match result {
-                Err(reason) => {
-                    func(Err(SetFieldByPathError::InvalidPath {
-                        reason,
-                        value: opt_value.take().unwrap(),
-                    }));
-                }
-                Ok(property) => {
-                    property.set_field(field, opt_value.take().unwrap(), &mut |result| match result
-                    {
-                        Ok(value) => func(Ok(value)),
-                        Err(err) => func(Err(SetFieldByPathError::SetFieldError(err))),
-                    })
-                }
-            });
-        } else {
-            self.set_field(path, value, &mut |result| match result {
-                Ok(value) => func(Ok(value)),
-                Err(err) => func(Err(SetFieldByPathError::SetFieldError(err))),
-            });
-        }
+        resolve_paths_grouped(
+            self,
+            paths.iter().map(|path| (*path, *path)).collect(),
+            func,
+        )
     }
 
-    pub fn enumerate_fields_recursively<F>(&self, func: &mut F, ignored_types: &[TypeId])
-    where
-        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect),
-    {
-        self.enumerate_fields_recursively_internal("", None, func, ignored_types)
+    /// Returns the names of the top-level fields of the value, in the order they are provided by
+    /// [`Reflect::fields_ref`]. Use [`Reflect::field_count`] to get just the number of fields.
+    pub fn field_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.fields_ref(&mut |fields| {
+            names.extend(fields.iter().map(|field| field.name.to_string()))
+        });
+        names
     }
 
-    fn enumerate_fields_recursively_internal<F>(
-        &self,
-        path: &str,
-        field_info: Option<&FieldRef>,
-        func: &mut F,
-        ignored_types: &[TypeId],
-    ) where
-        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect),
+    /// Clones the value. Unlike [`Reflect::try_clone_box`], it does not give up on values, that do not
+    /// implement [`Clone`] - such values are re-assembled from deep clones of their fields (or items)
+    /// using [`Reflect::set_field`], [`ReflectList::reflect_push`] and [`ReflectHashMap::reflect_insert`].
+    /// A non-cloneable value must be able to provide its default instance (see
+    /// [`Reflect::try_default_box`]), hidden fields of the value keep their default values.
+    ///
+    /// Returns `None` if some leaf value cannot be cloned, or some non-cloneable value cannot be
+    /// default-constructed.
+    pub fn reflect_clone_deep(&self) -> Option<Box<dyn Reflect>> {
+        if let Some(clone) = self.try_clone_box() {
+            return Some(clone);
+        }
+
+        let mut clone = self.try_default_box()?;
+        let mut succeeded = true;
+
+        // Enums must have the same active variant before the fields could be copied.
+        self.as_enum(&mut |source| {
+            if let Some(source) = source {
+                clone.as_enum_mut(&mut |target| {
+                    succeeded &=
+                        target.is_some_and(|target| target.set_variant(source.variant_name()));
+                });
+            }
+        });
+
+        let mut done = false;
+
+        self.as_list(&mut |source| {
+            if let Some(source) = source {
+                clone.as_list_mut(&mut |target| match target {
+                    Some(target) => {
+                        while target.reflect_pop().is_some() {}
+                        for i in 0..source.reflect_len() {
+                            let item = source
+                                .reflect_index(i)
+                                .and_then(|item| item.reflect_clone_deep());
+                            succeeded &= item.is_some_and(|item| target.reflect_push(item).is_ok());
+                        }
+                    }
+                    None => succeeded = false,
+                });
+
+                done = true;
+            }
+        });
+
+        if !done {
+            self.as_array(&mut |source| {
+                if let Some(source) = source {
+                    clone.as_array_mut(&mut |target| match target {
+                        Some(target) if target.reflect_len() == source.reflect_len() => {
+                            for i in 0..source.reflect_len() {
+                                let item = source
+                                    .reflect_index(i)
+                                    .and_then(|item| item.reflect_clone_deep());
+                                succeeded &= match (item, target.reflect_index_mut(i)) {
+                                    (Some(item), Some(slot)) => slot.set(item).is_ok(),
+                                    _ => false,
+                                };
+                            }
+                        }
+                        _ => succeeded = false,
+                    });
+
+                    done = true;
+                }
+            });
+        }
+
+        if !done {
+            self.as_hash_map(&mut |source| {
+                if let Some(source) = source {
+                    clone.as_hash_map_mut(&mut |target| match target {
+                        Some(target) => {
+                            for i in 0..source.reflect_len() {
+                                let Some((key, value)) = source.reflect_get_at(i) else {
+                                    continue;
+                                };
+                                match (key.reflect_clone_deep(), value.reflect_clone_deep()) {
+                                    (Some(key), Some(value)) => {
+                                        target.reflect_insert(key, value);
+                                    }
+                                    _ => succeeded = false,
+                                }
+                            }
+                        }
+                        None => succeeded = false,
+                    });
+
+                    done = true;
+                }
+            });
+        }
+
+        if !done {
+            self.fields_ref(&mut |fields| {
+                for field in fields {
+                    match field.value.field_value_as_reflect().reflect_clone_deep() {
+                        Some(value) => clone.set_field(field.name, value, &mut |result| {
+                            succeeded &= result.is_ok()
+                        }),
+                        None => succeeded = false,
+                    }
+                }
+            });
+        }
+
+        succeeded.then_some(clone)
+    }
+
+    /// Sets a field by its path in the given entity. This method always uses [`Reflect::set_field`] which means,
+    /// that it will always call custom property setters.
+    ///
+    /// On success, the callback receives the previous value of the field, regardless of whether the
+    /// field is a top-level one (`foo`) or a nested one (`foo.bar`). If the field has a custom setter
+    /// (`#[reflect(setter = ..)]`), the "previous value" is whatever the setter returns, so setters are
+    /// expected to return the value that was replaced.
+    #[inline]
+    pub fn set_field_by_path<'p>(
+        &mut self,
+        path: &'p str,
+        value: Box<dyn Reflect>,
+        func: &mut dyn FnMut(Result<Box<dyn Reflect>, SetFieldByPathError<'p>>),
+    ) {
+        if let Some((parent_path, field)) = split_last_field(path) {
+            let mut opt_value = Some(value);
+            let field = unescape_path_component(field);
+            self.resolve_path_mut(parent_path, &mut |result| match result {
+                Err(reason) => {
+                    func(Err(SetFieldByPathError::InvalidPath {
+                        reason,
+                        value: opt_value.take().unwrap(),
+                    }));
+                }
+                Ok(property) => property.set_field(
+                    &field,
+                    opt_value.take().unwrap(),
+                    &mut |result| match result {
+                        Ok(value) => func(Ok(value)),
+                        Err(err) => func(Err(SetFieldByPathError::SetFieldError(err))),
+                    },
+                ),
+            });
+        } else {
+            self.set_field(
+                &unescape_path_component(path),
+                value,
+                &mut |result| match result {
+                    Ok(value) => func(Ok(value)),
+                    Err(err) => func(Err(SetFieldByPathError::SetFieldError(err))),
+                },
+            );
+        }
+    }
+
+    /// Returns `true` if the field at the given path or any of its ancestors along the path is marked
+    /// as read-only (see [`FieldMetadata::read_only`]). Items of collections inherit the read-only
+    /// flag of the collection. Returns `false` if the path is invalid.
+    pub fn effective_read_only(&self, path: &str) -> bool {
+        let Ok((component, sub_path)) = Component::next(path) else {
+            return false;
+        };
+
+        let mut read_only = false;
+
+        if let Component::Field(name) = component {
+            if name.is_empty() {
+                return false;
+            }
+
+            let name = unescape_path_component(name);
+            self.fields_ref(&mut |fields| {
+                read_only = fields
+                    .iter()
+                    .any(|field| field.matches_name(&name) && field.read_only);
+            });
+        }
+
+        if read_only || sub_path.is_empty() {
+            return read_only;
+        }
+
+        component.resolve(self, &mut |result| {
+            if let Ok(child) = result {
+                read_only = child.effective_read_only(sub_path);
+            }
+        });
+
+        read_only
+    }
+
+    /// Calls the given function for the value itself and for every field (and item) of it, recursively.
+    /// Special characters of field names and hash map keys are escaped in the paths, so the paths
+    /// could be resolved back using [`Reflect::resolve_path`].
+    pub fn enumerate_fields_recursively<F>(&self, func: &mut F, ignored_types: &[TypeId])
+    where
+        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect),
+    {
+        self.enumerate_fields_recursively_internal("", None, 0, usize::MAX, func, ignored_types)
+    }
+
+    /// Same as [`Self::enumerate_fields_recursively`], but does not descend deeper than the given
+    /// depth. Depth `0` means the value itself, depth `1` - its top-level fields (or items of an
+    /// array or a hash map), and so on.
+    pub fn enumerate_fields_to_depth<F>(
+        &self,
+        max_depth: usize,
+        func: &mut F,
+        ignored_types: &[TypeId],
+    ) where
+        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect),
+    {
+        self.enumerate_fields_recursively_internal("", None, 0, max_depth, func, ignored_types)
+    }
+
+    /// Same as [`Self::enumerate_fields_recursively`], but skips read-only fields (see
+    /// [`FieldMetadata::read_only`]) along with their entire subtrees, because everything inside a
+    /// read-only field is read-only as well (see [`Self::effective_read_only`]). The last argument of
+    /// the callback is `true` for collections whose length cannot be changed: fixed-size arrays and
+    /// dynamic collections marked as [`FieldMetadata::immutable_collection`]. Items of such
+    /// collections are still reported and could be edited.
+    pub fn enumerate_editable_fields_recursively<F>(&self, func: &mut F, ignored_types: &[TypeId])
+    where
+        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect, bool),
+    {
+        fn is_length_locked(value: &dyn Reflect, field_info: Option<&FieldRef>) -> bool {
+            let (mut is_array, mut is_resizable) = (false, false);
+            value.as_array(&mut |array| is_array = array.is_some());
+            value.as_list(&mut |list| is_resizable |= list.is_some());
+            value.as_hash_map(&mut |hash_map| is_resizable |= hash_map.is_some());
+
+            if is_resizable {
+                field_info.is_some_and(|field_info| field_info.immutable_collection)
+            } else {
+                is_array
+            }
+        }
+
+        let mut skipped_path: Option<String> = None;
+        self.enumerate_fields_recursively(
+            &mut |path, field_info, value| {
+                // Skip the inner values of a read-only field.
+                if let Some(skipped_path) = skipped_path.as_ref() {
+                    if path == skipped_path
+                        || path
+                            .strip_prefix(skipped_path.as_str())
+                            .is_some_and(|rest| rest.starts_with(['.', '[']))
+                    {
+                        return;
+                    }
+                }
+                skipped_path = None;
+
+                if field_info.is_some_and(|field_info| field_info.read_only) {
+                    skipped_path = Some(path.to_string());
+                    return;
+                }
+
+                func(path, field_info, value, is_length_locked(value, field_info));
+            },
+            ignored_types,
+        )
+    }
+
+    fn enumerate_fields_recursively_internal<F>(
+        &self,
+        path: &str,
+        field_info: Option<&FieldRef>,
+        depth: usize,
+        max_depth: usize,
+        func: &mut F,
+        ignored_types: &[TypeId],
+    ) where
+        F: FnMut(&str, Option<&FieldRef>, &dyn Reflect),
     {
         if ignored_types.contains(&self.type_id()) {
             return;
@@ -1037,14 +1890,26 @@ impl dyn Reflect {
 
         func(path, field_info, self);
 
+        if depth >= max_depth {
+            return;
+        }
+
         let mut done = false;
 
         self.as_inheritable_variable(&mut |variable| {
             if let Some(variable) = variable {
                 // Inner variable might also contain inheritable variables, so continue iterating.
+                // The inner value has the same path, so the depth stays the same.
                 variable
                     .inner_value_ref()
-                    .enumerate_fields_recursively_internal(path, field_info, func, ignored_types);
+                    .enumerate_fields_recursively_internal(
+                        path,
+                        field_info,
+                        depth,
+                        max_depth,
+                        func,
+                        ignored_types,
+                    );
 
                 done = true;
             }
@@ -1063,6 +1928,8 @@ impl dyn Reflect {
                         item.enumerate_fields_recursively_internal(
                             &item_path,
                             field_info,
+                            depth + 1,
+                            max_depth,
                             func,
                             ignored_types,
                         );
@@ -1099,11 +1966,13 @@ impl dyn Reflect {
                             key_str.pop();
                         }
 
-                        let item_path = format!("{path}[{key_str}]");
+                        let item_path = format!("{path}[{}]", escape_path_component(&key_str));
 
                         value.enumerate_fields_recursively_internal(
                             &item_path,
                             field_info,
+                            depth + 1,
+                            max_depth,
                             func,
                             ignored_types,
                         );
@@ -1120,11 +1989,12 @@ impl dyn Reflect {
 
         self.fields_ref(&mut |fields| {
             for field in fields {
+                let name = escape_path_component(field.metadata.name);
                 let compound_path;
-                let field_path = if path.is_empty() {
-                    field.metadata.name
+                let field_path: &str = if path.is_empty() {
+                    &name
                 } else {
-                    compound_path = format!("{}.{}", path, field.metadata.name);
+                    compound_path = format!("{path}.{name}");
                     &compound_path
                 };
 
@@ -1134,6 +2004,8 @@ impl dyn Reflect {
                     .enumerate_fields_recursively_internal(
                         field_path,
                         Some(field),
+                        depth + 1,
+                        max_depth,
                         func,
                         ignored_types,
                     );
@@ -1282,6 +2154,222 @@ pub fn is_path_to_array_element(path: &str) -> bool {
     path.ends_with(']')
 }
 
+/// Compact description of a value, that is used by [`reflect_diff`] to compare values.
+#[derive(PartialEq)]
+enum DiffSnapshot {
+    /// A value without any fields or items, described by its `Debug` representation.
+    Leaf(String),
+    /// A value with fields or items, described by the names of its fields (or keys, or length).
+    Compound(String),
+}
+
+impl DiffSnapshot {
+    fn new(value: &dyn Reflect) -> Self {
+        let mut structure = None;
+
+        value.as_array(&mut |array| {
+            if let Some(array) = array {
+                structure = Some(format!("[{}]", array.reflect_len()));
+            }
+        });
+
+        if structure.is_none() {
+            value.as_hash_map(&mut |hash_map| {
+                if let Some(hash_map) = hash_map {
+                    let mut keys = (0..hash_map.reflect_len())
+                        .filter_map(|i| hash_map.reflect_get_at(i))
+                        .map(|(key, _)| format!("{key:?}"))
+                        .collect::<Vec<_>>();
+                    keys.sort();
+                    structure = Some(format!("{{{}}}", keys.join(", ")));
+                }
+            });
+        }
+
+        if structure.is_none() {
+            value.fields_ref(&mut |fields| {
+                if !fields.is_empty() {
+                    let names = fields
+                        .iter()
+                        .map(|field| field.metadata.name)
+                        .collect::<Vec<_>>();
+                    structure = Some(format!("({})", names.join(", ")));
+                }
+            });
+        }
+
+        match structure {
+            Some(structure) => Self::Compound(format!("{}{structure}", value.type_name())),
+            None => Self::Leaf(format!("{}: {value:?}", value.type_name())),
+        }
+    }
+}
+
+/// Computes a set of changes, that turns the value `a` into the value `b`. Both values are walked in
+/// lockstep (see [`Reflect::enumerate_fields_recursively`]) and every leaf value (a value without fields
+/// or items) of `b` that differs from the respective leaf of `a` is emitted as a pair of its path and
+/// a copy of the new value. Leaves are compared using [`Reflect::reflect_partial_eq`] if their types
+/// support it, otherwise their `Debug` representations are compared. If the structure
+/// of a value differs (arrays of different length, hash maps with different keys, different enum
+/// variants, etc.), the entire value is emitted instead of its leaves. An empty path means the entire
+/// value. Values of the ignored types are skipped, as well as the values that cannot be cloned (see
+/// [`Reflect::try_clone_box`]).
+///
+/// The changes could be applied back using [`apply_diff`].
+pub fn reflect_diff(
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+    ignored_types: &[TypeId],
+) -> Vec<(String, Box<dyn Reflect>)> {
+    fn is_inheritable_variable(value: &dyn Reflect) -> bool {
+        let mut is_variable = false;
+        value.as_inheritable_variable(&mut |variable| is_variable = variable.is_some());
+        is_variable
+    }
+
+    let mut snapshots = HashMap::new();
+    // Copies of the leaves of `a`, that could be compared using `reflect_partial_eq`.
+    let mut leaves = HashMap::new();
+    a.enumerate_fields_recursively(
+        &mut |path, _, value| {
+            // Inheritable variables are followed by their inner values with the same path.
+            if !is_inheritable_variable(value) {
+                let snapshot = DiffSnapshot::new(value);
+                if let DiffSnapshot::Leaf(_) = snapshot {
+                    if let Some(leaf) = value
+                        .try_clone_box()
+                        .filter(|leaf| leaf.reflect_partial_eq(value).is_some())
+                    {
+                        leaves.insert(path.to_string(), leaf);
+                    }
+                }
+                snapshots.insert(path.to_string(), snapshot);
+            }
+        },
+        ignored_types,
+    );
+
+    let mut changes = Vec::new();
+    let mut replaced_path: Option<String> = None;
+    b.enumerate_fields_recursively(
+        &mut |path, _, value| {
+            if is_inheritable_variable(value) {
+                return;
+            }
+
+            // Skip inner values of a value, that was emitted entirely.
+            if let Some(replaced_path) = replaced_path.as_ref() {
+                if replaced_path.is_empty()
+                    || path
+                        .strip_prefix(replaced_path.as_str())
+                        .is_some_and(|rest| rest.starts_with(['.', '[']))
+                {
+                    return;
+                }
+            }
+            replaced_path = None;
+
+            let snapshot = DiffSnapshot::new(value);
+            let unchanged = match leaves
+                .get(path)
+                .and_then(|leaf| leaf.reflect_partial_eq(value))
+            {
+                Some(equal) => equal,
+                None => snapshots.get(path) == Some(&snapshot),
+            };
+            if unchanged {
+                return;
+            }
+
+            if let Some(new_value) = value.try_clone_box() {
+                if let DiffSnapshot::Compound(_) = snapshot {
+                    replaced_path = Some(path.to_string());
+                }
+                changes.push((path.to_string(), new_value));
+            }
+        },
+        ignored_types,
+    );
+
+    changes
+}
+
+/// Applies a set of changes (for example, produced by [`reflect_diff`]) to the given value. Every
+/// change is a pair of a path and a new value, it is applied using [`Reflect::set_field`] (which
+/// means that custom property setters will be called), paths to array elements and the empty path
+/// (the entire value) are applied using [`Reflect::set`]. Failed changes do not stop the process,
+/// instead all errors are collected and returned. Path resolution errors are reported as
+/// [`SetFieldError::NoSuchField`] with the full path of the change.
+pub fn apply_diff(
+    target: &mut dyn Reflect,
+    changes: Vec<(String, Box<dyn Reflect>)>,
+) -> Vec<SetFieldByPathError<'static>> {
+    fn set_value(
+        field: &mut dyn Reflect,
+        value: Box<dyn Reflect>,
+    ) -> Result<(), SetFieldByPathError<'static>> {
+        field
+            .set(value)
+            .map(|_| ())
+            .map_err(|value| SetFieldByPathError::InvalidValue {
+                field_type_name: field.type_name(),
+                value,
+            })
+    }
+
+    let mut errors = Vec::new();
+
+    for (path, value) in changes {
+        let mut value = Some(value);
+
+        if path.is_empty() {
+            if let Err(error) = set_value(target, value.take().unwrap()) {
+                errors.push(error);
+            }
+        } else if is_path_to_array_element(&path) {
+            target.resolve_path_mut(&path, &mut |result| {
+                let result = match result {
+                    Ok(field) => set_value(field, value.take().unwrap()),
+                    Err(_) => Err(SetFieldByPathError::SetFieldError(
+                        SetFieldError::NoSuchField {
+                            name: path.clone(),
+                            value: value.take().unwrap(),
+                        },
+                    )),
+                };
+                if let Err(error) = result {
+                    errors.push(error);
+                }
+            });
+        } else {
+            target.set_field_by_path(&path, value.take().unwrap(), &mut |result| {
+                let error = match result {
+                    Ok(_) => return,
+                    Err(SetFieldByPathError::InvalidPath { value, .. }) => {
+                        SetFieldByPathError::SetFieldError(SetFieldError::NoSuchField {
+                            name: path.clone(),
+                            value,
+                        })
+                    }
+                    Err(SetFieldByPathError::InvalidValue {
+                        field_type_name,
+                        value,
+                    }) => SetFieldByPathError::InvalidValue {
+                        field_type_name,
+                        value,
+                    },
+                    Err(SetFieldByPathError::SetFieldError(error)) => {
+                        SetFieldByPathError::SetFieldError(error)
+                    }
+                };
+                errors.push(error);
+            });
+        }
+    }
+
+    errors
+}
+
 // Make it a trait?
 impl dyn ReflectList {
     pub fn get_reflect_index<T: Reflect>(&self, index: usize, func: &mut dyn FnMut(Option<&T>)) {
@@ -1335,6 +2423,10 @@ macro_rules! blank_reflect {
             ""
         }
 
+        fn type_source_path(&self) -> &'static str {
+            file!()
+        }
+
         fn assembly_name(&self) -> &'static str {
             env!("CARGO_PKG_NAME")
         }
@@ -1415,6 +2507,10 @@ macro_rules! delegate_reflect {
             self.deref().doc()
         }
 
+        fn type_source_path(&self) -> &'static str {
+            self.deref().type_source_path()
+        }
+
         fn assembly_name(&self) -> &'static str {
             self.deref().assembly_name()
         }
@@ -1478,6 +2574,30 @@ macro_rules! delegate_reflect {
         fn as_list_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectList>)) {
             self.deref_mut().as_list_mut(func)
         }
+
+        fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+            self.deref().as_enum(func)
+        }
+
+        fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+            self.deref_mut().as_enum_mut(func)
+        }
+
+        fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+            self.deref().as_option(func)
+        }
+
+        fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+            self.deref_mut().as_option_mut(func)
+        }
+
+        fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+            self.deref().reflect_partial_eq(other)
+        }
+
+        fn field_count(&self) -> usize {
+            self.deref().field_count()
+        }
     };
 }
 
@@ -1565,6 +2685,30 @@ macro_rules! newtype_reflect {
         fn as_hash_map_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectHashMap>)) {
             self.0.as_hash_map_mut(func)
         }
+
+        fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+            self.0.as_enum(func)
+        }
+
+        fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+            self.0.as_enum_mut(func)
+        }
+
+        fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+            self.0.as_option(func)
+        }
+
+        fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+            self.0.as_option_mut(func)
+        }
+
+        fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+            self.0.reflect_partial_eq(other)
+        }
+
+        fn field_count(&self) -> usize {
+            self.0.field_count()
+        }
     };
 }
 
@@ -1577,7 +2721,8 @@ pub use delegate_reflect;
 #[cfg(test)]
 mod test {
     use super::prelude::*;
-    use std::any::TypeId;
+    use std::any::{Any, TypeId};
+    use std::cell::Cell;
     use std::collections::HashMap;
 
     #[derive(Reflect, Clone, Default, Debug)]
@@ -1627,6 +2772,112 @@ mod test {
         assert_eq!(names[9], "hash_map[Foobar].payload");
     }
 
+    #[test]
+    fn enumerate_fields_to_depth() {
+        let foo = Foo {
+            bar: Default::default(),
+            baz: 0.0,
+            collection: vec![Item::default()],
+            hash_map: [("Foobar".to_string(), Item::default())].into(),
+        };
+
+        let enumerate = |max_depth| {
+            let mut names = Vec::new();
+            (&foo as &dyn Reflect).enumerate_fields_to_depth(
+                max_depth,
+                &mut |path, _, _| names.push(path.to_string()),
+                &[],
+            );
+            names
+        };
+
+        assert_eq!(enumerate(0), [""]);
+        assert_eq!(enumerate(1), ["", "bar", "baz", "collection", "hash_map"]);
+        assert_eq!(
+            enumerate(2),
+            [
+                "",
+                "bar",
+                "bar.stuff",
+                "baz",
+                "collection",
+                "collection[0]",
+                "hash_map",
+                "hash_map[Foobar]"
+            ]
+        );
+    }
+
+    #[test]
+    fn enumerate_editable_fields_recursively() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Form {
+            #[reflect(read_only)]
+            id: u32,
+            name: String,
+            #[reflect(read_only)]
+            locked: Bar,
+            #[reflect(immutable_collection)]
+            slots: Vec<Item>,
+            items: Vec<Item>,
+            fixed: [u32; 1],
+        }
+
+        let form = Form {
+            slots: vec![Item::default()],
+            items: vec![Item::default()],
+            ..Default::default()
+        };
+
+        let mut fields = Vec::new();
+        (&form as &dyn Reflect).enumerate_editable_fields_recursively(
+            &mut |path, _, _, length_locked| fields.push((path.to_string(), length_locked)),
+            &[],
+        );
+
+        let expected = [
+            ("", false),
+            ("name", false),
+            ("slots", true),
+            ("slots[0]", false),
+            ("slots[0].payload", false),
+            ("items", false),
+            ("items[0]", false),
+            ("items[0].payload", false),
+            ("fixed", true),
+            ("fixed[0]", false),
+        ];
+        assert_eq!(
+            fields,
+            expected.map(|(path, locked)| (path.to_string(), locked))
+        );
+    }
+
+    #[test]
+    fn effective_read_only() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Container {
+            #[reflect(read_only)]
+            locked: Foo,
+            unlocked: Foo,
+            #[reflect(read_only)]
+            items: Vec<Item>,
+        }
+
+        let container = Container {
+            items: vec![Item::default()],
+            ..Default::default()
+        };
+        let container = &container as &dyn Reflect;
+
+        assert!(container.effective_read_only("locked"));
+        assert!(container.effective_read_only("locked.bar.stuff"));
+        assert!(container.effective_read_only("items[0].payload"));
+        assert!(!container.effective_read_only("unlocked"));
+        assert!(!container.effective_read_only("unlocked.bar.stuff"));
+        assert!(!container.effective_read_only("unknown.field"));
+    }
+
     #[derive(Reflect, Clone, Debug)]
     #[reflect(derived_type = "Derived")]
     struct Base;
@@ -1639,4 +2890,904 @@ mod test {
         let base = Base;
         assert_eq!(base.query_derived_types(), &[TypeId::of::<Derived>()])
     }
+
+    #[test]
+    fn reflect_diff() {
+        let a = Foo {
+            bar: Bar {
+                stuff: "Foo".to_string(),
+            },
+            baz: 1.0,
+            collection: vec![Item { payload: 1 }, Item { payload: 2 }],
+            hash_map: [
+                ("Foo".to_string(), Item { payload: 1 }),
+                ("Bar".to_string(), Item { payload: 2 }),
+            ]
+            .into(),
+        };
+
+        assert!(super::reflect_diff(&a, &a.clone(), &[]).is_empty());
+
+        let mut b = a.clone();
+        b.bar.stuff = "Bar".to_string();
+        b.collection[1].payload = 3;
+        b.hash_map.get_mut("Bar").unwrap().payload = 4;
+
+        let diff = super::reflect_diff(&a, &b, &[]);
+        let paths = diff
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "bar.stuff",
+                "collection[1].payload",
+                "hash_map[Bar].payload"
+            ]
+        );
+        assert_eq!(
+            format!("{:?}", diff[0].1),
+            format!("{:?}", "Bar".to_string())
+        );
+        assert_eq!(format!("{:?}", diff[1].1), "3");
+
+        // Collections of different size are emitted entirely.
+        let mut c = a.clone();
+        c.collection.push(Item { payload: 5 });
+        c.hash_map.insert("Baz".to_string(), Item::default());
+        c.baz = 2.0;
+
+        let diff = super::reflect_diff(&a, &c, &[]);
+        let paths = diff
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["baz", "collection", "hash_map"]);
+        assert_eq!(format!("{:?}", diff[1].1), format!("{:?}", c.collection));
+
+        // The reverse direction must work as well.
+        let diff = super::reflect_diff(&c, &a, &[]);
+        let paths = diff
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["baz", "collection", "hash_map"]);
+
+        // Ignored types must not be compared.
+        let diff = super::reflect_diff(&a, &c, &[TypeId::of::<Vec<Item>>()]);
+        let paths = diff
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["baz", "hash_map"]);
+    }
+
+    #[test]
+    fn apply_diff() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Container {
+            foo: Foo,
+            numbers: Vec<u32>,
+            items: HashMap<u32, Item>,
+        }
+
+        let a = Container {
+            foo: Foo {
+                baz: 1.0,
+                collection: vec![Item { payload: 1 }],
+                hash_map: [("Foo".to_string(), Item { payload: 1 })].into(),
+                ..Default::default()
+            },
+            numbers: vec![1, 2, 3],
+            items: [(1, Item { payload: 1 }), (2, Item { payload: 2 })].into(),
+        };
+
+        let mut b = a.clone();
+        b.foo.bar.stuff = "Bar".to_string();
+        b.foo.collection.push(Item { payload: 2 });
+        b.foo.hash_map.get_mut("Foo").unwrap().payload = 3;
+        b.numbers[1] = 20;
+        b.items.remove(&1);
+
+        let mut target = a.clone();
+        let diff = super::reflect_diff(&target, &b, &[]);
+        assert!(!diff.is_empty());
+        let errors = super::apply_diff(&mut target, diff);
+        assert!(errors.is_empty());
+        assert!(super::reflect_diff(&target, &b, &[]).is_empty());
+
+        assert_eq!(target.foo.bar.stuff, "Bar");
+        assert_eq!(target.foo.collection.len(), 2);
+        assert_eq!(target.foo.collection[1].payload, 2);
+        assert_eq!(target.foo.hash_map["Foo"].payload, 3);
+        assert_eq!(target.numbers, [1, 20, 3]);
+        assert_eq!(target.items.len(), 1);
+        assert_eq!(target.items[&2].payload, 2);
+
+        // Invalid changes must not prevent valid ones from being applied.
+        let errors = super::apply_diff(
+            &mut target,
+            vec![
+                ("foo.unknown".to_string(), Box::new(1u32)),
+                ("foo.baz".to_string(), Box::new(1u32)),
+                ("numbers[10]".to_string(), Box::new(1u32)),
+                ("foo.bar.stuff".to_string(), Box::new("Baz".to_string())),
+            ],
+        );
+        assert_eq!(errors.len(), 3);
+        assert_eq!(target.foo.bar.stuff, "Baz");
+        assert_eq!(target.foo.baz, 1.0);
+    }
+
+    #[test]
+    fn reflect_diff_escaped_paths() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Escaped {
+            #[reflect(name = "a.b")]
+            a_b: u32,
+            map: HashMap<String, u32>,
+        }
+
+        let a = Escaped {
+            a_b: 1,
+            map: [("x]y".to_string(), 2), ("config.main".to_string(), 3)].into(),
+        };
+        let mut b = a.clone();
+        b.a_b = 10;
+        *b.map.get_mut("x]y").unwrap() = 20;
+        *b.map.get_mut("config.main").unwrap() = 30;
+
+        let diff = super::reflect_diff(&a, &b, &[]);
+        let mut paths = diff
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, ["a\\.b", "map[config\\.main]", "map[x\\]y]"]);
+
+        let mut target = a.clone();
+        assert!(super::apply_diff(&mut target, diff).is_empty());
+        assert_eq!(target.a_b, 10);
+        assert_eq!(target.map["x]y"], 20);
+        assert_eq!(target.map["config.main"], 30);
+    }
+
+    #[test]
+    fn escaped_path_components() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Escaped {
+            #[reflect(name = "a.b")]
+            a_b: u32,
+            map: HashMap<String, u32>,
+            nested: Vec<HashMap<String, u32>>,
+        }
+
+        let mut escaped = Escaped {
+            a_b: 1,
+            map: [("x[y]".to_string(), 2), ("config.main".to_string(), 3)].into(),
+            nested: vec![[("x.y".to_string(), 4)].into()],
+        };
+        let escaped = &mut escaped as &mut dyn Reflect;
+
+        let components = super::path_to_components("a\\.b.map[x\\[y\\]].nested[0]");
+        assert_eq!(components.len(), 5);
+        assert!(matches!(components[0], super::Component::Field("a\\.b")));
+        assert_eq!(components[0].unescaped(), "a.b");
+        assert!(matches!(components[1], super::Component::Field("map")));
+        assert!(matches!(components[2], super::Component::Index("x\\[y\\]")));
+        assert_eq!(components[2].unescaped(), "x[y]");
+        assert!(matches!(components[3], super::Component::Field("nested")));
+        assert!(matches!(components[4], super::Component::Index("0")));
+
+        let resolve = |value: &dyn Reflect, path: &str| {
+            let mut result = None;
+            value.get_resolve_path::<u32>(path, &mut |value| result = value.ok().copied());
+            result
+        };
+
+        assert_eq!(resolve(escaped, "a\\.b"), Some(1));
+        assert_eq!(resolve(escaped, "a.b"), None);
+        assert_eq!(resolve(escaped, "map[x\\[y\\]]"), Some(2));
+        assert_eq!(resolve(escaped, "map[config.main]"), Some(3));
+        assert_eq!(resolve(escaped, "map[config\\.main]"), Some(3));
+        assert_eq!(resolve(escaped, "nested[0][x.y]"), Some(4));
+
+        escaped.set_field_by_path("a\\.b", Box::new(10u32), &mut |result| {
+            assert!(result.is_ok())
+        });
+        assert_eq!(resolve(escaped, "a\\.b"), Some(10));
+    }
+
+    #[test]
+    fn resolve_cached() {
+        let mut foo = Foo {
+            bar: Bar {
+                stuff: "Stuff".to_string(),
+            },
+            collection: vec![Item { payload: 1 }, Item { payload: 2 }],
+            hash_map: [("Foo".to_string(), Item { payload: 3 })].into(),
+            ..Default::default()
+        };
+
+        for (path, expected) in [("collection[1].payload", 2), ("hash_map[Foo].payload", 3)] {
+            let resolved = ResolvedPath::new(path).unwrap();
+            assert_eq!(resolved.path(), path);
+            assert_eq!(resolved.components().count(), 3);
+
+            let mut value = None;
+            foo.resolve_cached(&resolved, &mut |result| {
+                result
+                    .unwrap()
+                    .downcast_ref::<u32>(&mut |v| value = v.copied())
+            });
+            assert_eq!(value, Some(expected));
+        }
+
+        let resolved = ResolvedPath::new("bar.stuff").unwrap();
+        foo.resolve_cached_mut(&resolved, &mut |result| {
+            result.unwrap().set(Box::new("Other".to_string())).unwrap();
+        });
+        assert_eq!(foo.bar.stuff, "Other");
+
+        let resolved = ResolvedPath::new("collection[5].payload").unwrap();
+        foo.resolve_cached(&resolved, &mut |result| {
+            assert!(matches!(
+                result,
+                Err(super::ReflectPathError::NoItemForIndex { s: "5" })
+            ))
+        });
+
+        assert!(matches!(
+            ResolvedPath::new("collection[0"),
+            Err(super::ReflectPathError::UnclosedBrackets { .. })
+        ));
+    }
+
+    #[test]
+    fn resolve_integer_hash_map_key() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct IntMap {
+            map: HashMap<u32, Item>,
+            signed: HashMap<i64, Item>,
+        }
+
+        let mut int_map = IntMap {
+            map: [(5, Item { payload: 1 }), (7, Item { payload: 2 })].into(),
+            signed: [(-3, Item { payload: 3 })].into(),
+        };
+
+        let resolve = |path: &str| {
+            let mut value = None;
+            int_map.resolve_path(path, &mut |result| {
+                if let Ok(field) = result {
+                    field.downcast_ref::<u32>(&mut |v| value = v.copied())
+                }
+            });
+            value
+        };
+        assert_eq!(resolve("map[5].payload"), Some(1));
+        assert_eq!(resolve("map[7].payload"), Some(2));
+        assert_eq!(resolve("signed[-3].payload"), Some(3));
+        assert_eq!(resolve("map[6].payload"), None);
+
+        int_map.resolve_path_mut("map[5].payload", &mut |result| {
+            result.unwrap().set(Box::new(10u32)).unwrap();
+        });
+        assert_eq!(int_map.map[&5].payload, 10);
+
+        int_map.resolve_path("map[6]", &mut |result| {
+            assert!(matches!(
+                result,
+                Err(super::ReflectPathError::NoItemForIndex { s: "6" })
+            ))
+        });
+    }
+
+    #[test]
+    #[ignore = "benchmark"]
+    fn resolve_cached_benchmark() {
+        let foo = Foo {
+            collection: vec![Item { payload: 1 }],
+            ..Default::default()
+        };
+        let foo = &foo as &dyn Reflect;
+
+        const PATH: &str = "collection[0].payload";
+        const ITERATIONS: usize = 100_000;
+
+        let mut sum = 0;
+        let start = std::time::Instant::now();
+        for _ in 0..ITERATIONS {
+            foo.resolve_path(PATH, &mut |result| {
+                result
+                    .unwrap()
+                    .downcast_ref::<u32>(&mut |v| sum += v.unwrap())
+            });
+        }
+        let uncached = start.elapsed();
+
+        let resolved = ResolvedPath::new(PATH).unwrap();
+        let start = std::time::Instant::now();
+        for _ in 0..ITERATIONS {
+            foo.resolve_cached(&resolved, &mut |result| {
+                result
+                    .unwrap()
+                    .downcast_ref::<u32>(&mut |v| sum += v.unwrap())
+            });
+        }
+        let cached = start.elapsed();
+
+        assert_eq!(sum, 2 * ITERATIONS as u32);
+        assert!(
+            cached < uncached,
+            "Uncached: {uncached:?}, cached: {cached:?}"
+        );
+    }
+
+    #[test]
+    fn field_count_and_names() {
+        let foo = Foo::default();
+        let foo = &foo as &dyn Reflect;
+
+        assert_eq!(foo.field_count(), 4);
+        assert_eq!(foo.field_names(), ["bar", "baz", "collection", "hash_map"]);
+
+        let item = Item::default();
+        assert_eq!((&item as &dyn Reflect).field_names(), ["payload"]);
+    }
+
+    #[test]
+    fn reflect_clone_deep() {
+        #[derive(Reflect, Default, Debug)]
+        #[reflect(non_cloneable)]
+        struct NonCloneable {
+            foo: Foo,
+            numbers: Vec<u32>,
+            map: HashMap<String, Item>,
+            #[reflect(hidden)]
+            hidden: u32,
+        }
+
+        #[derive(Reflect, Debug)]
+        #[reflect(non_cloneable)]
+        struct NoDefault {
+            value: u32,
+        }
+
+        let value = NonCloneable {
+            foo: Foo {
+                baz: 1.0,
+                collection: vec![Item { payload: 1 }],
+                ..Default::default()
+            },
+            numbers: vec![1, 2, 3],
+            map: [("Foo".to_string(), Item { payload: 2 })].into(),
+            hidden: 123,
+        };
+        let value = &value as &dyn Reflect;
+
+        assert!(value.try_clone_box().is_none());
+
+        let clone = value.reflect_clone_deep().unwrap();
+        let clone = clone.downcast::<NonCloneable>().unwrap();
+        assert_eq!(clone.foo.baz, 1.0);
+        assert_eq!(clone.foo.collection.len(), 1);
+        assert_eq!(clone.foo.collection[0].payload, 1);
+        assert_eq!(clone.numbers, [1, 2, 3]);
+        assert_eq!(clone.map["Foo"].payload, 2);
+        assert_eq!(clone.hidden, 0);
+
+        let no_default = NoDefault { value: 1 };
+        assert!((&no_default as &dyn Reflect).reflect_clone_deep().is_none());
+    }
+
+    #[test]
+    fn tuples() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Tuples {
+            pair: (f32, String),
+            octet: (u8, u16, u32, u64, i8, i16, i32, bool),
+        }
+
+        let mut value = Tuples {
+            pair: (1.0, "Foo".to_string()),
+            ..Default::default()
+        };
+
+        let mut first = None;
+        value.get_resolve_path::<f32>("pair[0]", &mut |result| first = result.ok().copied());
+        assert_eq!(first, Some(1.0));
+
+        value.get_resolve_path_mut::<String>("pair[1]", &mut |result| {
+            *result.unwrap() = "Bar".to_string()
+        });
+        assert_eq!(value.pair.1, "Bar");
+
+        value.resolve_path_mut("octet[7]", &mut |result| {
+            assert!(result.unwrap().set(Box::new(true)).is_ok())
+        });
+        assert!(value.octet.7);
+
+        value.resolve_path("pair[2]", &mut |result| {
+            assert!(matches!(
+                result,
+                Err(super::ReflectPathError::NoItemForIndex { s: "2" })
+            ))
+        });
+
+        value.resolve_path("octet", &mut |result| {
+            assert_eq!(result.unwrap().field_count(), 8)
+        });
+    }
+
+    #[test]
+    fn reflect_option() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Optional {
+            item: Option<Item>,
+        }
+
+        let mut value = Optional {
+            item: Some(Item { payload: 1 }),
+        };
+
+        let mut payload = None;
+        value.get_resolve_path::<u32>("item.Some@0.payload", &mut |result| {
+            payload = result.ok().copied()
+        });
+        assert_eq!(payload, Some(1));
+
+        value.resolve_path_mut("item", &mut |result| {
+            result.unwrap().as_option_mut(&mut |option| {
+                let option = option.unwrap();
+                assert!(option.reflect_is_some());
+                assert!(option.reflect_inner().unwrap().is::<Item>());
+
+                let previous = option.reflect_set_none().unwrap();
+                assert_eq!(previous.take::<Item>().unwrap().payload, 1);
+                assert!(!option.reflect_is_some());
+                assert!(option.reflect_inner().is_none());
+
+                assert!(option.reflect_set_some(Box::new(123u32)).is_err());
+                assert!(option
+                    .reflect_set_some(Box::new(Item { payload: 2 }))
+                    .is_ok());
+            })
+        });
+        assert_eq!(value.item.as_ref().map(|item| item.payload), Some(2));
+
+        value.as_option(&mut |option| assert!(option.is_none()));
+    }
+
+    #[test]
+    fn reflect_partial_eq() {
+        #[derive(Reflect, Clone, Default, Debug, PartialEq)]
+        #[reflect(partial_eq)]
+        struct Inner {
+            value: f32,
+            name: String,
+        }
+
+        #[derive(Reflect, Clone, Default, Debug, PartialEq)]
+        #[reflect(partial_eq)]
+        struct Outer {
+            inner: Inner,
+            items: Vec<u32>,
+        }
+
+        let a = Outer {
+            inner: Inner {
+                value: 1.0,
+                name: "Inner".to_string(),
+            },
+            items: vec![1, 2, 3],
+        };
+        let mut b = a.clone();
+        assert_eq!(a.reflect_partial_eq(&b), Some(true));
+
+        b.inner.value = 1.0 + f32::EPSILON;
+        assert_eq!(a.reflect_partial_eq(&b), Some(false));
+        assert_eq!(
+            a.inner.value.reflect_partial_eq(&b.inner.value),
+            Some(false)
+        );
+
+        b.inner = a.inner.clone();
+        b.items.push(4);
+        assert_eq!(a.reflect_partial_eq(&b), Some(false));
+        assert_eq!(a.inner.reflect_partial_eq(&b.inner), Some(true));
+
+        // Different types cannot be compared.
+        assert_eq!(a.reflect_partial_eq(&a.inner), None);
+        assert_eq!(1u32.reflect_partial_eq(&1u64), None);
+        assert_eq!(f32::NAN.reflect_partial_eq(&f32::NAN), Some(false));
+
+        // The type does not opt in.
+        let foo = Foo::default();
+        assert_eq!(foo.reflect_partial_eq(&foo.clone()), None);
+    }
+
+    #[test]
+    fn resolve_paths() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Inner {
+            x: u32,
+            y: u32,
+            z: String,
+        }
+
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Outer {
+            bar: Inner,
+            baz: f32,
+        }
+
+        // Counts accesses to the fields of the root.
+        #[derive(Clone, Default, Debug)]
+        struct Counting {
+            outer: Outer,
+            hits: Cell<usize>,
+        }
+
+        impl Reflect for Counting {
+            fn source_path() -> &'static str {
+                file!()
+            }
+
+            fn derived_types() -> &'static [TypeId] {
+                &[]
+            }
+
+            fn try_clone_box(&self) -> Option<Box<dyn Reflect>> {
+                Some(Box::new(self.clone()))
+            }
+
+            fn query_derived_types(&self) -> &'static [TypeId] {
+                Self::derived_types()
+            }
+
+            fn type_name(&self) -> &'static str {
+                std::any::type_name::<Self>()
+            }
+
+            fn doc(&self) -> &'static str {
+                ""
+            }
+
+            fn fields_ref(&self, func: &mut dyn FnMut(&[FieldRef])) {
+                self.hits.set(self.hits.get() + 1);
+                self.outer.fields_ref(func)
+            }
+
+            fn fields_mut(&mut self, func: &mut dyn FnMut(&mut [FieldMut])) {
+                self.outer.fields_mut(func)
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self, func: &mut dyn FnMut(&dyn Any)) {
+                func(self)
+            }
+
+            fn as_any_mut(&mut self, func: &mut dyn FnMut(&mut dyn Any)) {
+                func(self)
+            }
+
+            fn as_reflect(&self, func: &mut dyn FnMut(&dyn Reflect)) {
+                func(self)
+            }
+
+            fn as_reflect_mut(&mut self, func: &mut dyn FnMut(&mut dyn Reflect)) {
+                func(self)
+            }
+
+            fn set(
+                &mut self,
+                value: Box<dyn Reflect>,
+            ) -> Result<Box<dyn Reflect>, Box<dyn Reflect>> {
+                let this = std::mem::replace(self, value.take()?);
+                Ok(Box::new(this))
+            }
+
+            fn type_source_path(&self) -> &'static str {
+                file!()
+            }
+
+            fn assembly_name(&self) -> &'static str {
+                env!("CARGO_PKG_NAME")
+            }
+
+            fn type_assembly_name() -> &'static str {
+                env!("CARGO_PKG_NAME")
+            }
+        }
+
+        let counting = Counting {
+            outer: Outer {
+                bar: Inner {
+                    x: 1,
+                    y: 2,
+                    z: "z".to_string(),
+                },
+                baz: 3.0,
+            },
+            hits: Default::default(),
+        };
+        let value = &counting as &dyn Reflect;
+
+        let paths = ["bar.x", "baz", "bar.y", "bar.z", "bar.w", "bar[", "bar"];
+
+        let mut expected = HashMap::new();
+        for path in paths {
+            value.resolve_path(path, &mut |result| {
+                expected.insert(path, format!("{:?}", result));
+            });
+        }
+
+        let hits_before = counting.hits.get();
+        let mut actual = HashMap::new();
+        value.resolve_paths(&paths, &mut |path, result| {
+            assert!(actual.insert(path, format!("{:?}", result)).is_none());
+        });
+
+        assert_eq!(actual, expected);
+        // `bar` and `baz` are resolved once each.
+        assert_eq!(counting.hits.get() - hits_before, 2);
+    }
+
+    #[test]
+    fn hash_map_remove_at() {
+        let mut map: HashMap<String, u32> = [("a".to_string(), 1), ("b".to_string(), 2)].into();
+        let map_ref = &mut map as &mut dyn ReflectHashMap;
+
+        let (key, value) = map_ref.reflect_get_at(1).unwrap();
+        let (key, value) = (
+            key.try_clone_box().unwrap().take::<String>().unwrap(),
+            value.try_clone_box().unwrap().take::<u32>().unwrap(),
+        );
+
+        let mut removed = None;
+        map_ref.reflect_remove_at(1, &mut |entry| removed = entry);
+        let (removed_key, removed_value) = removed.unwrap();
+        assert_eq!(removed_key.take::<String>().unwrap(), key);
+        assert_eq!(removed_value.take::<u32>().unwrap(), value);
+        assert_eq!(map.len(), 1);
+        assert!(!map.contains_key(&key));
+
+        let map_ref = &mut map as &mut dyn ReflectHashMap;
+        let mut removed = Some((
+            Box::new(()) as Box<dyn Reflect>,
+            Box::new(()) as Box<dyn Reflect>,
+        ));
+        map_ref.reflect_remove_at(1, &mut |entry| removed = entry);
+        assert!(removed.is_none());
+    }
+
+    #[test]
+    fn fixed_size_array() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Arrays {
+            arr: [u32; 4],
+        }
+
+        let mut value = Arrays { arr: [1, 2, 3, 4] };
+
+        let mut result = None;
+        value.get_resolve_path::<u32>("arr[1]", &mut |value| result = value.ok().copied());
+        assert_eq!(result, Some(2));
+
+        value.get_resolve_path_mut::<u32>("arr[3]", &mut |value| *value.unwrap() = 10);
+        assert_eq!(value.arr, [1, 2, 3, 10]);
+
+        value.resolve_path("arr[4]", &mut |result| {
+            assert!(matches!(
+                result,
+                Err(super::ReflectPathError::NoItemForIndex { s: "4" })
+            ))
+        });
+
+        // Fixed-size arrays cannot change their length.
+        value.resolve_path("arr", &mut |result| {
+            let arr = result.unwrap();
+            assert_eq!(arr.field_count(), 4);
+            arr.as_array(&mut |array| assert_eq!(array.map(|a| a.reflect_len()), Some(4)));
+            arr.as_list(&mut |list| assert!(list.is_none()));
+        });
+    }
+
+    #[test]
+    fn set_field_error_messages() {
+        let mut foo = Foo::default();
+
+        let mut message = String::new();
+        foo.set_field("qux", Box::new(1u32), &mut |result| {
+            message = result.unwrap_err().to_string()
+        });
+        assert!(message.contains("qux"), "{message}");
+        assert!(message.contains("u32"), "{message}");
+
+        foo.set_field("baz", Box::new(1u32), &mut |result| {
+            message = result.unwrap_err().to_string()
+        });
+        assert!(message.contains("f32"), "{message}");
+        assert!(message.contains("u32"), "{message}");
+
+        let foo = &mut foo as &mut dyn Reflect;
+        foo.set_field_by_path("bar.qux", Box::new(1u32), &mut |result| {
+            message = result.unwrap_err().to_string()
+        });
+        assert!(message.contains("qux"), "{message}");
+        assert!(message.contains("u32"), "{message}");
+
+        foo.set_field_by_path("bar.stuff", Box::new(1u32), &mut |result| {
+            message = result.unwrap_err().to_string()
+        });
+        assert!(message.contains("String"), "{message}");
+    }
+
+    #[test]
+    fn set_field_by_path_returns_previous_value() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct WithSetter {
+            #[reflect(setter = "set_value")]
+            value: u32,
+        }
+
+        impl WithSetter {
+            fn set_value(&mut self, value: u32) -> u32 {
+                std::mem::replace(&mut self.value, value)
+            }
+        }
+
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Outer {
+            inner: WithSetter,
+            #[reflect(setter = "set_value")]
+            value: u32,
+        }
+
+        impl Outer {
+            fn set_value(&mut self, value: u32) -> u32 {
+                std::mem::replace(&mut self.value, value)
+            }
+        }
+
+        fn set(target: &mut dyn Reflect, path: &str, value: Box<dyn Reflect>) -> Box<dyn Reflect> {
+            let mut old = None;
+            target.set_field_by_path(path, value, &mut |result| old = Some(result.unwrap()));
+            old.unwrap()
+        }
+
+        fn take<T: Reflect>(value: Box<dyn Reflect>) -> T {
+            value.take::<T>().ok().unwrap()
+        }
+
+        let mut foo = Foo {
+            bar: Bar {
+                stuff: "Stuff".to_string(),
+            },
+            baz: 1.0,
+            ..Default::default()
+        };
+
+        // Top-level field.
+        assert_eq!(take::<f32>(set(&mut foo, "baz", Box::new(2.0f32))), 1.0);
+        assert_eq!(foo.baz, 2.0);
+
+        // Nested field.
+        assert_eq!(
+            take::<String>(set(&mut foo, "bar.stuff", Box::new("Other".to_string()))),
+            "Stuff"
+        );
+        assert_eq!(foo.bar.stuff, "Other");
+
+        // Top-level and nested fields with custom setters.
+        let mut outer = Outer {
+            inner: WithSetter { value: 1 },
+            value: 2,
+        };
+        assert_eq!(take::<u32>(set(&mut outer, "value", Box::new(20u32))), 2);
+        assert_eq!(outer.value, 20);
+        assert_eq!(
+            take::<u32>(set(&mut outer, "inner.value", Box::new(10u32))),
+            1
+        );
+        assert_eq!(outer.inner.value, 10);
+    }
+
+    #[test]
+    fn field_alias() {
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Inner {
+            #[reflect(alias = "old_value", alias = "older_value")]
+            value: u32,
+        }
+
+        #[derive(Reflect, Clone, Default, Debug)]
+        struct Outer {
+            #[reflect(alias = "old_inner")]
+            inner: Inner,
+        }
+
+        let mut outer = Outer {
+            inner: Inner { value: 1 },
+        };
+
+        for path in [
+            "inner.value",
+            "inner.old_value",
+            "old_inner.value",
+            "old_inner.older_value",
+        ] {
+            let mut result = None;
+            outer.get_resolve_path::<u32>(path, &mut |value| result = value.ok().copied());
+            assert_eq!(result, Some(1), "{path}");
+        }
+
+        let mut found = false;
+        outer
+            .inner
+            .field("old_value", &mut |field| found = field.is_some());
+        assert!(found);
+
+        outer.inner.field_mut("older_value", &mut |field| {
+            field.unwrap().set(Box::new(2u32)).ok().unwrap();
+        });
+        assert_eq!(outer.inner.value, 2);
+
+        let mut previous = None;
+        (&mut outer as &mut dyn Reflect).set_field_by_path(
+            "old_inner.old_value",
+            Box::new(3u32),
+            &mut |result| previous = result.ok().and_then(|value| value.take::<u32>().ok()),
+        );
+        assert_eq!(previous, Some(2));
+        assert_eq!(outer.inner.value, 3);
+
+        let mut missing = true;
+        outer
+            .inner
+            .field("unknown", &mut |field| missing = field.is_none());
+        assert!(missing);
+    }
+
+    #[test]
+    fn field_metadata_new() {
+        static METADATA: FieldMetadata = FieldMetadata {
+            doc: "Some doc",
+            read_only: true,
+            ..FieldMetadata::new("foo")
+        };
+
+        assert_eq!(METADATA.name, "foo");
+        assert_eq!(METADATA.display_name, "foo");
+        assert_eq!(METADATA.doc, "Some doc");
+        assert!(METADATA.read_only);
+        assert!(METADATA.aliases.is_empty());
+        assert!(METADATA.matches_name("foo"));
+    }
+
+    #[test]
+    fn set_field_invalid_value() {
+        let mut foo = Foo::default();
+        let mut called = false;
+        foo.set_field(Foo::BAZ, Box::new(123u32), &mut |result| {
+            called = true;
+            match result {
+                Err(SetFieldError::InvalidValue {
+                    field_type_name,
+                    value_type_name,
+                    ..
+                }) => {
+                    assert_eq!(field_type_name, std::any::type_name::<f32>());
+                    assert_eq!(value_type_name, std::any::type_name::<u32>());
+                }
+                _ => panic!("expected InvalidValue error"),
+            }
+        });
+        assert!(called);
+    }
 }
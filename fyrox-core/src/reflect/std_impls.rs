@@ -45,6 +45,14 @@ macro_rules! impl_blank_reflect {
         $(
             impl Reflect for $ty {
                 blank_reflect!();
+
+                fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                    let mut result = None;
+                    other.as_any(&mut |other| {
+                        result = other.downcast_ref::<Self>().map(|other| self == other)
+                    });
+                    result
+                }
             }
         )*
     }
@@ -64,23 +72,55 @@ impl_blank_reflect! {
 macro_rules! impl_reflect_tuple {
     (
         $(
-            ( $($t:ident,)* );
+            ( $($t:ident: $i:tt,)* );
         )*
     ) => {
         $(
             impl< $($t: Clone + Reflect),* > Reflect for ( $($t,)* ) {
                 blank_reflect!();
+
+                fn as_array(&self, func: &mut dyn FnMut(Option<&dyn ReflectArray>)) {
+                    func(Some(self))
+                }
+
+                fn as_array_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectArray>)) {
+                    func(Some(self))
+                }
+            }
+
+            // Tuple elements may have different types, they are accessible by their index.
+            impl< $($t: Clone + Reflect),* > ReflectArray for ( $($t,)* ) {
+                fn reflect_index(&self, index: usize) -> Option<&dyn Reflect> {
+                    match index {
+                        $($i => Some(&self.$i),)*
+                        _ => None,
+                    }
+                }
+
+                fn reflect_index_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                    match index {
+                        $($i => Some(&mut self.$i),)*
+                        _ => None,
+                    }
+                }
+
+                fn reflect_len(&self) -> usize {
+                    [$($i),*].len()
+                }
             }
         )*
     }
 }
 
 impl_reflect_tuple! {
-    (T0,);
-    (T0, T1, );
-    (T0, T1, T2, );
-    (T0, T1, T2, T3,);
-    (T0, T1, T2, T3, T4,);
+    (T0: 0,);
+    (T0: 0, T1: 1,);
+    (T0: 0, T1: 1, T2: 2,);
+    (T0: 0, T1: 1, T2: 2, T3: 3,);
+    (T0: 0, T1: 1, T2: 2, T3: 3, T4: 4,);
+    (T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5,);
+    (T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6,);
+    (T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7,);
 }
 
 impl<const N: usize, T: Reflect + Clone> Reflect for [T; N] {
@@ -269,25 +309,70 @@ where
             None => func(None),
         })
     }
+
+    fn reflect_remove_at(
+        &mut self,
+        index: usize,
+        func: &mut dyn FnMut(Option<(Box<dyn Reflect>, Box<dyn Reflect>)>),
+    ) {
+        let entry = self
+            .keys()
+            .nth(index)
+            .cloned()
+            .and_then(|key| self.remove_entry(&key));
+        func(entry.map(|(key, value)| {
+            (
+                Box::new(key) as Box<dyn Reflect>,
+                Box::new(value) as Box<dyn Reflect>,
+            )
+        }))
+    }
 }
 
 impl Reflect for () {
     blank_reflect!();
 }
 
-impl_reflect! { pub struct Uuid; }
+impl_reflect! {
+    #[reflect(partial_eq)]
+    pub struct Uuid;
+}
 
 impl_reflect! {
     pub struct Cell<T: Debug + Copy>;
 }
 
 impl_reflect! {
+    #[reflect(ReflectOption)]
     pub enum Option<T: Clone> {
         Some(T),
         None
     }
 }
 
+impl<T: Reflect + Clone> ReflectOption for Option<T> {
+    fn reflect_is_some(&self) -> bool {
+        self.is_some()
+    }
+
+    fn reflect_inner(&self) -> Option<&dyn Reflect> {
+        self.as_ref().map(|value| value as &dyn Reflect)
+    }
+
+    fn reflect_inner_mut(&mut self) -> Option<&mut dyn Reflect> {
+        self.as_mut().map(|value| value as &mut dyn Reflect)
+    }
+
+    fn reflect_set_none(&mut self) -> Option<Box<dyn Reflect>> {
+        self.take().map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn reflect_set_some(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = Some(*value.downcast::<T>()?);
+        Ok(())
+    }
+}
+
 impl_reflect! {
     pub struct Range<Idx: Clone> {
         pub start: Idx,
@@ -328,6 +413,10 @@ macro_rules! impl_reflect_inner_mutability {
             ""
         }
 
+        fn type_source_path(&self) -> &'static str {
+            file!()
+        }
+
         fn assembly_name(&self) -> &'static str {
             env!("CARGO_PKG_NAME")
         }
@@ -441,6 +530,26 @@ macro_rules! impl_reflect_inner_mutability {
             let mut guard = $acquire_lock_guard;
             guard.as_hash_map_mut(func)
         }
+
+        fn as_enum(&$self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+            let guard = $acquire_lock_guard;
+            guard.as_enum(func)
+        }
+
+        fn as_enum_mut(&mut $self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+            let mut guard = $acquire_lock_guard;
+            guard.as_enum_mut(func)
+        }
+
+        fn as_option(&$self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+            let guard = $acquire_lock_guard;
+            guard.as_option(func)
+        }
+
+        fn as_option_mut(&mut $self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+            let mut guard = $acquire_lock_guard;
+            guard.as_option_mut(func)
+        }
     };
 }
 
@@ -27,6 +27,8 @@ use std::{
     future::Future,
     sync::mpsc::{self, Receiver, Sender},
 };
+#[cfg(not(target_arch = "wasm32"))]
+use std::{collections::VecDeque, pin::Pin, sync::Arc};
 use uuid::Uuid;
 
 // ========
@@ -83,6 +85,23 @@ impl dyn AsyncTaskResult {
     }
 }
 
+/// Priority of a task, that is spawned via [`TaskPool::spawn_task_with_priority`]. Tasks with
+/// higher priority are started before the tasks with lower priority, that are still waiting in
+/// the queue.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Low priority, should be used for background tasks.
+    Low = 0,
+    /// Normal priority.
+    #[default]
+    Normal = 1,
+    /// High priority, should be used for tasks, whose results are needed as soon as possible.
+    High = 2,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type PrioritizedTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+
 pub struct TaskResult {
     pub id: Uuid,
     pub payload: Box<dyn AsyncTaskResult>,
@@ -93,6 +112,9 @@ pub struct TaskPool {
     thread_pool: ThreadPool,
     sender: Sender<TaskResult>,
     receiver: Mutex<Receiver<TaskResult>>,
+    /// Queues of prioritized tasks, one per [`TaskPriority`].
+    #[cfg(not(target_arch = "wasm32"))]
+    queues: Arc<Mutex<[VecDeque<PrioritizedTask>; 3]>>,
 }
 
 impl Default for TaskPool {
@@ -110,6 +132,8 @@ impl TaskPool {
             thread_pool: ThreadPool::new().unwrap(),
             sender,
             receiver: Mutex::new(receiver),
+            #[cfg(not(target_arch = "wasm32"))]
+            queues: Default::default(),
         }
     }
 
@@ -131,6 +155,52 @@ impl TaskPool {
         self.thread_pool.spawn_ok(future);
     }
 
+    /// Spawns a task with the given priority. When all the threads of the pool are busy, the tasks
+    /// are queued and the ones with higher priority are started first. Tasks with the same priority
+    /// are started in the order of spawning.
+    ///
+    /// ## Platform-specific
+    ///
+    /// WebAssembly: priority is ignored, the task is spawned as usual.
+    #[inline]
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_task_with_priority<F>(&self, future: F, _priority: TaskPriority)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        self.spawn_task(future);
+    }
+
+    /// Spawns a task with the given priority. When all the threads of the pool are busy, the tasks
+    /// are queued and the ones with higher priority are started first. Tasks with the same priority
+    /// are started in the order of spawning.
+    ///
+    /// ## Platform-specific
+    ///
+    /// WebAssembly: priority is ignored, the task is spawned as usual.
+    #[inline]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_task_with_priority<F>(&self, future: F, priority: TaskPriority)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.queues.safe_lock()[priority as usize].push_back(Box::pin(future));
+
+        // Each spawned runner takes a task with the highest priority at the moment when the runner
+        // is actually started by the thread pool, not when it was spawned.
+        let queues = self.queues.clone();
+        self.thread_pool.spawn_ok(async move {
+            let task = queues
+                .safe_lock()
+                .iter_mut()
+                .rev()
+                .find_map(|queue| queue.pop_front());
+            if let Some(task) = task {
+                task.await;
+            }
+        });
+    }
+
     #[inline]
     pub fn spawn_with_result<F, T>(&self, future: F) -> Uuid
     where
@@ -156,3 +226,54 @@ impl TaskPool {
         self.receiver.safe_lock().try_recv().ok()
     }
 }
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn single_threaded_pool() -> TaskPool {
+        let (sender, receiver) = mpsc::channel();
+        TaskPool {
+            thread_pool: ThreadPool::builder().pool_size(1).create().unwrap(),
+            sender,
+            receiver: Mutex::new(receiver),
+            queues: Default::default(),
+        }
+    }
+
+    #[test]
+    fn spawn_task_with_priority() {
+        let pool = single_threaded_pool();
+
+        // Occupy the only thread of the pool, so the rest of the tasks will be queued.
+        let (gate_sender, gate_receiver) = mpsc::channel::<()>();
+        pool.spawn_task(async move {
+            gate_receiver.recv().unwrap();
+        });
+
+        let (sender, receiver) = mpsc::channel();
+        for (name, priority) in [
+            ("low", TaskPriority::Low),
+            ("normal", TaskPriority::Normal),
+            ("high", TaskPriority::High),
+            ("normal2", TaskPriority::Normal),
+        ] {
+            let sender = sender.clone();
+            pool.spawn_task_with_priority(
+                async move {
+                    sender.send(name).unwrap();
+                },
+                priority,
+            );
+        }
+
+        gate_sender.send(()).unwrap();
+
+        let order = (0..4)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(order, ["high", "normal", "normal2", "low"]);
+    }
+}
@@ -431,6 +431,10 @@ where
         self.value.doc()
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
@@ -527,6 +531,35 @@ where
         self.value.as_list_mut(func)
     }
 
+    #[inline]
+    fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+        self.value.as_enum(func)
+    }
+
+    #[inline]
+    fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+        // Switching variants of inheritable enums must mark the variable as modified.
+        self.mark_modified_and_need_sync();
+        self.value.as_enum_mut(func)
+    }
+
+    #[inline]
+    fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+        self.value.as_option(func)
+    }
+
+    #[inline]
+    fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+        // Toggling inheritable options must mark the variable as modified.
+        self.mark_modified_and_need_sync();
+        self.value.as_option_mut(func)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        self.value.reflect_partial_eq(other)
+    }
+
     #[inline]
     fn as_inheritable_variable(
         &self,
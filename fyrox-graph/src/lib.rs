@@ -1560,6 +1560,10 @@ mod test {
             self.0.deref().doc()
         }
 
+        fn type_source_path(&self) -> &'static str {
+            self.0.deref().type_source_path()
+        }
+
         fn assembly_name(&self) -> &'static str {
             self.0.deref().assembly_name()
         }
@@ -136,6 +136,29 @@ impl Executor {
         )
     }
 
+    /// Creates new game executor, that renders everything into an invisible window. It could be
+    /// used for automated tests (visual regression tests, for example), where frames are rendered
+    /// off-screen and checked by plugins via the renderer in
+    /// [`crate::plugin::PluginContext::graphics_context`]. If you need to drive the engine manually
+    /// and read rendered frames back to the CPU, create an engine with an invisible window and use
+    /// [`Engine::render_scene_to_image`] (see its docs for an example).
+    pub fn new_offscreen(event_loop: EventLoop<()>) -> Self {
+        let mut window_attributes = WindowAttributes::default();
+        window_attributes.visible = false;
+        window_attributes.title = "Fyrox Offscreen".to_string();
+
+        Self::from_params(
+            Some(event_loop),
+            GraphicsContextParams {
+                window_attributes,
+                vsync: false,
+                msaa_sample_count: None,
+                graphics_server_constructor: Default::default(),
+                named_objects: false,
+            },
+        )
+    }
+
     /// Enables or disables hot reloading of changed resources (such as textures, shaders, scenes, etc.).
     /// Enabled by default.
     ///
@@ -349,8 +372,20 @@ fn run_normal(
                     ApplicationLoopController::ActiveEventLoop(active_event_loop),
                     &mut lag,
                 );
+
+                engine.handle_resume_by_plugins(
+                    fixed_time_step,
+                    ApplicationLoopController::ActiveEventLoop(active_event_loop),
+                    &mut lag,
+                );
             }
             Event::Suspended => {
+                engine.handle_suspend_by_plugins(
+                    fixed_time_step,
+                    ApplicationLoopController::ActiveEventLoop(active_event_loop),
+                    &mut lag,
+                );
+
                 engine
                     .destroy_graphics_context()
                     .expect("Unable to destroy graphics context!");
@@ -471,6 +506,9 @@ fn game_loop_iteration(
         }
     }
 
+    // Variable time step update runs once per frame, after all the fixed time step updates.
+    engine.update_plugins_variable(elapsed.as_secs_f32(), fixed_time_step, controller, lag);
+
     if let GraphicsContext::Initialized(ref ctx) = engine.graphics_context {
         ctx.window.request_redraw();
     }
@@ -56,7 +56,7 @@ use crate::{
     engine::{error::EngineError, task::TaskPoolHandler},
     event::Event,
     graph::{BaseSceneGraph, NodeMapping, SceneGraph},
-    graphics::error::FrameworkError,
+    graphics::{error::FrameworkError, framebuffer::ReadTarget, gpu_texture::GpuTextureKind},
     gui::{
         constructor::WidgetConstructorContainer,
         font::{loader::FontLoader, Font, BUILT_IN_FONT},
@@ -71,8 +71,8 @@ use crate::{
         Material,
     },
     plugin::{
-        dylib::DyLibDynamicPlugin, DynamicPlugin, Plugin, PluginContainer, PluginContext,
-        PluginRegistrationContext,
+        dylib::DyLibDynamicPlugin, DynamicPlugin, OtherPlugins, Plugin, PluginContainer,
+        PluginContext, PluginMessage, PluginRegistrationContext,
     },
     renderer::Renderer,
     resource::{
@@ -502,6 +502,11 @@ pub struct Engine {
 
     plugins_enabled: bool,
 
+    // Type ids of resource loaders registered by each plugin (in the same order as `plugins`).
+    plugin_loaders: Vec<Vec<TypeId>>,
+
+    plugin_messages: Vec<PluginMessage>,
+
     // Amount of time (in seconds) that passed from creation of the engine.
     elapsed_time: f32,
 
@@ -991,7 +996,7 @@ impl ScriptProcessor {
         }
 
         // Process scripts from destroyed scenes.
-        for (handle, mut detached_scene) in scenes.destruction_list.drain(..) {
+        for (handle, mut detached_scene, path) in scenes.destruction_list.drain(..) {
             if let Some(scripted_scene) = self.scripted_scenes.iter().find(|s| s.handle == handle) {
                 let mut context = ScriptDeinitContext {
                     elapsed_time,
@@ -1020,6 +1025,9 @@ impl ScriptProcessor {
                     });
                 }
             }
+
+            // Plugins will be notified about the scene removal after its scripts were destroyed.
+            scenes.unloaded_list.push((handle, path));
         }
     }
 }
@@ -1425,6 +1433,33 @@ impl ApplicationLoopController<'_> {
     }
 }
 
+/// Builds a [`PluginContext`] that borrows the engine sub-systems from `$engine`. The rest of the
+/// plugins (see [`OtherPlugins::split`]) are passed separately, because the plugin that receives the
+/// context is borrowed from the same storage.
+macro_rules! plugin_context {
+    ($engine:expr, $plugins:expr, $dt:expr, $lag:expr, $controller:expr) => {
+        PluginContext {
+            scenes: &mut $engine.scenes,
+            resource_manager: &$engine.resource_manager,
+            graphics_context: &mut $engine.graphics_context,
+            dt: $dt,
+            lag: $lag,
+            user_interfaces: &mut $engine.user_interfaces,
+            serialization_context: &$engine.serialization_context,
+            widget_constructors: &$engine.widget_constructors,
+            performance_statistics: &$engine.performance_statistics,
+            elapsed_time: $engine.elapsed_time,
+            script_processor: &$engine.script_processor,
+            async_scene_loader: &mut $engine.async_scene_loader,
+            loop_controller: $controller,
+            task_pool: &mut $engine.task_pool,
+            input_state: &$engine.input_state,
+            plugins: $plugins,
+            plugin_messages: &mut $engine.plugin_messages,
+        }
+    };
+}
+
 impl Engine {
     /// Creates new instance of engine from given initialization parameters. Automatically creates all sub-systems
     /// (sound, ui, resource manager, etc.) **except** graphics context. Graphics context should be created manually
@@ -1510,6 +1545,8 @@ impl Engine {
             widget_constructors,
             script_processor: Default::default(),
             plugins_enabled: false,
+            plugin_loaders: Default::default(),
+            plugin_messages: Default::default(),
             elapsed_time: 0.0,
             task_pool: TaskPoolHandler::new(task_pool),
             input_state: Default::default(),
@@ -1698,25 +1735,10 @@ impl Engine {
                     // Notify plugins about a scene, that started loading.
                     if self.plugins_enabled {
                         let path = request.path.clone();
-                        let mut context = PluginContext {
-                            scenes: &mut self.scenes,
-                            resource_manager: &self.resource_manager,
-                            graphics_context: &mut self.graphics_context,
-                            dt,
-                            lag,
-                            user_interfaces: &mut self.user_interfaces,
-                            serialization_context: &self.serialization_context,
-                            widget_constructors: &self.widget_constructors,
-                            performance_statistics: &self.performance_statistics,
-                            elapsed_time: self.elapsed_time,
-                            script_processor: &self.script_processor,
-                            async_scene_loader: &mut self.async_scene_loader,
-                            loop_controller: controller,
-                            task_pool: &mut self.task_pool,
-                            input_state: &self.input_state,
-                        };
-
-                        for plugin in self.plugins.iter_mut() {
+                        for index in 0..self.plugins.len() {
+                            let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                            let mut context = plugin_context!(self, plugins, dt, lag, controller);
+
                             plugin.on_scene_begin_loading(&path, &mut context);
                         }
                     }
@@ -1732,24 +1754,6 @@ impl Engine {
                 .loading_scenes
                 .remove(&loading_result.path)
             {
-                let mut context = PluginContext {
-                    scenes: &mut self.scenes,
-                    resource_manager: &self.resource_manager,
-                    graphics_context: &mut self.graphics_context,
-                    dt,
-                    lag,
-                    user_interfaces: &mut self.user_interfaces,
-                    serialization_context: &self.serialization_context,
-                    widget_constructors: &self.widget_constructors,
-                    performance_statistics: &self.performance_statistics,
-                    elapsed_time: self.elapsed_time,
-                    script_processor: &self.script_processor,
-                    async_scene_loader: &mut self.async_scene_loader,
-                    loop_controller: controller,
-                    task_pool: &mut self.task_pool,
-                    input_state: &self.input_state,
-                };
-
                 match loading_result.result {
                     Ok((mut scene, data)) => {
                         if request.options.derived {
@@ -1815,11 +1819,16 @@ impl Engine {
                             }
                         }
 
-                        let scene_handle = context.scenes.add(scene);
+                        let scene_handle = self.scenes.add_with_path(scene, request.path.clone());
 
                         // Notify plugins about newly loaded scene.
                         if self.plugins_enabled {
-                            for plugin in self.plugins.iter_mut() {
+                            for index in 0..self.plugins.len() {
+                                let (plugin, plugins) =
+                                    OtherPlugins::split(&mut self.plugins, index);
+                                let mut context =
+                                    plugin_context!(self, plugins, dt, lag, controller);
+
                                 Log::info(format!(
                                     "Scene {} was loaded successfully!",
                                     loading_result.path.display()
@@ -1842,7 +1851,12 @@ impl Engine {
                                 loading_result.path, error
                             ));
 
-                            for plugin in self.plugins.iter_mut() {
+                            for index in 0..self.plugins.len() {
+                                let (plugin, plugins) =
+                                    OtherPlugins::split(&mut self.plugins, index);
+                                let mut context =
+                                    plugin_context!(self, plugins, dt, lag, controller);
+
                                 plugin.on_scene_loading_failed(&request.path, &error, &mut context);
                             }
                         }
@@ -1877,6 +1891,7 @@ impl Engine {
         // or removed. This is where most of the rules of the game happen.
         self.update_plugins(dt, controller, lag);
         self.handle_scripts(dt);
+        self.handle_unloaded_scenes(dt, controller, lag);
 
         // Now that the plugins and scripts have made whatever changes are needed, we must respond
         // to those changes by updating the scenes and the state of the engine.
@@ -1986,23 +2001,7 @@ impl Engine {
                 (plugin_task_handler)(
                     result.payload,
                     &mut self.plugins,
-                    &mut PluginContext {
-                        scenes: &mut self.scenes,
-                        resource_manager: &self.resource_manager,
-                        graphics_context: &mut self.graphics_context,
-                        dt,
-                        lag,
-                        user_interfaces: &mut self.user_interfaces,
-                        serialization_context: &self.serialization_context,
-                        widget_constructors: &self.widget_constructors,
-                        performance_statistics: &self.performance_statistics,
-                        elapsed_time: self.elapsed_time,
-                        script_processor: &self.script_processor,
-                        async_scene_loader: &mut self.async_scene_loader,
-                        loop_controller: controller,
-                        task_pool: &mut self.task_pool,
-                        input_state: &self.input_state,
-                    },
+                    &mut plugin_context!(self, Default::default(), dt, lag, controller),
                 )
             } else if let Some(node_task_handler) = self.task_pool.pop_node_task_handler(result.id)
             {
@@ -2078,29 +2077,44 @@ impl Engine {
             // Handle asynchronous tasks first.
             self.handle_async_tasks(dt, controller, lag);
 
-            // Then update all the plugins.
-            let mut context = PluginContext {
-                scenes: &mut self.scenes,
-                resource_manager: &self.resource_manager,
-                graphics_context: &mut self.graphics_context,
-                dt,
-                lag,
-                user_interfaces: &mut self.user_interfaces,
-                serialization_context: &self.serialization_context,
-                widget_constructors: &self.widget_constructors,
-                performance_statistics: &self.performance_statistics,
-                elapsed_time: self.elapsed_time,
-                script_processor: &self.script_processor,
-                async_scene_loader: &mut self.async_scene_loader,
-                loop_controller: controller,
-                task_pool: &mut self.task_pool,
-                input_state: &self.input_state,
-            };
+            // Deliver messages, that were sent by plugins since the previous update. Messages sent
+            // during the delivery will be delivered on the next update.
+            for message in std::mem::take(&mut self.plugin_messages) {
+                for index in 0..self.plugins.len() {
+                    if message.sender == Some(index) {
+                        continue;
+                    }
+
+                    let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                    let mut context = plugin_context!(self, plugins, dt, lag, controller);
+
+                    plugin.on_plugin_message(&*message.payload, &mut context);
+                }
+            }
+
+            // Then update all the plugins. Every plugin receives `before_update` call first, then
+            // `update` and finally `after_update`.
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
+
+                plugin.before_update(&mut context);
+            }
+
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
 
-            for plugin in self.plugins.iter_mut() {
                 plugin.update(&mut context);
             }
 
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
+
+                plugin.after_update(&mut context);
+            }
+
             let mut uis = self
                 .user_interfaces
                 .pair_iter()
@@ -2113,25 +2127,10 @@ impl Engine {
                     .try_get_mut(ui)
                     .and_then(|ui| ui.poll_message())
                 {
-                    let mut context = PluginContext {
-                        scenes: &mut self.scenes,
-                        resource_manager: &self.resource_manager,
-                        graphics_context: &mut self.graphics_context,
-                        dt,
-                        lag,
-                        user_interfaces: &mut self.user_interfaces,
-                        serialization_context: &self.serialization_context,
-                        widget_constructors: &self.widget_constructors,
-                        performance_statistics: &self.performance_statistics,
-                        elapsed_time: self.elapsed_time,
-                        script_processor: &self.script_processor,
-                        async_scene_loader: &mut self.async_scene_loader,
-                        loop_controller: controller,
-                        task_pool: &mut self.task_pool,
-                        input_state: &self.input_state,
-                    };
-
-                    for plugin in self.plugins.iter_mut() {
+                    for index in 0..self.plugins.len() {
+                        let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                        let mut context = plugin_context!(self, plugins, dt, lag, controller);
+
                         plugin.on_ui_message(&mut context, &message, ui);
                     }
                 }
@@ -2150,25 +2149,10 @@ impl Engine {
         let time = instant::Instant::now();
 
         if self.plugins_enabled {
-            let mut context = PluginContext {
-                scenes: &mut self.scenes,
-                resource_manager: &self.resource_manager,
-                graphics_context: &mut self.graphics_context,
-                dt,
-                lag,
-                user_interfaces: &mut self.user_interfaces,
-                serialization_context: &self.serialization_context,
-                widget_constructors: &self.widget_constructors,
-                performance_statistics: &self.performance_statistics,
-                elapsed_time: self.elapsed_time,
-                script_processor: &self.script_processor,
-                async_scene_loader: &mut self.async_scene_loader,
-                loop_controller: controller,
-                task_pool: &mut self.task_pool,
-                input_state: &self.input_state,
-            };
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
 
-            for plugin in self.plugins.iter_mut() {
                 plugin.post_update(&mut context);
             }
         }
@@ -2254,27 +2238,9 @@ impl Engine {
         }
 
         if self.plugins_enabled {
-            for plugin in self.plugins.iter_mut() {
-                plugin.on_os_event(
-                    event,
-                    PluginContext {
-                        scenes: &mut self.scenes,
-                        resource_manager: &self.resource_manager,
-                        graphics_context: &mut self.graphics_context,
-                        dt,
-                        lag,
-                        user_interfaces: &mut self.user_interfaces,
-                        serialization_context: &self.serialization_context,
-                        widget_constructors: &self.widget_constructors,
-                        performance_statistics: &self.performance_statistics,
-                        elapsed_time: self.elapsed_time,
-                        script_processor: &self.script_processor,
-                        async_scene_loader: &mut self.async_scene_loader,
-                        loop_controller: controller,
-                        task_pool: &mut self.task_pool,
-                        input_state: &self.input_state,
-                    },
-                );
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.on_os_event(event, plugin_context!(self, plugins, dt, lag, controller));
             }
         }
     }
@@ -2286,24 +2252,57 @@ impl Engine {
         lag: &mut f32,
     ) {
         if self.plugins_enabled {
-            for plugin in self.plugins.iter_mut() {
-                plugin.on_graphics_context_initialized(PluginContext {
-                    scenes: &mut self.scenes,
-                    resource_manager: &self.resource_manager,
-                    graphics_context: &mut self.graphics_context,
-                    dt,
-                    lag,
-                    user_interfaces: &mut self.user_interfaces,
-                    serialization_context: &self.serialization_context,
-                    widget_constructors: &self.widget_constructors,
-                    performance_statistics: &self.performance_statistics,
-                    elapsed_time: self.elapsed_time,
-                    script_processor: &self.script_processor,
-                    async_scene_loader: &mut self.async_scene_loader,
-                    loop_controller: controller,
-                    task_pool: &mut self.task_pool,
-                    input_state: &self.input_state,
-                });
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.on_graphics_context_initialized(plugin_context!(
+                    self, plugins, dt, lag, controller
+                ));
+            }
+        }
+    }
+
+    /// Calls [`Plugin::on_suspend`] of every plugin. It must be called when the application is
+    /// suspended, before the graphics context is destroyed. The executor calls it automatically,
+    /// you should only call this manually if you're using a custom game loop.
+    ///
+    /// ## Parameters
+    ///
+    /// `dt` - the fixed time step, that is used to update plugins in [`Self::update`].
+    ///
+    /// `lag` - is a reference to time accumulator, see [`Self::update`] for more info.
+    pub fn handle_suspend_by_plugins(
+        &mut self,
+        dt: f32,
+        controller: ApplicationLoopController,
+        lag: &mut f32,
+    ) {
+        if self.plugins_enabled {
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.on_suspend(&mut plugin_context!(self, plugins, dt, lag, controller));
+            }
+        }
+    }
+
+    /// Calls [`Plugin::on_resume`] of every plugin. It must be called when the application is
+    /// resumed, after the graphics context is initialized. The executor calls it automatically,
+    /// you should only call this manually if you're using a custom game loop.
+    ///
+    /// ## Parameters
+    ///
+    /// `dt` - the fixed time step, that is used to update plugins in [`Self::update`].
+    ///
+    /// `lag` - is a reference to time accumulator, see [`Self::update`] for more info.
+    pub fn handle_resume_by_plugins(
+        &mut self,
+        dt: f32,
+        controller: ApplicationLoopController,
+        lag: &mut f32,
+    ) {
+        if self.plugins_enabled {
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.on_resume(&mut plugin_context!(self, plugins, dt, lag, controller));
             }
         }
     }
@@ -2315,24 +2314,60 @@ impl Engine {
         lag: &mut f32,
     ) {
         if self.plugins_enabled {
-            for plugin in self.plugins.iter_mut() {
-                plugin.on_graphics_context_destroyed(PluginContext {
-                    scenes: &mut self.scenes,
-                    resource_manager: &self.resource_manager,
-                    graphics_context: &mut self.graphics_context,
-                    dt,
-                    lag,
-                    user_interfaces: &mut self.user_interfaces,
-                    serialization_context: &self.serialization_context,
-                    widget_constructors: &self.widget_constructors,
-                    performance_statistics: &self.performance_statistics,
-                    elapsed_time: self.elapsed_time,
-                    script_processor: &self.script_processor,
-                    async_scene_loader: &mut self.async_scene_loader,
-                    loop_controller: controller,
-                    task_pool: &mut self.task_pool,
-                    input_state: &self.input_state,
-                });
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.on_graphics_context_destroyed(plugin_context!(
+                    self, plugins, dt, lag, controller
+                ));
+            }
+        }
+    }
+
+    /// Calls [`Plugin::update_variable`] of every plugin. It must be called once per frame, after
+    /// all the fixed time step updates of the frame (see [`Plugin::update_variable`] docs for more
+    /// info). The executor calls it automatically, you should only call this manually if you're
+    /// using a custom game loop.
+    ///
+    /// ## Parameters
+    ///
+    /// `real_dt` - the real amount of time (in seconds) passed since the previous frame.
+    ///
+    /// `dt` - the fixed time step, that is used to update plugins in [`Self::update`].
+    ///
+    /// `lag` - is a reference to time accumulator, see [`Self::update`] for more info.
+    pub fn update_plugins_variable(
+        &mut self,
+        real_dt: f32,
+        dt: f32,
+        controller: ApplicationLoopController,
+        lag: &mut f32,
+    ) {
+        if self.plugins_enabled {
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
+                plugin.update_variable(&mut context, real_dt);
+            }
+        }
+    }
+
+    fn handle_unloaded_scenes(
+        &mut self,
+        dt: f32,
+        controller: ApplicationLoopController,
+        lag: &mut f32,
+    ) {
+        let unloaded_list = std::mem::take(&mut self.scenes.unloaded_list);
+
+        if !self.plugins_enabled {
+            return;
+        }
+
+        for (handle, path) in unloaded_list {
+            for index in 0..self.plugins.len() {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                let mut context = plugin_context!(self, plugins, dt, lag, controller);
+                plugin.on_scene_unloaded(path.as_deref(), handle, &mut context);
             }
         }
     }
@@ -2344,24 +2379,13 @@ impl Engine {
         lag: &mut f32,
     ) {
         if self.plugins_enabled {
-            for plugin in self.plugins.iter_mut() {
-                plugin.before_rendering(PluginContext {
-                    scenes: &mut self.scenes,
-                    resource_manager: &self.resource_manager,
-                    graphics_context: &mut self.graphics_context,
-                    dt,
-                    lag,
-                    user_interfaces: &mut self.user_interfaces,
-                    serialization_context: &self.serialization_context,
-                    widget_constructors: &self.widget_constructors,
-                    performance_statistics: &self.performance_statistics,
-                    elapsed_time: self.elapsed_time,
-                    script_processor: &self.script_processor,
-                    async_scene_loader: &mut self.async_scene_loader,
-                    loop_controller: controller,
-                    task_pool: &mut self.task_pool,
-                    input_state: &self.input_state,
-                });
+            // Stable sort keeps the registration order of the plugins with the same render order.
+            let mut order = (0..self.plugins.len()).collect::<Vec<_>>();
+            order.sort_by_key(|index| self.plugins[*index].render_order());
+
+            for index in order {
+                let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
+                plugin.before_rendering(plugin_context!(self, plugins, dt, lag, controller));
             }
         }
     }
@@ -2475,6 +2499,118 @@ impl Engine {
         Ok(())
     }
 
+    /// Renders a single frame of the given scene into an off-screen frame buffer and reads its
+    /// pixels back to the CPU. The graphics context must be initialized. This method does not
+    /// present anything on screen, so it could be used for automated screenshot (visual regression)
+    /// tests in pair with [`crate::engine::executor::Executor::new_offscreen`], which creates an
+    /// invisible window. The returned image is flipped, so its first row is the top row of the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use fyrox_impl::{
+    /// #     application::ApplicationHandler,
+    /// #     asset::manager::ResourceManager,
+    /// #     core::{pool::Handle, task::TaskPool},
+    /// #     engine::{Engine, EngineInitParams, GraphicsContextParams, SerializationContext},
+    /// #     event::WindowEvent,
+    /// #     event_loop::{ActiveEventLoop, EventLoop},
+    /// #     scene::{base::BaseBuilder, camera::CameraBuilder, Scene},
+    /// #     window::{WindowAttributes, WindowId},
+    /// # };
+    /// # use std::sync::Arc;
+    /// # use fyrox_resource::io::FsResourceIo;
+    /// # use fyrox_ui::constructor::new_widget_constructor_container;
+    /// struct Screenshot {
+    ///     engine: Engine,
+    ///     scene: Handle<Scene>,
+    /// }
+    ///
+    /// impl ApplicationHandler for Screenshot {
+    ///     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+    ///         self.engine.initialize_graphics_context(event_loop).unwrap();
+    ///         let image = self.engine.render_scene_to_image(self.scene).unwrap();
+    ///         image.save("screenshot.png").unwrap();
+    ///         event_loop.exit();
+    ///     }
+    ///
+    ///     fn window_event(&mut self, _: &ActiveEventLoop, _: WindowId, _: WindowEvent) {}
+    /// }
+    ///
+    /// let mut window_attributes = WindowAttributes::default();
+    /// window_attributes.visible = false;
+    /// let task_pool = Arc::new(TaskPool::new());
+    /// let mut engine = Engine::new(EngineInitParams {
+    ///     graphics_context_params: GraphicsContextParams {
+    ///         window_attributes,
+    ///         vsync: false,
+    ///         msaa_sample_count: None,
+    ///         graphics_server_constructor: Default::default(),
+    ///         named_objects: false,
+    ///     },
+    ///     resource_manager: ResourceManager::new(Arc::new(FsResourceIo), task_pool.clone()),
+    ///     serialization_context: Arc::new(SerializationContext::new()),
+    ///     task_pool,
+    ///     widget_constructors: Arc::new(new_widget_constructor_container()),
+    /// })
+    /// .unwrap();
+    ///
+    /// let mut scene = Scene::new();
+    /// CameraBuilder::new(BaseBuilder::new()).build(&mut scene.graph);
+    /// let scene = engine.scenes.add(scene);
+    ///
+    /// EventLoop::new()
+    ///     .unwrap()
+    ///     .run_app(&mut Screenshot { engine, scene })
+    ///     .unwrap();
+    /// ```
+    pub fn render_scene_to_image(
+        &mut self,
+        scene_handle: Handle<Scene>,
+    ) -> Result<image::RgbaImage, EngineError> {
+        let GraphicsContext::Initialized(ref mut ctx) = self.graphics_context else {
+            return Err(EngineError::Custom(
+                "Graphics context is not initialized!".to_string(),
+            ));
+        };
+
+        let scene = self.scenes.try_get(scene_handle).ok_or_else(|| {
+            EngineError::Custom(format!("There's no scene with {scene_handle} handle!"))
+        })?;
+
+        let scene_data = ctx.renderer.render_scene(
+            scene_handle,
+            scene,
+            self.elapsed_time,
+            0.0,
+            &self.resource_manager,
+        )?;
+
+        let (width, height) = match scene_data.scene_data.ldr_scene_frame_texture().kind() {
+            GpuTextureKind::Rectangle { width, height } => (width as u32, height as u32),
+            _ => {
+                return Err(EngineError::Custom(
+                    "Scene frame texture must be a rectangular texture!".to_string(),
+                ))
+            }
+        };
+
+        let pixels = scene_data
+            .scene_data
+            .ldr_scene_framebuffer
+            .read_pixels(ReadTarget::Color(0))
+            .ok_or_else(|| EngineError::Custom("Unable to read frame pixels!".to_string()))?;
+
+        let mut image = image::RgbaImage::from_raw(width, height, pixels).ok_or_else(|| {
+            EngineError::Custom("Frame pixels does not match the frame size!".to_string())
+        })?;
+
+        // OpenGL stores images from bottom to top.
+        image::imageops::flip_vertical_in_place(&mut image);
+
+        Ok(image)
+    }
+
     /// Enables or disables registered plugins.
     pub(crate) fn enable_plugins(
         &mut self,
@@ -2487,50 +2623,30 @@ impl Engine {
 
             if self.plugins_enabled {
                 // Create and initialize instances.
-                for plugin in self.plugins.iter_mut() {
+                for index in 0..self.plugins.len() {
+                    let (plugin, plugins) = OtherPlugins::split(&mut self.plugins, index);
                     plugin.init(
                         scene_path,
-                        PluginContext {
-                            scenes: &mut self.scenes,
-                            resource_manager: &self.resource_manager,
-                            graphics_context: &mut self.graphics_context,
-                            dt: 0.0,
-                            lag: &mut 0.0,
-                            user_interfaces: &mut self.user_interfaces,
-                            serialization_context: &self.serialization_context,
-                            widget_constructors: &self.widget_constructors,
-                            performance_statistics: &self.performance_statistics,
-                            elapsed_time: self.elapsed_time,
-                            script_processor: &self.script_processor,
-                            async_scene_loader: &mut self.async_scene_loader,
-                            loop_controller: controller,
-                            task_pool: &mut self.task_pool,
-                            input_state: &self.input_state,
-                        },
+                        plugin_context!(self, plugins, 0.0, &mut 0.0, controller),
                     );
                 }
             } else {
                 self.handle_scripts(0.0);
 
-                for mut plugin in self.plugins.drain(..) {
+                for (mut plugin, loaders) in
+                    self.plugins.drain(..).zip(self.plugin_loaders.drain(..))
+                {
                     // Deinit plugin first.
-                    plugin.on_deinit(PluginContext {
-                        scenes: &mut self.scenes,
-                        resource_manager: &self.resource_manager,
-                        graphics_context: &mut self.graphics_context,
-                        dt: 0.0,
-                        lag: &mut 0.0,
-                        user_interfaces: &mut self.user_interfaces,
-                        serialization_context: &self.serialization_context,
-                        widget_constructors: &self.widget_constructors,
-                        performance_statistics: &self.performance_statistics,
-                        elapsed_time: self.elapsed_time,
-                        script_processor: &self.script_processor,
-                        async_scene_loader: &mut self.async_scene_loader,
-                        loop_controller: controller,
-                        task_pool: &mut self.task_pool,
-                        input_state: &self.input_state,
-                    });
+                    plugin.on_deinit(plugin_context!(
+                        self,
+                        Default::default(),
+                        0.0,
+                        &mut 0.0,
+                        controller
+                    ));
+
+                    // Then remove every resource loader registered by the plugin.
+                    Self::remove_plugin_loaders(&self.resource_manager, loaders);
                 }
             }
         }
@@ -2541,15 +2657,33 @@ impl Engine {
         widget_constructors: &Arc<WidgetConstructorContainer>,
         resource_manager: &ResourceManager,
         plugin: &dyn Plugin,
-    ) {
+    ) -> Vec<TypeId> {
+        let loaders = resource_manager.state().loaders.clone();
+        let existing_loaders = loaders.safe_lock().type_ids().collect::<FxHashSet<_>>();
+
         plugin.register(PluginRegistrationContext {
             serialization_context,
             widget_constructors,
             resource_manager,
         });
+
+        // Remember the loaders added by the plugin, so they could be removed with the plugin.
+        let loaders = loaders.safe_lock();
+        loaders
+            .type_ids()
+            .filter(|type_id| !existing_loaders.contains(type_id))
+            .collect()
     }
 
-    fn register_plugin(&self, plugin: &dyn Plugin) {
+    fn remove_plugin_loaders(resource_manager: &ResourceManager, loaders: Vec<TypeId>) {
+        let state = resource_manager.state();
+        let mut container = state.loaders.safe_lock();
+        for type_id in loaders {
+            container.remove_by_type_id(type_id);
+        }
+    }
+
+    fn register_plugin(&self, plugin: &dyn Plugin) -> Vec<TypeId> {
         Self::register_plugin_internal(
             &self.serialization_context,
             &self.widget_constructors,
@@ -2563,9 +2697,10 @@ impl Engine {
     where
         P: Plugin + 'static,
     {
-        self.register_plugin(&plugin);
+        let loaders = self.register_plugin(&plugin);
 
         self.plugins.push(PluginContainer::Static(Box::new(plugin)));
+        self.plugin_loaders.push(loaders);
     }
 
     /// Tries to add a new dynamic plugin. This method attempts to load a dynamic library by the
@@ -2604,8 +2739,9 @@ impl Engine {
 
         let plugin_container = PluginContainer::Dynamic(Box::new(plugin));
 
-        self.register_plugin(plugin_container.deref());
+        let loaders = self.register_plugin(plugin_container.deref());
         self.plugins.push(plugin_container);
+        self.plugin_loaders.push(loaders);
 
         Log::info(format!("Plugin {display_name:?} was loaded successfully"));
 
@@ -2771,11 +2907,18 @@ impl Engine {
 
         let binary_blob = binary_blob.into_inner();
 
+        // Resource loaders of the previous version of the plugin must be removed before its library
+        // is unloaded, they will be registered again by the new version.
+        Self::remove_plugin_loaders(
+            &self.resource_manager,
+            std::mem::take(&mut self.plugin_loaders[plugin_index]),
+        );
+
         plugin.reload(&mut |plugin| {
             // Re-register the plugin. This is needed, because it might contain new script/node/widget
             // types (or removed ones too). This is done right before deserialization, because plugin
             // might contain some entities, that have dynamic registration.
-            Self::register_plugin_internal(
+            self.plugin_loaders[plugin_index] = Self::register_plugin_internal(
                 &self.serialization_context,
                 &self.widget_constructors,
                 &self.resource_manager,
@@ -2827,23 +2970,13 @@ impl Engine {
         }
 
         // Call `on_loaded` for plugins, so they could restore some runtime non-serializable state.
-        plugin.as_loaded_mut().on_loaded(PluginContext {
-            scenes: &mut self.scenes,
-            resource_manager: &self.resource_manager,
-            user_interfaces: &mut self.user_interfaces,
-            graphics_context: &mut self.graphics_context,
+        plugin.as_loaded_mut().on_loaded(plugin_context!(
+            self,
+            Default::default(),
             dt,
             lag,
-            serialization_context: &self.serialization_context,
-            widget_constructors: &self.widget_constructors,
-            performance_statistics: &Default::default(),
-            elapsed_time: self.elapsed_time,
-            script_processor: &self.script_processor,
-            async_scene_loader: &mut self.async_scene_loader,
-            loop_controller: controller,
-            task_pool: &mut self.task_pool,
-            input_state: &self.input_state,
-        });
+            controller
+        ));
 
         Log::info(format!("Plugin {plugin_index} was successfully reloaded!"));
 
@@ -2900,6 +3033,17 @@ impl Drop for Engine {
             self.scenes.remove(handle);
         }
 
+        // De-initialize scripts of the removed scenes, then let plugins know about every removed
+        // scene before they're disabled.
+        self.handle_scripts(0.0);
+        self.handle_unloaded_scenes(
+            0.0,
+            ApplicationLoopController::Headless {
+                running: &Default::default(),
+            },
+            &mut 0.0,
+        );
+
         // Finally disable plugins.
         self.enable_plugins(
             None,
@@ -2914,11 +3058,14 @@ impl Drop for Engine {
 #[cfg(test)]
 mod test {
     use crate::engine::ApplicationLoopController;
+    use crate::plugin::{
+        OtherPlugins, Plugin, PluginContainer, PluginContext, PluginRegistrationContext,
+    };
     use crate::{
         asset::manager::ResourceManager,
         core::{
             pool::Handle, reflect::prelude::*, task::TaskPool, type_traits::prelude::*,
-            visitor::prelude::*,
+            visitor::prelude::*, SafeLock,
         },
         engine::{task::TaskPoolHandler, GraphicsContext, ScriptProcessor},
         graph::BaseSceneGraph,
@@ -2928,9 +3075,14 @@ mod test {
             ScriptTrait,
         },
     };
-    use fyrox_resource::io::FsResourceIo;
+    use fyrox_resource::{
+        io::{FsResourceIo, ResourceIo},
+        loader::{BoxedLoaderFuture, ResourceLoader},
+    };
     use fyrox_ui::UiContainer;
+    use std::any::Any;
     use std::cell::Cell;
+    use std::path::PathBuf;
     use std::sync::{
         mpsc::{self, Sender, TryRecvError},
         Arc,
@@ -3181,6 +3333,153 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_unloaded_scenes() {
+        let resource_manager =
+            ResourceManager::new(Arc::new(FsResourceIo), Arc::new(Default::default()));
+
+        let mut scene_container = SceneContainer::new(Default::default());
+        let loaded = scene_container.add_with_path(Scene::new(), "data/level.rgs".into());
+        let created = scene_container.add(Scene::new());
+        assert_eq!(
+            scene_container.path(loaded),
+            Some(std::path::Path::new("data/level.rgs"))
+        );
+        assert_eq!(scene_container.path(created), None);
+
+        scene_container.remove(loaded);
+        scene_container.remove(created);
+        assert!(scene_container.unloaded_list.is_empty());
+
+        let mut script_processor = ScriptProcessor::default();
+        let mut task_pool = TaskPoolHandler::new(Arc::new(TaskPool::new()));
+        let mut gc = GraphicsContext::Uninitialized(Default::default());
+        let mut user_interfaces = UiContainer::default();
+        script_processor.handle_scripts(
+            &mut scene_container,
+            &mut Vec::new(),
+            &resource_manager,
+            &mut task_pool,
+            &mut gc,
+            &mut user_interfaces,
+            0.0,
+            0.0,
+            &Default::default(),
+        );
+
+        assert!(scene_container.destruction_list.is_empty());
+        assert_eq!(
+            scene_container.unloaded_list,
+            vec![(loaded, Some("data/level.rgs".into())), (created, None)]
+        );
+
+        // Clearing the container removes every scene in the same way.
+        let loaded = scene_container.add_with_path(Scene::new(), "data/level.rgs".into());
+        scene_container.add(Scene::new());
+        scene_container.clear();
+        assert_eq!(scene_container.path(loaded), None);
+        assert_eq!(scene_container.destruction_list.len(), 2);
+        assert_eq!(
+            scene_container.destruction_list[0].2,
+            Some("data/level.rgs".into())
+        );
+
+        // Taken scenes are not tracked by the container anymore.
+        let taken = scene_container.add_with_path(Scene::new(), "data/level.rgs".into());
+        let (ticket, _) = scene_container.take_reserve(taken);
+        assert_eq!(scene_container.path(taken), None);
+        scene_container.forget_ticket(ticket);
+    }
+
+    #[derive(Debug, Clone, Reflect, Visit, TypeUuidProvider, ComponentProvider)]
+    #[type_uuid(id = "5d3c3b7e-0f55-4b8e-9a3e-7d2b7f1a9c44")]
+    struct ShutdownScript {
+        #[reflect(hidden)]
+        #[visit(skip)]
+        sender: Sender<String>,
+    }
+
+    impl ScriptTrait for ShutdownScript {
+        fn on_deinit(&mut self, _ctx: &mut ScriptDeinitContext) {
+            self.sender.send("script deinit".to_string()).unwrap();
+        }
+    }
+
+    #[derive(Visit, Reflect, Debug)]
+    #[reflect(non_cloneable)]
+    struct ShutdownPlugin {
+        #[reflect(hidden)]
+        #[visit(skip)]
+        sender: Sender<String>,
+    }
+
+    impl Plugin for ShutdownPlugin {
+        fn on_deinit(&mut self, _context: PluginContext) {
+            self.sender.send("plugin deinit".to_string()).unwrap();
+        }
+
+        fn on_scene_unloaded(
+            &mut self,
+            path: Option<&std::path::Path>,
+            _scene: Handle<Scene>,
+            _context: &mut PluginContext,
+        ) {
+            self.sender
+                .send(format!("scene unloaded {path:?}"))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_scenes_unloaded_on_shutdown() {
+        use crate::engine::{Engine, EngineInitParams};
+
+        let task_pool = Arc::new(TaskPool::default());
+        let mut engine = Engine::new(EngineInitParams {
+            graphics_context_params: Default::default(),
+            serialization_context: Arc::new(Default::default()),
+            widget_constructors: Arc::new(Default::default()),
+            resource_manager: ResourceManager::new(Arc::new(FsResourceIo), task_pool.clone()),
+            task_pool,
+        })
+        .unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        engine.add_plugin(ShutdownPlugin {
+            sender: sender.clone(),
+        });
+
+        let is_running = Cell::new(true);
+        let controller = ApplicationLoopController::Headless {
+            running: &is_running,
+        };
+        engine.enable_plugins(None, true, controller);
+
+        let mut scene = Scene::new();
+        PivotBuilder::new(BaseBuilder::new().with_script(ShutdownScript { sender }))
+            .build(&mut scene.graph);
+        let scene_handle = engine.scenes.add_with_path(scene, "data/level.rgs".into());
+        engine.register_scripted_scene(scene_handle);
+
+        // Initialize the script.
+        engine.update(1.0 / 60.0, controller, &mut 0.0, Default::default());
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+        drop(engine);
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<_>>(),
+            vec![
+                "script deinit".to_string(),
+                format!(
+                    "scene unloaded {:?}",
+                    Some(std::path::Path::new("data/level.rgs"))
+                ),
+                "plugin deinit".to_string(),
+            ]
+        );
+    }
+
     #[derive(Debug, ScriptMessagePayload)]
     enum MyMessage {
         Foo(usize),
@@ -3347,6 +3646,235 @@ mod test {
 
     impl ScriptTrait for ScriptWithoutAsyncTasks {}
 
+    #[derive(Debug, PartialEq)]
+    struct Ping(u32);
+
+    #[derive(Default, Visit, Reflect, Debug)]
+    #[reflect(non_cloneable)]
+    struct PingSender {
+        sent: bool,
+        received: u32,
+    }
+
+    impl Plugin for PingSender {
+        fn update(&mut self, context: &mut PluginContext) {
+            if !self.sent {
+                self.sent = true;
+                context.send_plugin_message(Ping(42));
+            }
+        }
+
+        fn on_plugin_message(&mut self, _message: &dyn Any, _context: &mut PluginContext) {
+            self.received += 1;
+        }
+    }
+
+    #[derive(Default, Visit, Reflect, Debug)]
+    #[reflect(non_cloneable)]
+    struct PingReceiver {
+        received: Vec<u32>,
+    }
+
+    impl Plugin for PingReceiver {
+        fn on_plugin_message(&mut self, message: &dyn Any, _context: &mut PluginContext) {
+            if let Some(Ping(value)) = message.downcast_ref::<Ping>() {
+                self.received.push(*value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plugin_messages() {
+        use crate::engine::{Engine, EngineInitParams};
+
+        let task_pool = Arc::new(TaskPool::default());
+        let mut engine = Engine::new(EngineInitParams {
+            graphics_context_params: Default::default(),
+            serialization_context: Arc::new(Default::default()),
+            widget_constructors: Arc::new(Default::default()),
+            resource_manager: ResourceManager::new(Arc::new(FsResourceIo), task_pool.clone()),
+            task_pool,
+        })
+        .unwrap();
+
+        engine.add_plugin(PingSender::default());
+        engine.add_plugin(PingReceiver::default());
+
+        let is_running = Cell::new(true);
+        let controller = ApplicationLoopController::Headless {
+            running: &is_running,
+        };
+
+        engine.enable_plugins(None, true, controller);
+
+        // The message is sent on the first update and delivered on the second one.
+        for _ in 0..3 {
+            engine.update(1.0 / 60.0, controller, &mut 0.0, Default::default());
+        }
+
+        let sender = engine.plugins()[0].cast::<PingSender>().unwrap();
+        assert!(sender.sent);
+        assert_eq!(sender.received, 0);
+        let receiver = engine.plugins()[1].cast::<PingReceiver>().unwrap();
+        assert_eq!(receiver.received, vec![42]);
+    }
+
+    #[derive(Default, Visit, Reflect, Debug)]
+    #[reflect(non_cloneable)]
+    struct OrderedPlugin {
+        order: i32,
+        rendered: bool,
+        // Amount of plugins that were rendered before this one.
+        position: usize,
+    }
+
+    impl Plugin for OrderedPlugin {
+        fn before_rendering(&mut self, context: PluginContext) {
+            self.position = context
+                .plugins
+                .iter()
+                .filter(|plugin| {
+                    plugin
+                        .cast::<OrderedPlugin>()
+                        .is_some_and(|plugin| plugin.rendered)
+                })
+                .count();
+            self.rendered = true;
+        }
+
+        fn render_order(&self) -> i32 {
+            self.order
+        }
+    }
+
+    #[test]
+    fn test_plugin_render_order() {
+        use crate::engine::{Engine, EngineInitParams};
+
+        let task_pool = Arc::new(TaskPool::default());
+        let mut engine = Engine::new(EngineInitParams {
+            graphics_context_params: Default::default(),
+            serialization_context: Arc::new(Default::default()),
+            widget_constructors: Arc::new(Default::default()),
+            resource_manager: ResourceManager::new(Arc::new(FsResourceIo), task_pool.clone()),
+            task_pool,
+        })
+        .unwrap();
+
+        for order in [5, -1, 3, 0] {
+            engine.add_plugin(OrderedPlugin {
+                order,
+                ..Default::default()
+            });
+        }
+
+        let is_running = Cell::new(true);
+        let controller = ApplicationLoopController::Headless {
+            running: &is_running,
+        };
+
+        engine.enable_plugins(None, true, controller);
+        engine.handle_before_rendering_by_plugins(1.0 / 60.0, controller, &mut 0.0);
+
+        let positions = engine
+            .plugins()
+            .iter()
+            .map(|plugin| plugin.cast::<OrderedPlugin>().unwrap().position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![3, 0, 2, 1]);
+    }
+
+    #[derive(Default, Visit, Reflect, Debug, TypeUuidProvider)]
+    #[type_uuid(id = "6d0a5b8e-2f3c-4a1d-9e7b-0c4f8a2d1e35")]
+    #[reflect(non_cloneable)]
+    struct PluginWithId {
+        value: u32,
+    }
+
+    impl Plugin for PluginWithId {
+        fn id(&self) -> Uuid {
+            Self::type_uuid()
+        }
+    }
+
+    #[test]
+    fn test_plugin_by_id() {
+        let mut plugins = vec![
+            PluginContainer::Static(Box::new(PingReceiver::default())),
+            PluginContainer::Static(Box::new(PluginWithId { value: 123 })),
+        ];
+
+        let (_, other_plugins) = OtherPlugins::split(&mut plugins, 0);
+        let plugin = other_plugins.by_id(PluginWithId::type_uuid()).unwrap();
+        assert_eq!(plugin.id(), PluginWithId::type_uuid());
+        assert_eq!(plugin.cast::<PluginWithId>().unwrap().value, 123);
+
+        // Plugins without an id cannot be found.
+        assert!(other_plugins.by_id(Uuid::nil()).is_none());
+
+        // The current plugin is not accessible.
+        let (_, other_plugins) = OtherPlugins::split(&mut plugins, 1);
+        assert!(other_plugins.by_id(PluginWithId::type_uuid()).is_none());
+    }
+
+    struct PluginLoader;
+
+    impl ResourceLoader for PluginLoader {
+        fn extensions(&self) -> &[&str] {
+            &["plugin_ext"]
+        }
+
+        fn data_type_uuid(&self) -> Uuid {
+            Default::default()
+        }
+
+        fn load(&self, _path: PathBuf, _io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture {
+            unreachable!()
+        }
+    }
+
+    #[derive(Default, Visit, Reflect, Debug)]
+    #[reflect(non_cloneable)]
+    struct PluginWithLoader;
+
+    impl Plugin for PluginWithLoader {
+        fn register(&self, context: PluginRegistrationContext) {
+            context.resource_manager.add_loader(PluginLoader);
+        }
+    }
+
+    #[test]
+    fn test_plugin_loaders_removal() {
+        use crate::engine::{Engine, EngineInitParams};
+
+        let task_pool = Arc::new(TaskPool::default());
+        let mut engine = Engine::new(EngineInitParams {
+            graphics_context_params: Default::default(),
+            serialization_context: Arc::new(Default::default()),
+            widget_constructors: Arc::new(Default::default()),
+            resource_manager: ResourceManager::new(Arc::new(FsResourceIo), task_pool.clone()),
+            task_pool,
+        })
+        .unwrap();
+
+        let loaders = engine.resource_manager.state().loaders.clone();
+        let loaders_count = loaders.safe_lock().len();
+
+        engine.add_plugin(PluginWithLoader);
+        assert!(loaders.safe_lock().find::<PluginLoader>().is_some());
+        assert_eq!(loaders.safe_lock().len(), loaders_count + 1);
+
+        let is_running = Cell::new(true);
+        let controller = ApplicationLoopController::Headless {
+            running: &is_running,
+        };
+        engine.enable_plugins(None, true, controller);
+        engine.enable_plugins(None, false, controller);
+
+        assert!(loaders.safe_lock().find::<PluginLoader>().is_none());
+        assert_eq!(loaders.safe_lock().len(), loaders_count);
+    }
+
     #[test]
     #[cfg(not(target_os = "macos"))] // This fails on macOS for some reason.
     fn test_async_script_tasks() {
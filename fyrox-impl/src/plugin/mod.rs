@@ -28,7 +28,7 @@ use crate::engine::input::InputState;
 use crate::engine::ApplicationLoopController;
 use crate::{
     asset::manager::ResourceManager,
-    core::{pool::Handle, reflect::Reflect, visitor::Visit},
+    core::{pool::Handle, reflect::Reflect, uuid::Uuid, visitor::Visit},
     engine::{
         task::TaskPoolHandler, AsyncSceneLoader, GraphicsContext, PerformanceStatistics,
         ScriptProcessor, SerializationContext,
@@ -44,6 +44,7 @@ use fyrox_core::define_as_any_trait;
 use fyrox_core::visitor::error::VisitError;
 use fyrox_ui::UserInterface;
 use std::{
+    any::Any,
     ops::{Deref, DerefMut},
     path::Path,
     sync::Arc,
@@ -111,6 +112,118 @@ impl DerefMut for PluginContainer {
     }
 }
 
+/// A set of plugins, that excludes the plugin which is currently being called. It allows plugins
+/// to access each other from [`PluginContext`], while the currently called plugin is accessible
+/// via `self`.
+#[derive(Default)]
+pub struct OtherPlugins<'a> {
+    index: Option<usize>,
+    before: &'a mut [PluginContainer],
+    after: &'a mut [PluginContainer],
+}
+
+impl<'a> OtherPlugins<'a> {
+    /// Splits the given plugins into the plugin at the given index and the rest of the plugins.
+    /// Panics if the index is out of bounds.
+    pub fn split(
+        plugins: &'a mut [PluginContainer],
+        index: usize,
+    ) -> (&'a mut PluginContainer, Self) {
+        let (before, rest) = plugins.split_at_mut(index);
+        let (current, after) = rest.split_first_mut().expect("index out of bounds");
+        (
+            current,
+            Self {
+                index: Some(index),
+                before,
+                after,
+            },
+        )
+    }
+
+    /// Returns an index of the plugin, that is currently being called. Could be `None` if the
+    /// set was created without a plugin.
+    #[inline]
+    pub fn current_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns total amount of plugins in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.before.len() + self.after.len()
+    }
+
+    /// Returns `true` if the set contains no plugins.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator yielding shared references to the plugins.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &PluginContainer> {
+        self.before.iter().chain(self.after.iter())
+    }
+
+    /// Returns an iterator yielding mutable references to the plugins.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut PluginContainer> {
+        self.before.iter_mut().chain(self.after.iter_mut())
+    }
+
+    /// Searches for a plugin of the given type `T`.
+    #[inline]
+    pub fn of_type_ref<T>(&self) -> Option<&T>
+    where
+        T: Plugin,
+    {
+        self.iter().find_map(|p| p.cast::<T>())
+    }
+
+    /// Searches for a plugin of the given type `T`.
+    #[inline]
+    pub fn of_type_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Plugin,
+    {
+        self.iter_mut().find_map(|p| p.cast_mut::<T>())
+    }
+
+    /// Searches for a plugin with the given id (see [`Plugin::id`]). Plugins with nil id are
+    /// ignored.
+    #[inline]
+    pub fn by_id(&self, id: Uuid) -> Option<&dyn Plugin> {
+        if id.is_nil() {
+            return None;
+        }
+
+        self.iter().map(|p| p.deref()).find(|p| p.id() == id)
+    }
+
+    /// Searches for a plugin with the given id (see [`Plugin::id`]). Plugins with nil id are
+    /// ignored.
+    #[inline]
+    pub fn by_id_mut(&mut self, id: Uuid) -> Option<&mut dyn Plugin> {
+        if id.is_nil() {
+            return None;
+        }
+
+        self.iter_mut()
+            .map(|p| p.deref_mut())
+            .find(|p| p.id() == id)
+    }
+}
+
+/// A message, that was sent by a plugin to the rest of the plugins. See
+/// [`PluginContext::send_plugin_message`] for more info.
+pub struct PluginMessage {
+    /// An index of the plugin, that has sent the message. `None` if the sender is unknown.
+    pub sender: Option<usize>,
+    /// Actual message.
+    pub payload: Box<dyn Any + Send>,
+}
+
 /// Contains plugin environment for the registration stage.
 pub struct PluginRegistrationContext<'a> {
     /// A reference to serialization context of the engine. See [`SerializationContext`] for more
@@ -119,7 +232,8 @@ pub struct PluginRegistrationContext<'a> {
     /// A reference to serialization context of the engine. See [`WidgetConstructorContainer`] for more
     /// info.
     pub widget_constructors: &'a Arc<WidgetConstructorContainer>,
-    /// A reference to the resource manager instance of the engine. Could be used to register resource loaders.
+    /// A reference to the resource manager instance of the engine. Could be used to register resource
+    /// loaders, see [`Plugin::register`] docs for more info about their lifetime.
     pub resource_manager: &'a ResourceManager,
 }
 
@@ -193,6 +307,121 @@ pub struct PluginContext<'a, 'b> {
     /// **Important:** this structure does not track from which device the corresponding event has
     /// come from, if you have more than one keyboard and/or mouse, use event-based approach instead!
     pub input_state: &'a InputState,
+
+    /// The rest of the plugins of the engine, excluding the one that is currently being called. Use
+    /// [`Self::plugin_of`] or [`Self::plugin_of_mut`] to find a plugin of a particular type. Could
+    /// be empty, if the method is called when the plugins are not fully initialized or are being
+    /// destroyed.
+    pub plugins: OtherPlugins<'a>,
+
+    /// A queue of messages, that will be delivered to plugins on the next update. Use
+    /// [`Self::send_plugin_message`] to send a message.
+    pub plugin_messages: &'a mut Vec<PluginMessage>,
+}
+
+impl PluginContext<'_, '_> {
+    /// Searches for another plugin of the given type `T`. The plugin, that is currently being
+    /// called, is not accessible via this method (use `self` instead).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fyrox_impl::{
+    /// #     core::{visitor::prelude::*, reflect::prelude::*},
+    /// #     plugin::{Plugin, PluginContext},
+    /// # };
+    /// #[derive(Default, Visit, Reflect, Debug)]
+    /// #[reflect(non_cloneable)]
+    /// struct GameState {
+    ///     score: u32,
+    /// }
+    ///
+    /// impl Plugin for GameState {}
+    ///
+    /// #[derive(Default, Visit, Reflect, Debug)]
+    /// #[reflect(non_cloneable)]
+    /// struct Hud {
+    ///     displayed_score: u32,
+    /// }
+    ///
+    /// impl Plugin for Hud {
+    ///     fn update(&mut self, context: &mut PluginContext) {
+    ///         if let Some(game_state) = context.plugin_of::<GameState>() {
+    ///             self.displayed_score = game_state.score;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn plugin_of<T: Plugin>(&self) -> Option<&T> {
+        self.plugins.of_type_ref()
+    }
+
+    /// Searches for another plugin of the given type `T`. The plugin, that is currently being
+    /// called, is not accessible via this method (use `self` instead).
+    pub fn plugin_of_mut<T: Plugin>(&mut self) -> Option<&mut T> {
+        self.plugins.of_type_mut()
+    }
+
+    /// Searches for another plugin with the given id (see [`Plugin::id`]). Unlike [`Self::plugin_of`]
+    /// this method does not require to know the type of the plugin at compile time. The plugin,
+    /// that is currently being called, is not accessible via this method.
+    pub fn plugin_by_id(&self, id: Uuid) -> Option<&dyn Plugin> {
+        self.plugins.by_id(id)
+    }
+
+    /// Searches for another plugin with the given id (see [`Plugin::id`]). See
+    /// [`Self::plugin_by_id`] for more info.
+    pub fn plugin_by_id_mut(&mut self, id: Uuid) -> Option<&mut dyn Plugin> {
+        self.plugins.by_id_mut(id)
+    }
+
+    /// Sends a message to every other plugin. The message is queued and will be delivered to
+    /// [`Plugin::on_plugin_message`] of every plugin, except the sender, on the next update. It
+    /// allows plugins to communicate with each other without knowing the exact types of each
+    /// other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use fyrox_impl::{
+    /// #     core::{visitor::prelude::*, reflect::prelude::*},
+    /// #     plugin::{Plugin, PluginContext},
+    /// # };
+    /// # use std::any::Any;
+    /// struct LevelCompleted {
+    ///     score: u32,
+    /// }
+    ///
+    /// #[derive(Default, Visit, Reflect, Debug)]
+    /// #[reflect(non_cloneable)]
+    /// struct Game;
+    ///
+    /// impl Plugin for Game {
+    ///     fn update(&mut self, context: &mut PluginContext) {
+    ///         context.send_plugin_message(LevelCompleted { score: 100 });
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default, Visit, Reflect, Debug)]
+    /// #[reflect(non_cloneable)]
+    /// struct Achievements {
+    ///     best_score: u32,
+    /// }
+    ///
+    /// impl Plugin for Achievements {
+    ///     fn on_plugin_message(&mut self, message: &dyn Any, context: &mut PluginContext) {
+    ///         if let Some(level_completed) = message.downcast_ref::<LevelCompleted>() {
+    ///             self.best_score = self.best_score.max(level_completed.score);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn send_plugin_message<M: Any + Send>(&mut self, message: M) {
+        self.plugin_messages.push(PluginMessage {
+            sender: self.plugins.current_index(),
+            payload: Box::new(message),
+        })
+    }
 }
 
 define_as_any_trait!(PluginAsAny => Plugin);
@@ -257,8 +486,40 @@ impl dyn Plugin {
 /// }
 /// ```
 pub trait Plugin: PluginAsAny + Visit + Reflect {
+    /// Returns a stable unique identifier of the plugin, that could be used to find the plugin
+    /// without knowing its type (see [`PluginContext::plugin_by_id`]) and to store references to
+    /// plugins across sessions. The default implementation returns nil id, which means that the
+    /// plugin cannot be found by its id. The easiest way to give the plugin an id is to derive
+    /// [`crate::core::TypeUuidProvider`] and return the type uuid:
+    ///
+    /// ```rust
+    /// # use fyrox_impl::{
+    /// #     core::{visitor::prelude::*, reflect::prelude::*, uuid::Uuid, TypeUuidProvider},
+    /// #     plugin::Plugin,
+    /// # };
+    /// #[derive(Default, Visit, Reflect, Debug, TypeUuidProvider)]
+    /// #[type_uuid(id = "3b2a1f6c-0d4e-4f59-9a2b-8c7d6e5f4a3b")]
+    /// #[reflect(non_cloneable)]
+    /// struct MyPlugin;
+    ///
+    /// impl Plugin for MyPlugin {
+    ///     fn id(&self) -> Uuid {
+    ///         Self::type_uuid()
+    ///     }
+    /// }
+    /// ```
+    fn id(&self) -> Uuid {
+        Uuid::nil()
+    }
+
     /// The method is called when the plugin constructor was just registered in the engine. The main
     /// use of this method is to register scripts and custom scene graph nodes in [`SerializationContext`].
+    ///
+    /// Resource loaders could also be registered here, the resource manager is fully constructed at
+    /// this moment and ready to accept new loaders. Every loader added by the plugin in this method
+    /// is associated with the plugin and automatically removed from the resource manager right after
+    /// [`Self::on_deinit`] (or before the plugin is hot-reloaded). Loaders that replace existing ones
+    /// of the same type are not removed.
     fn register(&self, #[allow(unused_variables)] context: PluginRegistrationContext) {}
 
     /// This method is used to register property editors for your game types; to make them editable
@@ -286,9 +547,41 @@ pub trait Plugin: PluginAsAny + Visit + Reflect {
     fn on_deinit(&mut self, #[allow(unused_variables)] context: PluginContext) {}
 
     /// Updates the plugin internals at fixed rate (see [`PluginContext::dt`] parameter for more
-    /// info).
+    /// info). This method could be called zero, one or multiple times per frame, see
+    /// [`Self::update_variable`] docs for more info.
     fn update(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
 
+    /// This method is called right before [`Self::update`], with the same fixed rate. The engine
+    /// calls this method for every plugin first, then [`Self::update`] for every plugin and then
+    /// [`Self::after_update`] for every plugin. This way, every plugin could prepare some data
+    /// (for example, take a snapshot of the input state) before any other plugin is updated.
+    fn before_update(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
+
+    /// This method is called right after [`Self::update`] was called for every plugin, with the
+    /// same fixed rate. See [`Self::before_update`] docs for the exact sequence. Scripts and scenes
+    /// are updated after this method.
+    fn after_update(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
+
+    /// Updates the plugin internals once per rendered frame with variable time step. `real_dt` is
+    /// the real amount of time (in seconds) passed since the previous frame. It should be used for
+    /// the things that must run at render rate, for example camera interpolation. The game loop of
+    /// the executor does the following every frame:
+    ///
+    /// 1. Adds `real_dt` to the time accumulator (`lag`) and then calls [`Self::update`] with the
+    /// fixed time step ([`PluginContext::dt`]) while the accumulator holds at least one fixed time
+    /// step, subtracting the fixed time step after each call. So [`Self::update`] is called zero,
+    /// one or multiple times per frame.
+    /// 2. Calls this method once. At this moment [`PluginContext::lag`] holds the remaining part of
+    /// the accumulator, so `lag / dt` could be used as an interpolation factor between the last two
+    /// fixed updates.
+    /// 3. Calls [`Self::before_rendering`] and renders the frame.
+    fn update_variable(
+        &mut self,
+        #[allow(unused_variables)] context: &mut PluginContext,
+        #[allow(unused_variables)] real_dt: f32,
+    ) {
+    }
+
     /// called after all Plugin and Script updates
     fn post_update(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
 
@@ -311,13 +604,45 @@ pub trait Plugin: PluginAsAny + Visit + Reflect {
     }
 
     /// The method is called before the actual frame rendering. It could be useful to render off-screen
-    /// data (render something to texture, that can be used later in the main frame).
+    /// data (render something to texture, that can be used later in the main frame). See
+    /// [`Self::render_order`] to define the order of the calls, when multiple plugins do this.
     fn before_rendering(&mut self, #[allow(unused_variables)] context: PluginContext) {}
 
+    /// Defines the order in which [`Self::before_rendering`] is called for the plugins. Plugins with
+    /// lower values are called first, plugins with equal values are called in the order of their
+    /// registration. It could be used to compose multiple off-screen passes made by different plugins,
+    /// when one plugin renders to a texture that is used by another one. Default value is `0`.
+    fn render_order(&self) -> i32 {
+        0
+    }
+
     /// The method is called when the current graphics context was destroyed.
     fn on_graphics_context_destroyed(&mut self, #[allow(unused_variables)] context: PluginContext) {
     }
 
+    /// The method is called when there is a message sent by some other plugin via
+    /// [`PluginContext::send_plugin_message`]. Use [`Any::downcast_ref`] to check the type of the
+    /// message. Messages are delivered on the next update after they were sent, right before
+    /// [`Self::before_update`].
+    fn on_plugin_message(
+        &mut self,
+        #[allow(unused_variables)] message: &dyn Any,
+        #[allow(unused_variables)] context: &mut PluginContext,
+    ) {
+    }
+
+    /// The method is called when the application is suspended (for example, when an Android app
+    /// goes to background). It could be used to pause audio or to save the game state. The method
+    /// is called right before [`Self::on_graphics_context_destroyed`], keep in mind that the
+    /// graphics context could be destroyed without suspension and vice versa on some platforms.
+    fn on_suspend(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
+
+    /// The method is called when the application is resumed after suspension. It is called right
+    /// after [`Self::on_graphics_context_initialized`]. Keep in mind, that most platforms resume
+    /// the application once right after it has started, so this method is also called once at
+    /// startup.
+    fn on_resume(&mut self, #[allow(unused_variables)] context: &mut PluginContext) {}
+
     /// The method will be called when there is any message from a user interface (UI) instance
     /// of the engine. Use `ui_handle` parameter to find out from which UI the message has come
     /// from.
@@ -360,4 +685,19 @@ pub trait Plugin: PluginAsAny + Visit + Reflect {
         #[allow(unused_variables)] context: &mut PluginContext,
     ) {
     }
+    /// This method is called when a scene was removed from the scene container (see
+    /// [`crate::scene::SceneContainer::remove`]). `path` is the path the scene was loaded from,
+    /// it is `None` for scenes that were created in code. The scene is already destroyed at this
+    /// moment, `scene` handle is given only to identify the scene and it must not be used to
+    /// access the container. The method is called on the next update after the removal, after
+    /// all the scripts of the scene were de-initialized. When the engine is being destroyed, all
+    /// remaining scenes are removed and this method is called for each of them before
+    /// [`Plugin::on_deinit`].
+    fn on_scene_unloaded(
+        &mut self,
+        #[allow(unused_variables)] path: Option<&Path>,
+        #[allow(unused_variables)] scene: Handle<Scene>,
+        #[allow(unused_variables)] context: &mut PluginContext,
+    ) {
+    }
 }
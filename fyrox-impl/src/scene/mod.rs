@@ -77,7 +77,7 @@ use crate::{
     },
     utils::navmesh::Navmesh,
 };
-use fxhash::FxHashSet;
+use fxhash::{FxHashMap, FxHashSet};
 use std::{
     fmt::{Display, Formatter},
     ops::{Index, IndexMut},
@@ -626,7 +626,9 @@ impl Scene {
 pub struct SceneContainer {
     pool: Pool<Scene>,
     sound_engine: SoundEngine,
-    pub(crate) destruction_list: Vec<(Handle<Scene>, Scene)>,
+    paths: FxHashMap<Handle<Scene>, PathBuf>,
+    pub(crate) destruction_list: Vec<(Handle<Scene>, Scene, Option<PathBuf>)>,
+    pub(crate) unloaded_list: Vec<(Handle<Scene>, Option<PathBuf>)>,
 }
 
 impl SceneContainer {
@@ -634,7 +636,9 @@ impl SceneContainer {
         Self {
             pool: Pool::new(),
             sound_engine,
+            paths: Default::default(),
             destruction_list: Default::default(),
+            unloaded_list: Default::default(),
         }
     }
 
@@ -684,10 +688,28 @@ impl SceneContainer {
         self.pool.spawn(scene)
     }
 
-    /// Removes all scenes from container.
+    /// Adds new scene into container and remembers the path it was loaded from. The path will be
+    /// passed to [`crate::plugin::Plugin::on_scene_unloaded`] when the scene is removed.
+    #[inline]
+    pub fn add_with_path(&mut self, scene: Scene, path: PathBuf) -> Handle<Scene> {
+        let handle = self.add(scene);
+        self.paths.insert(handle, path);
+        handle
+    }
+
+    /// Returns a path from which the scene with the given handle was loaded (if any).
+    #[inline]
+    pub fn path(&self, handle: Handle<Scene>) -> Option<&Path> {
+        self.paths.get(&handle).map(|path| path.as_path())
+    }
+
+    /// Removes all scenes from container. The scenes will be destroyed on a next update call.
     #[inline]
     pub fn clear(&mut self) {
-        self.pool.clear()
+        let handles = self.pool.pair_iter().map(|(h, _)| h).collect::<Vec<_>>();
+        for handle in handles {
+            self.remove(handle);
+        }
     }
 
     /// Removes given scene from container. The scene will be destroyed on a next update call.
@@ -696,13 +718,17 @@ impl SceneContainer {
         self.sound_engine
             .state()
             .remove_context(self.pool[handle].graph.sound_context.native.clone());
-        self.destruction_list.push((handle, self.pool.free(handle)));
+        let path = self.paths.remove(&handle);
+        self.destruction_list
+            .push((handle, self.pool.free(handle), path));
     }
 
     /// Takes scene from the container and transfers ownership to caller. You must either
     /// put scene back using ticket or call `forget_ticket` to make memory used by scene
-    /// vacant again.
+    /// vacant again. The container forgets the path the scene was loaded from (see
+    /// [`Self::add_with_path`]).
     pub fn take_reserve(&mut self, handle: Handle<Scene>) -> (Ticket<Scene>, Scene) {
+        self.paths.remove(&handle);
         self.pool.take_reserve(handle)
     }
 
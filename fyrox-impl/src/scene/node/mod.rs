@@ -597,6 +597,10 @@ impl Reflect for Node {
         self.0.deref().doc()
     }
 
+    fn type_source_path(&self) -> &'static str {
+        self.0.deref().type_source_path()
+    }
+
     fn assembly_name(&self) -> &'static str {
         self.0.deref().assembly_name()
     }
@@ -609,6 +613,10 @@ impl Reflect for Node {
         self.0.deref().fields_ref(func)
     }
 
+    fn field_count(&self) -> usize {
+        self.0.deref().field_count()
+    }
+
     fn fields_mut(&mut self, func: &mut dyn FnMut(&mut [FieldMut])) {
         self.0.deref_mut().fields_mut(func)
     }
@@ -87,6 +87,10 @@ impl Reflect for Limb {
         ""
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
@@ -100,6 +104,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "Bone",
+                    aliases: &[],
                     display_name: "Bone",
                     tag: "",
                     read_only: false,
@@ -118,6 +123,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "PhysicalBone",
+                    aliases: &[],
                     display_name: "Physical Bone",
                     tag: "",
                     read_only: false,
@@ -136,6 +142,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "Children",
+                    aliases: &[],
                     display_name: "Children",
                     tag: "",
                     read_only: false,
@@ -159,6 +166,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "Bone",
+                    aliases: &[],
                     display_name: "Bone",
                     tag: "",
                     read_only: false,
@@ -177,6 +185,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "PhysicalBone",
+                    aliases: &[],
                     display_name: "Physical Bone",
                     tag: "",
                     read_only: false,
@@ -195,6 +204,7 @@ impl Reflect for Limb {
             {
                 static METADATA: FieldMetadata = FieldMetadata {
                     name: "Children",
+                    aliases: &[],
                     display_name: "Children",
                     tag: "",
                     read_only: false,
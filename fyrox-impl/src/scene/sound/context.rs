@@ -80,6 +80,27 @@ impl SoundContextGuard<'_> {
         self.guard.distance_model()
     }
 
+    /// Sets new speed of sound (in units per second) of the environment. The value is informational
+    /// only, the renderer does not use it yet.
+    pub fn set_speed_of_sound(&mut self, speed_of_sound: f32) {
+        self.guard.set_speed_of_sound(speed_of_sound);
+    }
+
+    /// Returns current speed of sound (in units per second) of the environment.
+    pub fn speed_of_sound(&self) -> f32 {
+        self.guard.speed_of_sound()
+    }
+
+    /// Sets new master gain of the context. It scales the output of the whole context.
+    pub fn set_master_gain(&mut self, master_gain: f32) {
+        self.guard.set_master_gain(master_gain);
+    }
+
+    /// Returns current master gain of the context.
+    pub fn master_gain(&self) -> f32 {
+        self.guard.master_gain()
+    }
+
     /// Normalizes given frequency using context's sampling rate. Normalized frequency then can be used
     /// to create filters.
     pub fn normalize_frequency(&self, f: f32) -> f32 {
@@ -27,7 +27,7 @@ use crate::{
     core::{
         log::Log,
         pool::Handle,
-        reflect::{FieldRef, Reflect, ReflectArray, ReflectList},
+        reflect::{FieldRef, Reflect, ReflectArray, ReflectEnum, ReflectList, ReflectOption},
         type_traits::ComponentProvider,
         uuid::Uuid,
         visitor::{Visit, VisitResult, Visitor},
@@ -719,6 +719,10 @@ impl Reflect for Script {
         self.instance.doc()
     }
 
+    fn type_source_path(&self) -> &'static str {
+        self.instance.type_source_path()
+    }
+
     fn assembly_name(&self) -> &'static str {
         self.instance.assembly_name()
     }
@@ -731,6 +735,10 @@ impl Reflect for Script {
         self.instance.fields_ref(func)
     }
 
+    fn field_count(&self) -> usize {
+        self.instance.field_count()
+    }
+
     fn fields_mut(&mut self, func: &mut dyn FnMut(&mut [FieldMut])) {
         self.instance.fields_mut(func)
     }
@@ -783,6 +791,26 @@ impl Reflect for Script {
         self.instance.deref_mut().as_list_mut(func)
     }
 
+    fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+        self.instance.deref().as_enum(func)
+    }
+
+    fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+        self.instance.deref_mut().as_enum_mut(func)
+    }
+
+    fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+        self.instance.deref().as_option(func)
+    }
+
+    fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+        self.instance.deref_mut().as_option_mut(func)
+    }
+
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        self.instance.deref().reflect_partial_eq(other)
+    }
+
     fn try_clone_box(&self) -> Option<Box<dyn Reflect>> {
         Some(Box::new(self.clone()))
     }
@@ -32,10 +32,11 @@ use crate::core::{
     visitor::prelude::*,
 };
 
+use fxhash::FxHashMap;
 use std::{
     cmp::Ordering,
-    collections::BinaryHeap,
-    fmt::{Display, Formatter},
+    collections::{BinaryHeap, VecDeque},
+    fmt::{Debug, Display, Formatter, Write},
     ops::{Deref, DerefMut},
 };
 
@@ -47,9 +48,22 @@ pub struct VertexData {
     pub position: Vector3<f32>,
     /// A set of indices of neighbour vertices.
     pub neighbours: Vec<u32>,
-    /// Penalty can be interpreted as measure, how harder is to travel to this vertex.
-    #[visit(skip)]
+    /// Penalty can be interpreted as measure, how harder is to travel to this vertex. The default
+    /// cost of travel to this vertex is the distance to it multiplied by the penalty.
+    ///
+    /// # Notes
+    ///
+    /// Previous versions multiplied the **squared** distance by the penalty. The distance is used
+    /// now, so the cost of a path is its length (scaled by the penalties) and the default heuristic
+    /// (see [`euclidean_heuristic`]) never overestimates it. Penalties tuned for the old metric
+    /// might need to be adjusted.
+    #[visit(optional)]
     pub g_penalty: f32,
+    /// Explicit costs of travel from this vertex to some of its neighbours (the key is the index of a
+    /// neighbour vertex). Links that have no explicit cost use the default cost - distance between the
+    /// vertices multiplied by the penalty of the neighbour vertex. See [`Graph::link_unidirect_weighted`].
+    #[visit(optional)]
+    pub edge_costs: FxHashMap<u32, f32>,
 }
 
 impl Default for VertexData {
@@ -58,6 +72,7 @@ impl Default for VertexData {
             position: Default::default(),
             g_penalty: 1f32,
             neighbours: Default::default(),
+            edge_costs: Default::default(),
         }
     }
 }
@@ -69,6 +84,18 @@ impl VertexData {
             position,
             g_penalty: 1f32,
             neighbours: Default::default(),
+            edge_costs: Default::default(),
+        }
+    }
+
+    /// Returns the cost of travel from this vertex to the given neighbour vertex. It is either the
+    /// explicit cost of the link (see [`Self::edge_costs`]) or the distance (not squared distance,
+    /// see [`Self::g_penalty`]) between the vertices multiplied by the penalty of the neighbour
+    /// vertex.
+    pub fn travel_cost(&self, neighbour_index: u32, neighbour: &VertexData) -> f32 {
+        match self.edge_costs.get(&neighbour_index) {
+            Some(cost) => *cost,
+            None => (self.position - neighbour.position).norm() * neighbour.g_penalty,
         }
     }
 }
@@ -124,7 +151,7 @@ impl VertexDataProvider for GraphVertex {}
 /// A collection of GraphVertices for pathfinding.
 ///
 /// See module docs
-#[derive(Clone, Debug, Visit, PartialEq)]
+#[derive(Clone, Visit)]
 pub struct Graph<T>
 where
     T: VertexDataProvider,
@@ -133,7 +160,7 @@ where
     pub vertices: Vec<T>,
     /// The maximum iterations A* pathfinding will attempt before giving up and returning its best path.
     ///
-    /// **Default:** 1000
+    /// **Default:** 0 (automatic)
     ///
     /// # Notes
     ///
@@ -141,8 +168,186 @@ where
     /// Higher max iteration numbers will be required for huge graphs and graphs with many obstacles.
     /// Whereas, lower max iterations may be desired for smaller simple graphs.
     ///
+    /// **Zero** computes the maximum iterations from the amount of vertices in the graph, which is
+    /// enough to visit every vertex once, so a full path will always be found if it exists.
+    ///
     /// **Negative numbers** disable max iterations
     pub max_search_iterations: i32,
+    /// Optional acceleration structure for [`Self::get_closest_vertex_to`], see
+    /// [`Self::rebuild_spatial_index`].
+    #[visit(skip)]
+    spatial_index: Option<SpatialIndex>,
+}
+
+// The spatial index is just a cache, so it is not taken into account.
+impl<T> PartialEq for Graph<T>
+where
+    T: VertexDataProvider + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices == other.vertices && self.max_search_iterations == other.max_search_iterations
+    }
+}
+
+impl<T> Debug for Graph<T>
+where
+    T: VertexDataProvider + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Graph")
+            .field("vertices", &self.vertices)
+            .field("max_search_iterations", &self.max_search_iterations)
+            .finish()
+    }
+}
+
+/// Uniform grid over the positions of the vertices, that is used to accelerate closest vertex
+/// queries. Every cell stores indices of the vertices inside it.
+#[derive(Clone, Debug, PartialEq)]
+struct SpatialIndex {
+    origin: Vector3<f32>,
+    cell_size: f32,
+    dimensions: [usize; 3],
+    cells: Vec<Vec<u32>>,
+    vertex_count: usize,
+}
+
+impl SpatialIndex {
+    fn new<T: PositionProvider>(vertices: &[T]) -> Self {
+        let (min, max) = if vertices.is_empty() {
+            (Vector3::default(), Vector3::default())
+        } else {
+            vertices.iter().fold(
+                (Vector3::repeat(f32::MAX), Vector3::repeat(-f32::MAX)),
+                |(min, max), vertex| {
+                    let position = vertex.position();
+                    (min.inf(&position), max.sup(&position))
+                },
+            )
+        };
+
+        let extent = max - min;
+
+        // Pick the cell size so that there's roughly one vertex per cell, degenerate axes (for
+        // example, vertical axis of a flat navmesh) are ignored.
+        let mut measure = 1.0;
+        let mut axis_count = 0;
+        for axis_extent in extent.iter() {
+            if *axis_extent > f32::EPSILON {
+                measure *= *axis_extent;
+                axis_count += 1;
+            }
+        }
+        let mut cell_size = if axis_count == 0 {
+            1.0
+        } else {
+            (measure / vertices.len() as f32)
+                .powf(1.0 / axis_count as f32)
+                .max(f32::EPSILON)
+        };
+
+        let dimensions_for = |cell_size: f32| {
+            let mut dimensions = [1; 3];
+            for (dimension, axis_extent) in dimensions.iter_mut().zip(extent.iter()) {
+                *dimension = (*axis_extent / cell_size) as usize + 1;
+            }
+            dimensions
+        };
+
+        // Protect from excessive memory usage when the vertices are distributed very unevenly.
+        let max_cell_count = vertices.len().max(1) * 2;
+        let mut dimensions = dimensions_for(cell_size);
+        while dimensions
+            .iter()
+            .try_fold(1usize, |count, dimension| count.checked_mul(*dimension))
+            .is_none_or(|count| count > max_cell_count)
+        {
+            cell_size *= 2.0;
+            dimensions = dimensions_for(cell_size);
+        }
+
+        let mut index = Self {
+            origin: min,
+            cell_size,
+            dimensions,
+            cells: vec![Vec::new(); dimensions.iter().product()],
+            vertex_count: vertices.len(),
+        };
+
+        for (vertex_index, vertex) in vertices.iter().enumerate() {
+            let cell = index.cell_of(vertex.position());
+            let cell_index = index.linear_index(cell);
+            index.cells[cell_index].push(vertex_index as u32);
+        }
+
+        index
+    }
+
+    fn cell_of(&self, point: Vector3<f32>) -> [usize; 3] {
+        let mut cell = [0; 3];
+        for (axis, coordinate) in cell.iter_mut().enumerate() {
+            let local = ((point[axis] - self.origin[axis]) / self.cell_size).floor();
+            *coordinate = (local.max(0.0) as usize).min(self.dimensions[axis] - 1);
+        }
+        cell
+    }
+
+    fn linear_index(&self, cell: [usize; 3]) -> usize {
+        (cell[2] * self.dimensions[1] + cell[1]) * self.dimensions[0] + cell[0]
+    }
+
+    fn closest<T: PositionProvider>(&self, vertices: &[T], point: Vector3<f32>) -> Option<usize> {
+        let center = self.cell_of(point);
+        let max_radius = self.dimensions.iter().copied().max().unwrap_or_default();
+
+        let mut closest: Option<(usize, f32)> = None;
+
+        // Check cells in "rings" around the cell of the point, until it is guaranteed that every
+        // remaining cell is farther than the closest vertex found so far.
+        for radius in 0..=max_radius {
+            let range = |axis: usize| {
+                center[axis].saturating_sub(radius)
+                    ..=(center[axis] + radius).min(self.dimensions[axis] - 1)
+            };
+
+            for z in range(2) {
+                for y in range(1) {
+                    for x in range(0) {
+                        let distance = center[0]
+                            .abs_diff(x)
+                            .max(center[1].abs_diff(y))
+                            .max(center[2].abs_diff(z));
+                        if distance != radius {
+                            continue;
+                        }
+
+                        for vertex_index in self.cells[self.linear_index([x, y, z])].iter() {
+                            let vertex_index = *vertex_index as usize;
+                            let sqr_distance =
+                                (vertices[vertex_index].position() - point).norm_squared();
+                            // Prefer lower indices to match the result of the linear search.
+                            let is_closer = closest.is_none_or(|(index, closest_distance)| {
+                                sqr_distance < closest_distance
+                                    || (sqr_distance == closest_distance && vertex_index < index)
+                            });
+                            if is_closer {
+                                closest = Some((vertex_index, sqr_distance));
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some((_, sqr_distance)) = closest {
+                let bound = radius as f32 * self.cell_size;
+                if sqr_distance <= bound * bound {
+                    break;
+                }
+            }
+        }
+
+        closest.map(|(index, _)| index)
+    }
 }
 
 /// Shows path status.
@@ -160,8 +365,46 @@ pub enum PathKind {
     Partial,
 }
 
-fn heuristic(a: Vector3<f32>, b: Vector3<f32>) -> f32 {
-    (a - b).norm_squared()
+/// Default heuristic - straight-line (Euclidean) distance between two points. It never overestimates
+/// the actual cost of travel (as long as penalties are not less than one), so A* finds optimal paths.
+pub fn euclidean_heuristic(a: Vector3<f32>, b: Vector3<f32>) -> f32 {
+    (a - b).norm()
+}
+
+/// Removes redundant intermediate points of the given path (so called "string pulling"). A point is
+/// removed if there is an unobstructed line between the points around it, `is_visible` closure is used
+/// to check whether a line between two points is unobstructed (for example, using a ray cast or a
+/// navmesh query). The method is greedy - for every kept point it searches for the farthest visible
+/// point of the path and skips everything in between.
+///
+/// First and last points of the path are always preserved. Paths with less than three points are
+/// left unchanged.
+pub fn smooth_path<F>(path: &mut Vec<Vector3<f32>>, is_visible: F)
+where
+    F: Fn(Vector3<f32>, Vector3<f32>) -> bool,
+{
+    if path.len() < 3 {
+        return;
+    }
+
+    let mut smoothed = Vec::with_capacity(path.len());
+    let mut current = 0;
+    smoothed.push(path[current]);
+
+    while current < path.len() - 1 {
+        // Adjacent points are always considered visible to each other.
+        let mut next = current + 1;
+        for candidate in ((current + 2)..path.len()).rev() {
+            if is_visible(path[current], path[candidate]) {
+                next = candidate;
+                break;
+            }
+        }
+        smoothed.push(path[next]);
+        current = next;
+    }
+
+    *path = smoothed;
 }
 
 impl<T: VertexDataProvider> Default for Graph<T> {
@@ -202,6 +445,8 @@ pub enum PathError {
     Empty,
 }
 
+impl std::error::Error for PathError {}
+
 impl Display for PathError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -293,12 +538,50 @@ impl PartialPath {
     }
 }
 
+/// An entry of Dijkstra's search queue, ordered by its cost (the lower the cost - the higher the priority).
+#[derive(Copy, Clone)]
+struct DijkstraEntry {
+    index: usize,
+    cost: f32,
+}
+
+impl Ord for DijkstraEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.total_cmp(&other.cost).reverse()
+    }
+}
+
+impl PartialOrd for DijkstraEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for DijkstraEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for DijkstraEntry {}
+
 impl<T: VertexDataProvider> Graph<T> {
     /// Creates new empty graph.
     pub fn new() -> Self {
         Self {
             vertices: Default::default(),
-            max_search_iterations: 1000i32,
+            max_search_iterations: 0i32,
+            spatial_index: None,
+        }
+    }
+
+    /// Returns the actual maximum amount of search iterations, `None` means that there's no limit.
+    /// See [`Self::max_search_iterations`] docs for more info.
+    fn search_budget(&self) -> Option<usize> {
+        match self.max_search_iterations {
+            i32::MIN..=-1 => None,
+            0 => Some(self.vertices.len()),
+            max => Some(max as usize),
         }
     }
 
@@ -307,27 +590,90 @@ impl<T: VertexDataProvider> Graph<T> {
     /// such vertices won't be built.
     pub fn set_vertices(&mut self, vertices: Vec<T>) {
         self.vertices = vertices;
+        self.spatial_index = None;
     }
 
     /// Tries to find a vertex closest to given point.
     ///
     /// # Notes
     ///
-    /// O(n) complexity.
+    /// O(n) complexity, unless the spatial index is built (see [`Self::rebuild_spatial_index`]).
     pub fn get_closest_vertex_to(&self, point: Vector3<f32>) -> Option<usize> {
-        math::get_closest_point(&self.vertices, point)
+        match self.spatial_index {
+            Some(ref spatial_index) if spatial_index.vertex_count == self.vertices.len() => {
+                spatial_index.closest(&self.vertices, point)
+            }
+            _ => math::get_closest_point(&self.vertices, point),
+        }
+    }
+
+    /// Builds an acceleration structure, that is used by [`Self::get_closest_vertex_to`] to avoid
+    /// scanning every vertex of the graph. It could be useful for huge graphs that are queried a lot
+    /// (for example, every frame for every agent).
+    ///
+    /// # Notes
+    ///
+    /// The index is discarded by every method that adds, removes or gives mutable access to the
+    /// vertices, in this case [`Self::get_closest_vertex_to`] falls back to linear search until the
+    /// index is rebuilt. If [`Self::vertices`] are modified directly, this method must be called
+    /// again (or [`Self::invalidate_spatial_index`]), otherwise closest vertex queries might return
+    /// wrong results.
+    pub fn rebuild_spatial_index(&mut self) {
+        self.spatial_index = Some(SpatialIndex::new(&self.vertices));
+    }
+
+    /// Discards the spatial index (if any), see [`Self::rebuild_spatial_index`] for more info.
+    pub fn invalidate_spatial_index(&mut self) {
+        self.spatial_index = None;
     }
 
     /// Creates bidirectional link between two vertices. Bidirectional means
     /// that point `a` can be reached from point `b` as well as point `b`
-    /// can be reached from point `a`.
+    /// can be reached from point `a`. Explicit costs of existing links are kept, see
+    /// [`Self::link_unidirect`].
     pub fn link_bidirect(&mut self, a: usize, b: usize) {
         self.link_unidirect(a, b);
         self.link_unidirect(b, a);
     }
 
+    /// Creates bidirectional links for every pair of vertices in the given adjacency list. Pairs with
+    /// out-of-bounds indices are skipped.
+    pub fn link_from_adjacency(&mut self, edges: &[(usize, usize)]) {
+        let count = self.vertices.len();
+        for &(a, b) in edges {
+            if a < count && b < count {
+                self.link_bidirect(a, b);
+            }
+        }
+    }
+
+    /// Links vertices as a regular grid of the given size, where each vertex is bidirectionally linked
+    /// with its horizontal and vertical neighbours. Vertex at `(x, y)` must have `y * width + x` index.
+    /// Links to vertices that are out of bounds of the graph are skipped.
+    pub fn link_grid(&mut self, width: usize, height: usize) {
+        let count = self.vertices.len();
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                if index >= count {
+                    return;
+                }
+                if x + 1 < width && index + 1 < count {
+                    self.link_bidirect(index, index + 1);
+                }
+                if y + 1 < height && index + width < count {
+                    self.link_bidirect(index, index + width);
+                }
+            }
+        }
+    }
+
     /// Creates unidirectional link between vertex `a` and vertex `b`. Unidirectional
     /// means that there is no direct link between `b` to `a`, only from `a` to `b`.
+    ///
+    /// If the link already exists, it is left as is - an explicit cost of the link (see
+    /// [`Self::link_unidirect_weighted`]) is kept. Remove the link from [`VertexData::edge_costs`]
+    /// to reset its cost to the default one.
     pub fn link_unidirect(&mut self, a: usize, b: usize) {
         if let Some(vertex_a) = self.vertices.get_mut(a) {
             if vertex_a.neighbours.iter().all(|n| *n != b as u32) {
@@ -336,6 +682,25 @@ impl<T: VertexDataProvider> Graph<T> {
         }
     }
 
+    /// Creates unidirectional link between vertex `a` and vertex `b` with the given cost of travel.
+    /// The cost overrides the default cost of the link (distance between the vertices multiplied by
+    /// the penalty of the vertex `b`), so it could be used for one-way roads, terrain transitions, etc.
+    /// If the link already exists, only its cost is changed.
+    ///
+    /// # Notes
+    ///
+    /// The default heuristic ([`euclidean_heuristic`]) assumes that the cost of a link is not less
+    /// than the distance between its vertices. Cheaper links may result in sub-optimal paths, use
+    /// [`Self::build_with_heuristic`] with an appropriate heuristic in this case.
+    pub fn link_unidirect_weighted(&mut self, a: usize, b: usize, cost: f32) {
+        if let Some(vertex_a) = self.vertices.get_mut(a) {
+            if vertex_a.neighbours.iter().all(|n| *n != b as u32) {
+                vertex_a.neighbours.push(b as u32);
+            }
+            vertex_a.edge_costs.insert(b as u32, cost);
+        }
+    }
+
     /// Returns shared reference to a path vertex at the given index.
     pub fn vertex(&self, index: usize) -> Option<&T> {
         self.vertices.get(index)
@@ -343,6 +708,7 @@ impl<T: VertexDataProvider> Graph<T> {
 
     /// Returns mutable reference to a path vertex at the given index.
     pub fn vertex_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.spatial_index = None;
         self.vertices.get_mut(index)
     }
 
@@ -353,15 +719,23 @@ impl<T: VertexDataProvider> Graph<T> {
 
     /// Returns mutable reference to the array of vertices.
     pub fn vertices_mut(&mut self) -> &mut [T] {
+        self.spatial_index = None;
         &mut self.vertices
     }
 
+    /// Returns an iterator over mutable references to the vertices paired with their indices.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.spatial_index = None;
+        self.vertices.iter_mut().enumerate()
+    }
+
     /// Adds a new vertex to the path finder.
     pub fn add_vertex(&mut self, vertex: T) -> u32 {
         let index = self.vertices.len();
         // Since we're adding the vertex to the end of the array, we don't need to
         // shift indices of neighbours (like `insert_vertex`)
         self.vertices.push(vertex);
+        self.spatial_index = None;
         index as u32
     }
 
@@ -380,6 +754,8 @@ impl<T: VertexDataProvider> Graph<T> {
     /// deleted vertex from every other vertex in the graph and shifts indices of neighbour vertices, to
     /// preserve graph structure.
     pub fn remove_vertex(&mut self, index: usize) -> T {
+        self.spatial_index = None;
+
         for other_vertex in self.vertices.iter_mut() {
             // Remove "references" to the vertex, that will be deleted.
             if let Some(position) = other_vertex
@@ -396,15 +772,103 @@ impl<T: VertexDataProvider> Graph<T> {
                     *neighbour_index -= 1;
                 }
             }
+
+            if !other_vertex.edge_costs.is_empty() {
+                other_vertex.edge_costs = other_vertex
+                    .edge_costs
+                    .drain()
+                    .filter(|(neighbour_index, _)| *neighbour_index != index as u32)
+                    .map(|(neighbour_index, cost)| {
+                        if neighbour_index > index as u32 {
+                            (neighbour_index - 1, cost)
+                        } else {
+                            (neighbour_index, cost)
+                        }
+                    })
+                    .collect();
+            }
         }
 
         self.vertices.remove(index)
     }
 
+    /// Removes vertices at the given indices from the graph. The result is the same as calling
+    /// [`Self::remove_vertex`] for every index (in descending order), but references to the deleted
+    /// vertices are cleaned and neighbour indices are shifted in a single pass over the graph, which
+    /// is much faster when a lot of vertices must be removed at once. Duplicate indices are allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds.
+    pub fn remove_vertices(&mut self, indices: &[usize]) {
+        self.spatial_index = None;
+
+        let count = self.vertices.len();
+
+        let mut removed = vec![false; count];
+        for index in indices {
+            assert!(
+                *index < count,
+                "vertex index {index} is out of bounds (graph has {count} vertices)"
+            );
+            removed[*index] = true;
+        }
+
+        // Maps old vertex indices to new ones, removed vertices are mapped to `u32::MAX`.
+        let mut new_indices = vec![u32::MAX; count];
+        let mut next_index = 0;
+        for (old_index, is_removed) in removed.iter().enumerate() {
+            if !is_removed {
+                new_indices[old_index] = next_index;
+                next_index += 1;
+            }
+        }
+
+        let remap = |index: u32| -> Option<u32> {
+            match new_indices.get(index as usize) {
+                Some(new_index) if *new_index != u32::MAX => Some(*new_index),
+                // Removed vertex, the reference must be deleted.
+                Some(_) => None,
+                // Keep invalid references as is, but shift them the same way as valid ones.
+                None => Some(index - (count as u32 - next_index)),
+            }
+        };
+
+        let mut old_index = 0;
+        self.vertices.retain(|_| {
+            let keep = !removed[old_index];
+            old_index += 1;
+            keep
+        });
+
+        for vertex in self.vertices.iter_mut() {
+            vertex
+                .neighbours
+                .retain_mut(|neighbour_index| match remap(*neighbour_index) {
+                    Some(new_index) => {
+                        *neighbour_index = new_index;
+                        true
+                    }
+                    None => false,
+                });
+
+            if !vertex.edge_costs.is_empty() {
+                vertex.edge_costs = vertex
+                    .edge_costs
+                    .drain()
+                    .filter_map(|(neighbour_index, cost)| {
+                        remap(neighbour_index).map(|new_index| (new_index, cost))
+                    })
+                    .collect();
+            }
+        }
+    }
+
     /// Inserts the vertex at the given index. Automatically shifts neighbour indices of every other vertex
     /// in the graph to preserve graph structure.
     pub fn insert_vertex(&mut self, index: u32, vertex: T) {
         self.vertices.insert(index as usize, vertex);
+        self.spatial_index = None;
 
         // Shift neighbour indices to preserve vertex indexation.
         for other_vertex in self.vertices.iter_mut() {
@@ -413,6 +877,20 @@ impl<T: VertexDataProvider> Graph<T> {
                     *neighbour_index += 1;
                 }
             }
+
+            if !other_vertex.edge_costs.is_empty() {
+                other_vertex.edge_costs = other_vertex
+                    .edge_costs
+                    .drain()
+                    .map(|(neighbour_index, cost)| {
+                        if neighbour_index >= index {
+                            (neighbour_index + 1, cost)
+                        } else {
+                            (neighbour_index, cost)
+                        }
+                    })
+                    .collect();
+            }
         }
     }
 
@@ -425,7 +903,8 @@ impl<T: VertexDataProvider> Graph<T> {
     ///
     /// # Notes
     ///
-    /// This implementation is fast and allows for multiple searches in parallel, but does not attempt to find the optimal route
+    /// This implementation is fast and allows for multiple searches in parallel. The resulting path is optimal, unless
+    /// some links are cheaper than the distance between their vertices (see [`euclidean_heuristic`]).
     ///
     /// **See `Graph<T>.max_search_iterations`** to change the maximum amount of search iterations
     pub fn build_indexed_path(
@@ -434,6 +913,127 @@ impl<T: VertexDataProvider> Graph<T> {
         to: usize,
         path: &mut Vec<usize>,
     ) -> Result<PathKind, PathError> {
+        self.build_indexed_path_internal(from, to, euclidean_heuristic, self.search_budget(), path)
+            .map(|(kind, _)| kind)
+    }
+
+    /// The same as [`Self::build_indexed_path`], but stores the indices of the path vertices in the
+    /// order of travel - the first index is `from` and the last one is `to` (or the closest reachable
+    /// vertex in case of partial path). It could be useful when the path needs to be mapped back to
+    /// some other data associated with the vertices (for example, to navmesh triangles).
+    pub fn build_indices(
+        &self,
+        from: usize,
+        to: usize,
+        out: &mut Vec<usize>,
+    ) -> Result<PathKind, PathError> {
+        let result = self.build_indexed_path(from, to, out);
+        out.reverse();
+        result
+    }
+
+    /// The same as [`Self::build_indexed_path`], but also returns the total cost of the resulting path.
+    /// The cost is a sum of travel costs between adjacent vertices of the path (see
+    /// [`VertexData::travel_cost`]). It could be used to compare multiple
+    /// candidate paths, for example to pick the cheapest reachable objective. In case of partial path,
+    /// the cost of the partial path is returned.
+    pub fn build_with_cost(
+        &self,
+        from: usize,
+        to: usize,
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, f32), PathError> {
+        self.build_indexed_path_internal(from, to, euclidean_heuristic, self.search_budget(), path)
+    }
+
+    /// The same as [`Self::build_indexed_path`], but also returns the index of the vertex at which the
+    /// path actually ends and the straight-line distance from it to the vertex `to`. In case of full
+    /// path the end vertex is `to` and the distance is zero. In case of partial path, it could be used
+    /// to decide whether the path ends "close enough" to the desired destination.
+    ///
+    /// Unlike [`Self::build_indexed_path`], it does not return [`PathError::HitMaxSearchIterations`]
+    /// when the search budget (see [`Self::max_search_iterations`]) is exhausted. Instead, the best
+    /// path found so far is returned as a partial path along with its endpoint.
+    pub fn build_with_endpoint(
+        &self,
+        from: usize,
+        to: usize,
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, Option<usize>, f32), PathError> {
+        let kind = match self.build_indexed_path_internal(
+            from,
+            to,
+            euclidean_heuristic,
+            self.search_budget(),
+            path,
+        ) {
+            Ok((kind, _)) => kind,
+            // The best path found so far is still stored in the path vector.
+            Err(PathError::HitMaxSearchIterations(_)) => PathKind::Partial,
+            Err(err) => return Err(err),
+        };
+
+        // The path is stored in reverse order, so its first index is the end of the path.
+        let end = path.first().copied();
+        let distance = end
+            .and_then(|end| self.vertices.get(end))
+            .map(|end_vertex| {
+                end_vertex
+                    .position
+                    .metric_distance(&self.vertices[to].position)
+            })
+            .unwrap_or(f32::INFINITY);
+
+        Ok((kind, end, distance))
+    }
+
+    /// The same as [`Self::build_indexed_path`], but uses the given heuristic function instead of the
+    /// default [`euclidean_heuristic`]. The heuristic takes a position of a vertex and a position of
+    /// the goal and must return an estimated cost of travel between them. To get optimal paths,
+    /// the heuristic must not overestimate the actual cost (it must be admissible), for example the
+    /// Manhattan distance could be used for grid maps without diagonal links.
+    pub fn build_with_heuristic<H>(
+        &self,
+        from: usize,
+        to: usize,
+        heuristic: H,
+        path: &mut Vec<usize>,
+    ) -> Result<PathKind, PathError>
+    where
+        H: Fn(Vector3<f32>, Vector3<f32>) -> f32,
+    {
+        self.build_indexed_path_internal(from, to, heuristic, self.search_budget(), path)
+            .map(|(kind, _)| kind)
+    }
+
+    /// The same as [`Self::build_indexed_path`], but uses the given maximum amount of search iterations
+    /// instead of [`Self::max_search_iterations`]. It could be useful to raise the search budget for
+    /// a particular search on a huge graph, or to lower it for a cheap "best effort" search.
+    ///
+    /// Returns [`PathError::HitMaxSearchIterations`] if the budget was exhausted before the goal was
+    /// reached, in this case the path still contains the best partial path found so far.
+    pub fn build_with_budget(
+        &self,
+        from: usize,
+        to: usize,
+        max_iterations: usize,
+        path: &mut Vec<usize>,
+    ) -> Result<PathKind, PathError> {
+        self.build_indexed_path_internal(from, to, euclidean_heuristic, Some(max_iterations), path)
+            .map(|(kind, _)| kind)
+    }
+
+    fn build_indexed_path_internal<H>(
+        &self,
+        from: usize,
+        to: usize,
+        heuristic: H,
+        budget: Option<usize>,
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, f32), PathError>
+    where
+        H: Fn(Vector3<f32>, Vector3<f32>) -> f32,
+    {
         path.clear();
 
         if self.vertices.is_empty() {
@@ -446,10 +1046,170 @@ impl<T: VertexDataProvider> Graph<T> {
             .ok_or(PathError::InvalidIndex(to))?
             .position;
 
+        self.build_until_internal(
+            from,
+            |index, _| index == to,
+            |vertex| heuristic(vertex.position, end_pos),
+            budget,
+            path,
+        )
+    }
+
+    /// Tries to build path of vertex indices from beginning point to the nearest (by path cost) vertex
+    /// from the given set of `goals`. It runs a single search with the heuristic taken as the minimum
+    /// over all goals, which is much cheaper than running a separate search for every goal. Duplicate
+    /// goals are allowed. Returns path kind and the index of the reached goal vertex:
+    ///
+    /// - Full: Path vector is a direct path from beginning to the returned goal.
+    /// - Partial: Path vector is a path that ends closest to the goals, because pathfinder could not
+    ///   find a full path to any of them. The returned goal is the one closest (by straight-line
+    ///   distance) to the end of the partial path.
+    ///
+    /// *See `PathKind`*
+    ///
+    /// # Notes
+    ///
+    /// The path is stored in reverse order - the first index is the goal and the last index is the
+    /// beginning point. Returns [`PathError::Empty`] if `goals` slice is empty.
+    ///
+    /// **See `Graph<T>.max_search_iterations`** to change the maximum amount of search iterations
+    pub fn build_to_nearest(
+        &self,
+        from: usize,
+        goals: &[usize],
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, usize), PathError> {
+        path.clear();
+
+        if goals.is_empty() {
+            return Err(PathError::Empty);
+        }
+
+        let goal_positions = goals
+            .iter()
+            .map(|goal| {
+                self.vertices
+                    .get(*goal)
+                    .map(|vertex| vertex.position)
+                    .ok_or(PathError::InvalidIndex(*goal))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let distance_to_goals = |position: Vector3<f32>| {
+            goal_positions
+                .iter()
+                .map(|goal_position| euclidean_heuristic(position, *goal_position))
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let (kind, _) = self.build_until_internal(
+            from,
+            |index, _| goals.contains(&index),
+            |vertex| distance_to_goals(vertex.position),
+            self.search_budget(),
+            path,
+        )?;
+
+        // The path is stored in reverse order, so its first index is the end of the path.
+        let end = path.first().copied().unwrap_or(from);
+        let reached_goal = if kind == PathKind::Full {
+            end
+        } else {
+            let end_position = self.vertices[end].position;
+            goals
+                .iter()
+                .zip(goal_positions.iter())
+                .map(|(goal, goal_position)| {
+                    (*goal, euclidean_heuristic(end_position, *goal_position))
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(goal, _)| goal)
+                .unwrap_or(goals[0])
+        };
+
+        Ok((kind, reached_goal))
+    }
+
+    /// Tries to build path of vertex indices from beginning point to the first vertex that satisfies
+    /// the given `goal` predicate. It could be used when the goal is a condition rather than a specific
+    /// vertex, for example "reach any vertex in a region" or "reach any vertex within a radius of a
+    /// point". The `heuristic` is used to estimate the remaining cost from a vertex to the (caller-known)
+    /// goal region, it must return zero (or close to zero) for vertices that satisfy the goal. Returns
+    /// path kind:
+    ///
+    /// - Full: Path vector is a direct path from beginning to a vertex that satisfies the goal.
+    /// - Partial: Path vector is a path that ends at the vertex with the best score, because pathfinder
+    ///   could not find a vertex that satisfies the goal.
+    ///
+    /// *See `PathKind`*
+    ///
+    /// # Notes
+    ///
+    /// The path is stored in reverse order - the first index is the goal and the last index is the
+    /// beginning point.
+    ///
+    /// **See `Graph<T>.max_search_iterations`** to change the maximum amount of search iterations
+    pub fn build_until<G, H>(
+        &self,
+        from: usize,
+        goal: G,
+        heuristic: H,
+        path: &mut Vec<usize>,
+    ) -> Result<PathKind, PathError>
+    where
+        G: Fn(usize, &T) -> bool,
+        H: Fn(&T) -> f32,
+    {
+        self.build_until_internal(from, goal, heuristic, self.search_budget(), path)
+            .map(|(kind, _)| kind)
+    }
+
+    fn build_until_internal<G, H>(
+        &self,
+        from: usize,
+        goal: G,
+        heuristic: H,
+        budget: Option<usize>,
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, f32), PathError>
+    where
+        G: Fn(usize, &T) -> bool,
+        H: Fn(&T) -> f32,
+    {
+        self.build_until_filtered(from, goal, heuristic, |_, _| true, budget, path)
+    }
+
+    /// The same as [`Self::build_until_internal`], but follows only the links `a -> b` for which
+    /// `is_link_allowed(a, b)` returns `true`.
+    fn build_until_filtered<G, H, L>(
+        &self,
+        from: usize,
+        goal: G,
+        heuristic: H,
+        is_link_allowed: L,
+        budget: Option<usize>,
+        path: &mut Vec<usize>,
+    ) -> Result<(PathKind, f32), PathError>
+    where
+        G: Fn(usize, &T) -> bool,
+        H: Fn(&T) -> f32,
+        L: Fn(usize, usize) -> bool,
+    {
+        path.clear();
+
+        if self.vertices.is_empty() {
+            return Err(PathError::Empty);
+        }
+
+        let start_vertex = self
+            .vertices
+            .get(from)
+            .ok_or(PathError::InvalidIndex(from))?;
+
         // returns one point if the goal is the current postion
-        if from == to {
-            path.push(to);
-            return Ok(PathKind::Full);
+        if goal(from, start_vertex) {
+            path.push(from);
+            return Ok((PathKind::Full, 0.0));
         }
 
         // keeps track of which vertices we've searched
@@ -465,31 +1225,40 @@ impl<T: VertexDataProvider> Graph<T> {
         let mut best_path = PartialPath::default();
 
         // search loop
-        let mut search_iteration = 0i32;
-
-        while self.max_search_iterations < 0 || search_iteration < self.max_search_iterations {
-            // breakes loop if heap is empty
-            if search_heap.is_empty() {
-                break;
-            }
+        let mut search_iteration = 0usize;
 
-            // pops best partial path off the heap to use for this iteration
-            let current_path = search_heap.pop().unwrap();
+        let mut goal_reached = false;
+        let mut budget_exhausted = false;
 
+        // pops best partial path off the heap to use for this iteration
+        while let Some(current_path) = search_heap.pop() {
             let current_index = *current_path.vertices.last().unwrap();
             let current_vertex = self
                 .vertices
                 .get(current_index)
                 .ok_or(PathError::InvalidIndex(current_index))?;
 
+            // the vertex was already reached by a better path
+            if searched_vertices[current_index] {
+                continue;
+            }
+
+            // breaks if end is found
+            if goal(current_index, current_vertex) {
+                best_path = current_path;
+                goal_reached = true;
+                break;
+            }
+
+            // breaks if the search budget is exhausted
+            if budget.is_some_and(|budget| search_iteration >= budget) {
+                budget_exhausted = true;
+                break;
+            }
+
             // updates best path
             if current_path > best_path {
                 best_path = current_path.clone();
-
-                // breaks if end is found
-                if current_index == to {
-                    break;
-                }
             }
 
             // evaluates path scores one level deeper and adds the paths to the heap
@@ -503,7 +1272,12 @@ impl<T: VertexDataProvider> Graph<T> {
                 }
 
                 // avoids going in circles
-                if searched_vertices[neighbour_index] {
+                if searched_vertices
+                    .get(neighbour_index)
+                    .copied()
+                    .unwrap_or_default()
+                    || !is_link_allowed(current_index, neighbour_index)
+                {
                     continue;
                 }
 
@@ -512,11 +1286,10 @@ impl<T: VertexDataProvider> Graph<T> {
                     .get(neighbour_index)
                     .ok_or(PathError::InvalidIndex(neighbour_index))?;
 
-                let neighbour_g_score = current_path.g_score
-                    + ((current_vertex.position - neighbour.position).norm_squared()
-                        * neighbour.g_penalty);
+                let neighbour_g_score =
+                    current_path.g_score + current_vertex.travel_cost(*i, neighbour);
 
-                let neighbour_f_score = neighbour_g_score + heuristic(neighbour.position, end_pos);
+                let neighbour_f_score = neighbour_g_score + heuristic(neighbour);
 
                 search_heap.push(current_path.clone_and_add(
                     neighbour_index,
@@ -535,14 +1308,14 @@ impl<T: VertexDataProvider> Graph<T> {
         path.clone_from(&best_path.vertices);
         path.reverse();
 
-        if *path.first().unwrap() == to {
-            Ok(PathKind::Full)
-        } else if search_iteration == self.max_search_iterations - 1 {
+        if goal_reached {
+            Ok((PathKind::Full, best_path.g_score))
+        } else if budget_exhausted {
             Err(PathError::HitMaxSearchIterations(
-                self.max_search_iterations,
+                search_iteration.min(i32::MAX as usize) as i32,
             ))
         } else {
-            Ok(PathKind::Partial)
+            Ok((PathKind::Partial, best_path.g_score))
         }
     }
 
@@ -555,7 +1328,8 @@ impl<T: VertexDataProvider> Graph<T> {
     ///
     /// # Notes
     ///
-    /// This implementation is fast and allows for multiple searches in parallel, but does not attempt to find the optimal route
+    /// This implementation is fast and allows for multiple searches in parallel. The resulting path is optimal, unless
+    /// some links are cheaper than the distance between their vertices (see [`euclidean_heuristic`]).
     ///
     /// **See `Graph<T>.max_search_iterations`** to change the maximum amount of search iterations
     pub fn build_positional_path(
@@ -569,17 +1343,528 @@ impl<T: VertexDataProvider> Graph<T> {
         let mut indices: Vec<usize> = Vec::new();
         let path_kind = self.build_indexed_path(from, to, &mut indices)?;
 
-        // converts from indicies to positions
-        for index in indices.iter() {
-            let vertex = self
-                .vertices
-                .get(*index)
+        self.indices_to_positions(&indices, path)?;
+
+        Ok(path_kind)
+    }
+
+    /// The same as [`Self::build_positional_path`], but runs weighted A* - the heuristic term of the
+    /// f-score is multiplied by the given `epsilon`. With `epsilon == 1.0` the result is the same as
+    /// the result of [`Self::build_positional_path`].
+    ///
+    /// # Notes
+    ///
+    /// `epsilon > 1.0` trades optimality for speed: the search is more "greedy" and usually expands far
+    /// fewer vertices, but the resulting path could be up to `epsilon` times more expensive than the
+    /// optimal one. It could be useful for large maps, where slightly longer paths are acceptable.
+    /// Values less than one make the search slower without any benefits.
+    pub fn build_weighted(
+        &self,
+        from: usize,
+        to: usize,
+        epsilon: f32,
+        path: &mut Vec<Vector3<f32>>,
+    ) -> Result<PathKind, PathError> {
+        path.clear();
+
+        let mut indices: Vec<usize> = Vec::new();
+        let (path_kind, _) = self.build_indexed_path_internal(
+            from,
+            to,
+            |a, b| epsilon * euclidean_heuristic(a, b),
+            self.search_budget(),
+            &mut indices,
+        )?;
+
+        self.indices_to_positions(&indices, path)?;
+
+        Ok(path_kind)
+    }
+
+    fn indices_to_positions(
+        &self,
+        indices: &[usize],
+        path: &mut Vec<Vector3<f32>>,
+    ) -> Result<(), PathError> {
+        // converts from indicies to positions
+        for index in indices.iter() {
+            let vertex = self
+                .vertices
+                .get(*index)
                 .ok_or(PathError::InvalidIndex(*index))?;
 
             path.push(vertex.position);
         }
 
-        Ok(path_kind)
+        Ok(())
+    }
+
+    /// Tries to build up to `k` distinct paths of Vector3's from beginning point to endpoint, sorted by
+    /// their cost in increasing order. It could be used when alternative routes are needed, for example
+    /// to spread a squad of units over multiple paths. Only paths without loops are considered. If
+    /// there are fewer than `k` such paths, all of them are returned. If `from` is equal to `to`, the
+    /// only path is the one that contains a single point.
+    ///
+    /// # Notes
+    ///
+    /// Each path is stored in the same order as in [`Self::build_positional_path`] - the first point is
+    /// the endpoint and the last point is the beginning point.
+    ///
+    /// The paths are found using Yen's algorithm, which runs a separate A* search for every vertex of
+    /// every found path, so it is much slower than a single search. Each of the searches is limited by
+    /// [`Self::max_search_iterations`]: the error is returned if the shortest path cannot be found
+    /// within the limit, alternative paths that cannot be found within the limit are skipped.
+    pub fn build_k_shortest(
+        &self,
+        from: usize,
+        to: usize,
+        k: usize,
+    ) -> Result<Vec<Vec<Vector3<f32>>>, PathError> {
+        if self.vertices.is_empty() {
+            return Err(PathError::Empty);
+        }
+
+        let start_position = self
+            .vertices
+            .get(from)
+            .ok_or(PathError::InvalidIndex(from))?
+            .position;
+        let end_position = self
+            .vertices
+            .get(to)
+            .ok_or(PathError::InvalidIndex(to))?
+            .position;
+
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        if from == to {
+            return Ok(vec![vec![start_position]]);
+        }
+
+        let heuristic = |vertex: &T| euclidean_heuristic(vertex.position, end_position);
+        let budget = self.search_budget();
+
+        // Found paths (in the order of travel) along with their costs.
+        let mut paths: Vec<(Vec<usize>, f32)> = Vec::new();
+        let mut candidates: Vec<(Vec<usize>, f32)> = Vec::new();
+
+        let mut shortest = Vec::new();
+        let (kind, cost) = self.build_until_internal(
+            from,
+            |index, _| index == to,
+            heuristic,
+            budget,
+            &mut shortest,
+        )?;
+        if kind == PathKind::Full {
+            shortest.reverse();
+            paths.push((shortest, cost));
+        }
+
+        while let Some((previous, _)) = paths.last().filter(|_| paths.len() < k) {
+            // Every vertex of the previous path (except the last one) is a "spur" vertex, the path to
+            // the spur vertex is kept and the rest of the path is replaced with the shortest path,
+            // that deviates from all the found paths with the same beginning.
+            for spur_position in 0..previous.len() - 1 {
+                let root = &previous[..=spur_position];
+                let spur_index = previous[spur_position];
+
+                let removed_links = paths
+                    .iter()
+                    .filter(|(path, _)| path.len() > spur_position + 1 && path.starts_with(root))
+                    .map(|(path, _)| path[spur_position + 1])
+                    .collect::<Vec<_>>();
+                let removed_vertices = &root[..spur_position];
+
+                let mut spur_path = Vec::new();
+                let Ok((PathKind::Full, spur_cost)) = self.build_until_filtered(
+                    spur_index,
+                    |index, _| index == to,
+                    heuristic,
+                    |a, b| {
+                        !(removed_vertices.contains(&b)
+                            || a == spur_index && removed_links.contains(&b))
+                    },
+                    budget,
+                    &mut spur_path,
+                ) else {
+                    continue;
+                };
+
+                let mut candidate = root[..spur_position].to_vec();
+                candidate.extend(spur_path.iter().rev());
+
+                if candidates
+                    .iter()
+                    .chain(paths.iter())
+                    .any(|(path, _)| *path == candidate)
+                {
+                    continue;
+                }
+
+                let candidate_cost = self.path_cost(root) + spur_cost;
+                candidates.push((candidate, candidate_cost));
+            }
+
+            let Some(best) = candidates
+                .iter()
+                .enumerate()
+                .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+                .map(|(i, _)| i)
+            else {
+                break;
+            };
+            paths.push(candidates.remove(best));
+        }
+
+        Ok(paths
+            .into_iter()
+            .map(|(path, _)| {
+                path.iter()
+                    .rev()
+                    .map(|index| self.vertices[*index].position)
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Returns the total travel cost of the given path of vertex indices (in the order of travel).
+    fn path_cost(&self, path: &[usize]) -> f32 {
+        path.windows(2)
+            .map(|pair| self.vertices[pair[0]].travel_cost(pair[1] as u32, &self.vertices[pair[1]]))
+            .sum()
+    }
+
+    /// Checks whether the vertex `to` can be reached from the vertex `from` by following the links
+    /// of the graph. It performs plain breadth-first search over directed links and stops as soon as
+    /// the vertex `to` is reached, which is much cheaper than building a path. Returns `false` if any
+    /// of the indices is out of bounds.
+    pub fn can_reach(&self, from: usize, to: usize) -> bool {
+        if from >= self.vertices.len() || to >= self.vertices.len() {
+            return false;
+        }
+
+        if from == to {
+            return true;
+        }
+
+        let mut visited = vec![false; self.vertices.len()];
+        let mut queue = VecDeque::new();
+
+        visited[from] = true;
+        queue.push_back(from);
+
+        while let Some(index) = queue.pop_front() {
+            for neighbour_index in self.vertices[index].neighbours.iter() {
+                let neighbour_index = *neighbour_index as usize;
+
+                if neighbour_index == to {
+                    return true;
+                }
+
+                if let Some(visited) = visited.get_mut(neighbour_index) {
+                    if !*visited {
+                        *visited = true;
+                        queue.push_back(neighbour_index);
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Splits the graph into connected components, treating every link as undirected. Two vertices are
+    /// in the same component if there is a chain of links (in any direction) between them. The result
+    /// could be computed once and then used to cheaply reject path queries between isolated "islands"
+    /// of the graph, instead of running a full search that ends with [`PathKind::Partial`].
+    ///
+    /// Returns a list of components, every component is a list of vertex indices in ascending order.
+    /// Components are sorted by their smallest vertex index. Invalid neighbour indices are ignored.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        fn find(parents: &mut [usize], mut index: usize) -> usize {
+            while parents[index] != index {
+                parents[index] = parents[parents[index]];
+                index = parents[index];
+            }
+            index
+        }
+
+        let mut parents = (0..self.vertices.len()).collect::<Vec<_>>();
+
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            for neighbour_index in vertex.neighbours.iter() {
+                let neighbour_index = *neighbour_index as usize;
+                if neighbour_index < self.vertices.len() {
+                    let a = find(&mut parents, index);
+                    let b = find(&mut parents, neighbour_index);
+                    if a != b {
+                        parents[a.max(b)] = a.min(b);
+                    }
+                }
+            }
+        }
+
+        let mut component_indices = vec![usize::MAX; self.vertices.len()];
+        let mut components = Vec::<Vec<usize>>::new();
+        for index in 0..self.vertices.len() {
+            let root = find(&mut parents, index);
+            if component_indices[root] == usize::MAX {
+                component_indices[root] = components.len();
+                components.push(Vec::new());
+            }
+            components[component_indices[root]].push(index);
+        }
+
+        components
+    }
+
+    /// The same as [`Self::components`], but respects direction of the links - two vertices are in the
+    /// same (strongly connected) component only if each of them can be reached from the other one.
+    /// Use this method when the graph has unidirectional links (see [`Self::link_unidirect`]).
+    ///
+    /// Returns a list of components, every component is a list of vertex indices in ascending order.
+    /// Components are sorted by their smallest vertex index. Invalid neighbour indices are ignored.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let count = self.vertices.len();
+
+        let mut reversed_links = vec![Vec::new(); count];
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            for neighbour_index in vertex.neighbours.iter() {
+                let neighbour_index = *neighbour_index as usize;
+                if neighbour_index < count {
+                    reversed_links[neighbour_index].push(index);
+                }
+            }
+        }
+
+        // Kosaraju's algorithm. First pass - collect vertices in the order of DFS completion.
+        let mut visited = vec![false; count];
+        let mut order = Vec::with_capacity(count);
+        let mut stack = Vec::new();
+        for root in 0..count {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            stack.push((root, 0));
+            while let Some((index, next_neighbour)) = stack.last_mut() {
+                let index = *index;
+                match self.vertices[index].neighbours.get(*next_neighbour) {
+                    Some(neighbour_index) => {
+                        *next_neighbour += 1;
+                        let neighbour_index = *neighbour_index as usize;
+                        if neighbour_index < count && !visited[neighbour_index] {
+                            visited[neighbour_index] = true;
+                            stack.push((neighbour_index, 0));
+                        }
+                    }
+                    None => {
+                        order.push(index);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        // Second pass - walk the reversed graph in reverse completion order.
+        let mut assigned = vec![false; count];
+        let mut components = Vec::new();
+        let mut queue = Vec::new();
+        for root in order.into_iter().rev() {
+            if assigned[root] {
+                continue;
+            }
+            assigned[root] = true;
+            queue.push(root);
+            let mut component = Vec::new();
+            while let Some(index) = queue.pop() {
+                component.push(index);
+                for other in reversed_links[index].iter() {
+                    if !assigned[*other] {
+                        assigned[*other] = true;
+                        queue.push(*other);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components.sort_unstable_by_key(|component| component[0]);
+        components
+    }
+
+    /// Produces a textual representation of the graph in [Graphviz DOT](https://graphviz.org/doc/info/lang.html)
+    /// format, which could be useful for debugging. Every vertex is labeled using the given closure.
+    /// Bidirectional links are written as a single edge with `dir=both` attribute, unidirectional
+    /// links are written as plain directed edges.
+    pub fn to_dot<F>(&self, label: F) -> String
+    where
+        F: Fn(usize, &T) -> String,
+    {
+        let mut dot = String::from("digraph {\n");
+
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            let _ = writeln!(
+                dot,
+                "    {index} [label=\"{}\"];",
+                label(index, vertex).replace('"', "\\\"")
+            );
+        }
+
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            for neighbour_index in vertex.neighbours.iter() {
+                let neighbour_index = *neighbour_index as usize;
+
+                let bidirectional = neighbour_index != index
+                    && self
+                        .vertices
+                        .get(neighbour_index)
+                        .is_some_and(|neighbour| neighbour.neighbours.contains(&(index as u32)));
+
+                if bidirectional {
+                    // Write bidirectional links only once.
+                    if index < neighbour_index {
+                        let _ = writeln!(dot, "    {index} -> {neighbour_index} [dir=both];");
+                    }
+                } else {
+                    let _ = writeln!(dot, "    {index} -> {neighbour_index};");
+                }
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    /// Calculates costs of the shortest paths from every vertex in `sources` to every vertex of the
+    /// graph. The cost of a path is calculated the same way as in A* search - it is a sum of travel
+    /// costs between adjacent vertices (see [`VertexData::travel_cost`]).
+    /// Returns a matrix where `matrix[i][j]` is the cost of the shortest path from `sources[i]` to the
+    /// vertex `j`.
+    ///
+    /// # Notes
+    ///
+    /// Unreachable pairs (as well as invalid source indices) get [`f32::INFINITY`] cost. The method
+    /// runs Dijkstra's search from every source, so its complexity is `O(S · (E + V log V))`, where `S`
+    /// is the amount of sources, `E` - the amount of links and `V` - the amount of vertices. It is meant
+    /// to be used for small graphs or a small set of "points of interest".
+    pub fn distance_matrix(&self, sources: &[usize]) -> Vec<Vec<f32>> {
+        sources
+            .iter()
+            .map(|source| self.dijkstra(*source, None, f32::INFINITY))
+            .collect()
+    }
+
+    /// The same as [`Self::distance_matrix`], but calculates costs only to the given set of `targets`.
+    /// Returns a matrix where `matrix[i][j]` is the cost of the shortest path from `sources[i]` to
+    /// `targets[j]`. The search from a source stops as soon as all targets are reached, which makes it
+    /// faster than [`Self::distance_matrix`] when the targets are close to the sources.
+    pub fn distance_matrix_to(&self, sources: &[usize], targets: &[usize]) -> Vec<Vec<f32>> {
+        sources
+            .iter()
+            .map(|source| {
+                let costs = self.dijkstra(*source, Some(targets), f32::INFINITY);
+                targets
+                    .iter()
+                    .map(|target| costs.get(*target).copied().unwrap_or(f32::INFINITY))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Finds every vertex that can be reached from the vertex `from` with the total travel cost not
+    /// exceeding `max_cost`. The cost is calculated the same way as in A* search (see
+    /// [`VertexData::travel_cost`]). Returns pairs of vertex index and the cost of the cheapest path
+    /// to it, sorted by the cost in ascending order. The beginning point is always included with zero
+    /// cost. It could be used to build influence maps or to find all objectives in a certain range.
+    pub fn reachable_within(
+        &self,
+        from: usize,
+        max_cost: f32,
+    ) -> Result<Vec<(usize, f32)>, PathError> {
+        if self.vertices.is_empty() {
+            return Err(PathError::Empty);
+        }
+
+        if from >= self.vertices.len() {
+            return Err(PathError::InvalidIndex(from));
+        }
+
+        let mut reachable = self
+            .dijkstra(from, None, max_cost)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, cost)| *index == from || cost.is_finite() && *cost <= max_cost)
+            .collect::<Vec<_>>();
+
+        reachable.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        Ok(reachable)
+    }
+
+    /// Runs Dijkstra's search from the given source and returns costs to every vertex of the graph.
+    /// If `targets` is specified, the search stops as soon as all of them are reached. Vertices with
+    /// the cost above `max_cost` are not expanded and get [`f32::INFINITY`] cost.
+    fn dijkstra(&self, source: usize, targets: Option<&[usize]>, max_cost: f32) -> Vec<f32> {
+        let mut costs = vec![f32::INFINITY; self.vertices.len()];
+
+        if source >= self.vertices.len() {
+            return costs;
+        }
+
+        let mut remaining_targets = targets.map(|targets| {
+            targets
+                .iter()
+                .filter(|target| **target < self.vertices.len())
+                .collect::<Vec<_>>()
+        });
+
+        let mut visited = vec![false; self.vertices.len()];
+        let mut heap = BinaryHeap::new();
+
+        costs[source] = 0.0;
+        heap.push(DijkstraEntry {
+            index: source,
+            cost: 0.0,
+        });
+
+        while let Some(DijkstraEntry { index, cost }) = heap.pop() {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+
+            if let Some(remaining_targets) = remaining_targets.as_mut() {
+                remaining_targets.retain(|target| **target != index);
+                if remaining_targets.is_empty() {
+                    break;
+                }
+            }
+
+            let vertex = &self.vertices[index];
+            for link in vertex.neighbours.iter() {
+                let neighbour_index = *link as usize;
+                let Some(neighbour) = self.vertices.get(neighbour_index) else {
+                    continue;
+                };
+
+                let neighbour_cost = cost + vertex.travel_cost(*link, neighbour);
+
+                if neighbour_cost <= max_cost && neighbour_cost < costs[neighbour_index] {
+                    costs[neighbour_index] = neighbour_cost;
+                    heap.push(DijkstraEntry {
+                        index: neighbour_index,
+                        cost: neighbour_cost,
+                    });
+                }
+            }
+        }
+
+        costs
     }
 
     /// **Deprecated** *use **`Graph<T>.build_positional_path()`** instead*
@@ -593,7 +1878,8 @@ impl<T: VertexDataProvider> Graph<T> {
     ///
     /// # Notes
     ///
-    /// This implementation is fast and allows for multiple searches in parallel, but does not attempt to find the optimal route
+    /// This implementation is fast and allows for multiple searches in parallel. The resulting path is optimal, unless
+    /// some links are cheaper than the distance between their vertices (see [`euclidean_heuristic`]).
     ///
     /// **See `Graph<T>.max_search_iterations`** to change the maximum amount of search iterations
     #[deprecated = "name is too ambiguous use build_positional_path instead"]
@@ -612,8 +1898,12 @@ mod test {
     use crate::rand::Rng;
     use crate::utils::astar::PathError;
     use crate::{
-        core::{algebra::Vector3, rand},
-        utils::astar::{Graph, GraphVertex, PathKind},
+        core::{
+            algebra::Vector3,
+            rand,
+            visitor::{Visit, Visitor},
+        },
+        utils::astar::{smooth_path, Graph, GraphVertex, PathKind},
     };
     use std::time::Instant;
 
@@ -697,6 +1987,288 @@ mod test {
         assert!(paths_count > 0);
     }
 
+    #[test]
+    fn test_build_weighted() {
+        // The same layout as in the backwards travel benchmark, but smaller.
+        let size = 20;
+        let mut pathfinder = Graph::new();
+        pathfinder.set_vertices(make_grid_vertices(size, size));
+
+        for y in 0..(size - 1) {
+            for x in (0..(size - 1)).rev() {
+                if y == 0 || x != y {
+                    pathfinder.link_bidirect(y * size + x, y * size + x + 1);
+                    pathfinder.link_bidirect(y * size + x, (y + 1) * size + x);
+                }
+            }
+        }
+
+        let from = (size / 2) * size + (size - 1);
+        let to = (size - 1) * size + (size / 2);
+
+        let mut expected = Vec::new();
+        let mut path = Vec::new();
+        assert!(matches!(
+            pathfinder.build_positional_path(from, to, &mut expected),
+            Ok(PathKind::Full)
+        ));
+        assert!(matches!(
+            pathfinder.build_weighted(from, to, 1.0, &mut path),
+            Ok(PathKind::Full)
+        ));
+        assert_eq!(path, expected);
+
+        // The smallest search budget, that is enough to find a full path, is the number of the
+        // expanded vertices.
+        let mut expansions = |epsilon: f32| {
+            (1..=size * size)
+                .find(|budget| {
+                    pathfinder.max_search_iterations = *budget as i32;
+                    matches!(
+                        pathfinder.build_weighted(from, to, epsilon, &mut path),
+                        Ok(PathKind::Full)
+                    )
+                })
+                .unwrap()
+        };
+
+        let optimal_expansions = expansions(1.0);
+        let weighted_expansions = expansions(2.0);
+        assert!(weighted_expansions < optimal_expansions);
+        assert_eq!(*path.first().unwrap(), Vector3::new(10.0, 19.0, 0.0));
+        assert_eq!(*path.last().unwrap(), Vector3::new(19.0, 10.0, 0.0));
+    }
+
+    #[test]
+    fn test_penalty_serialization() {
+        let mut graph = Graph::<GraphVertex>::new();
+        graph.set_vertices(vec![
+            GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)),
+            GraphVertex::new(Vector3::new(1.0, 0.0, 0.0)),
+        ]);
+        graph.link_bidirect(0, 1);
+        graph.vertex_mut(1).unwrap().g_penalty = 4.0;
+
+        let mut visitor = Visitor::new();
+        graph.visit("Graph", &mut visitor).unwrap();
+        let data = visitor.save_binary_to_vec().unwrap();
+
+        let mut loaded = Graph::<GraphVertex>::new();
+        let mut visitor = Visitor::load_from_memory(&data).unwrap();
+        loaded.visit("Graph", &mut visitor).unwrap();
+
+        assert_eq!(loaded.vertex(0).unwrap().g_penalty, 1.0);
+        assert_eq!(loaded.vertex(1).unwrap().g_penalty, 4.0);
+        assert_eq!(loaded.vertex(0).unwrap().neighbours, vec![1]);
+    }
+
+    #[test]
+    fn test_path_error_is_error() {
+        let error: Box<dyn std::error::Error> = Box::new(PathError::Empty);
+        assert_eq!(error.to_string(), "Graph was empty");
+    }
+
+    #[test]
+    fn test_build_with_endpoint() {
+        let size = 10;
+        // Two islands separated half way along the x-axis.
+        let mut pathfinder = make_grid(size, |x, _| x == (size / 2) - 1);
+
+        let mut path = Vec::new();
+
+        // Partial path ends at the divide.
+        let from = 5 * size + 1;
+        let to = 5 * size + 8;
+        let (kind, end, distance) = pathfinder.build_with_endpoint(from, to, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Partial);
+        let end = end.unwrap();
+        let end_position = pathfinder.vertex(end).unwrap().position;
+        assert_eq!(end_position.x as usize, (size / 2) - 1);
+        assert_eq!(
+            distance,
+            end_position.metric_distance(&pathfinder.vertex(to).unwrap().position)
+        );
+        assert!(distance > 0.0);
+
+        // Full path ends at the goal.
+        let to = 2 * size + 3;
+        let (kind, end, distance) = pathfinder.build_with_endpoint(from, to, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Full);
+        assert_eq!(end, Some(to));
+        assert_eq!(distance, 0.0);
+
+        // Exhausted search budget gives the best path found so far.
+        pathfinder.max_search_iterations = 3;
+        let (kind, end, distance) = pathfinder.build_with_endpoint(from, to, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Partial);
+        assert_eq!(path.last(), Some(&from));
+        let end = end.unwrap();
+        assert_ne!(end, to);
+        assert_eq!(
+            distance,
+            pathfinder
+                .vertex(end)
+                .unwrap()
+                .position
+                .metric_distance(&pathfinder.vertex(to).unwrap().position)
+        );
+        assert!(distance > 0.0);
+    }
+
+    #[test]
+    fn test_build_k_shortest() {
+        let mut pathfinder = Graph::new();
+
+        // A diamond with two routes of equal length from 0 to 3.
+        pathfinder.set_vertices(vec![
+            GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)),
+            GraphVertex::new(Vector3::new(1.0, 1.0, 0.0)),
+            GraphVertex::new(Vector3::new(1.0, -1.0, 0.0)),
+            GraphVertex::new(Vector3::new(2.0, 0.0, 0.0)),
+        ]);
+        pathfinder.link_from_adjacency(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+
+        let position = |index: usize| pathfinder.vertex(index).unwrap().position;
+
+        let paths = pathfinder.build_k_shortest(0, 3, 5).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_ne!(paths[0], paths[1]);
+        for path in paths.iter() {
+            assert_eq!(path.len(), 3);
+            assert_eq!(path[0], position(3));
+            assert_eq!(path[2], position(0));
+        }
+        let mut middle = paths.iter().map(|path| path[1]).collect::<Vec<_>>();
+        middle.sort_by(|a, b| a.y.total_cmp(&b.y));
+        assert_eq!(middle, vec![position(2), position(1)]);
+
+        assert_eq!(pathfinder.build_k_shortest(0, 3, 1).unwrap().len(), 1);
+        assert!(pathfinder.build_k_shortest(0, 3, 0).unwrap().is_empty());
+        assert_eq!(
+            pathfinder.build_k_shortest(1, 1, 3).unwrap(),
+            vec![vec![position(1)]]
+        );
+
+        // A longer route is returned after the shorter ones.
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 5.0, 0.0)));
+        pathfinder.link_from_adjacency(&[(0, 4), (4, 3)]);
+        let paths = pathfinder.build_k_shortest(0, 3, 3).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[2][1], pathfinder.vertex(4).unwrap().position);
+
+        // The search budget is respected.
+        pathfinder.max_search_iterations = 1;
+        assert!(matches!(
+            pathfinder.build_k_shortest(0, 3, 2),
+            Err(PathError::HitMaxSearchIterations(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_k_shortest_loop_pruned_prefixes() {
+        let mut pathfinder = Graph::new();
+        pathfinder.set_vertices(
+            (0..5)
+                .map(|i| GraphVertex::new(Vector3::new(i as f32 * 0.1, 0.0, 0.0)))
+                .collect(),
+        );
+        // Vertex 2 is reached twice by the prefixes going through vertex 3 first, they cannot be
+        // continued without a loop, but the path 0 -> 1 -> 2 -> 3 -> 4 must still be found.
+        for (a, b, cost) in [
+            (0, 1, 8.0),
+            (0, 3, 4.0),
+            (1, 2, 6.0),
+            (2, 3, 2.0),
+            (3, 1, 3.0),
+            (3, 2, 7.0),
+            (3, 4, 9.0),
+        ] {
+            pathfinder.link_unidirect_weighted(a, b, cost);
+        }
+
+        let positions = |indices: &[usize]| {
+            indices
+                .iter()
+                .rev()
+                .map(|index| pathfinder.vertex(*index).unwrap().position)
+                .collect::<Vec<_>>()
+        };
+
+        let expected = vec![positions(&[0, 3, 4]), positions(&[0, 1, 2, 3, 4])];
+        assert_eq!(pathfinder.build_k_shortest(0, 4, 2).unwrap(), expected);
+        // There are only two paths without loops.
+        assert_eq!(pathfinder.build_k_shortest(0, 4, 3).unwrap(), expected);
+    }
+
+    fn make_grid_vertices(width: usize, height: usize) -> Vec<GraphVertex> {
+        let mut vertices = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                vertices.push(GraphVertex::new(Vector3::new(x as f32, y as f32, 0.0)));
+            }
+        }
+        vertices
+    }
+
+    /// Builds a `size x size` grid where every vertex is linked to its four neighbours, except
+    /// for the horizontal links `(x, y) -> (x + 1, y)` for which `is_wall(x, y)` returns `true`.
+    fn make_grid(size: usize, is_wall: impl Fn(usize, usize) -> bool) -> Graph<GraphVertex> {
+        let mut pathfinder = Graph::new();
+        pathfinder.set_vertices(make_grid_vertices(size, size));
+        for y in 0..size {
+            for x in 0..size {
+                if x + 1 < size && !is_wall(x, y) {
+                    pathfinder.link_bidirect(y * size + x, y * size + x + 1);
+                }
+                if y + 1 < size {
+                    pathfinder.link_bidirect(y * size + x, (y + 1) * size + x);
+                }
+            }
+        }
+        pathfinder
+    }
+
+    #[test]
+    fn test_link_grid() {
+        let (width, height) = (5, 4);
+
+        let mut manual = Graph::<GraphVertex>::new();
+        manual.set_vertices(make_grid_vertices(width, height));
+        for y in 0..height {
+            for x in 0..width {
+                if x + 1 < width {
+                    manual.link_bidirect(y * width + x, y * width + x + 1);
+                }
+                if y + 1 < height {
+                    manual.link_bidirect(y * width + x, (y + 1) * width + x);
+                }
+            }
+        }
+
+        let mut grid = Graph::<GraphVertex>::new();
+        grid.set_vertices(make_grid_vertices(width, height));
+        grid.link_grid(width, height);
+
+        for (a, b) in manual.vertices().iter().zip(grid.vertices()) {
+            let mut a = a.neighbours.clone();
+            let mut b = b.neighbours.clone();
+            a.sort_unstable();
+            b.sort_unstable();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_link_from_adjacency() {
+        let mut graph = Graph::<GraphVertex>::new();
+        graph.set_vertices(make_grid_vertices(3, 1));
+        graph.link_from_adjacency(&[(0, 1), (1, 2), (2, 100), (1, 0)]);
+
+        assert_eq!(graph.vertex(0).unwrap().neighbours, vec![1]);
+        assert_eq!(graph.vertex(1).unwrap().neighbours, vec![0, 2]);
+        assert_eq!(graph.vertex(2).unwrap().neighbours, vec![1]);
+    }
+
     #[test]
     fn test_remove_vertex() {
         let mut pathfinder = Graph::<GraphVertex>::new();
@@ -746,6 +2318,619 @@ mod test {
         assert_eq!(pathfinder.vertex(3).unwrap().neighbours, vec![2, 1]);
     }
 
+    #[test]
+    fn test_build_until() {
+        let pathfinder = make_grid(10, |_, _| false);
+
+        // Reach any vertex within the radius of a point.
+        let center = Vector3::new(8.0, 8.0, 0.0);
+        let radius = 1.5;
+        let mut path = Vec::new();
+        assert!(matches!(
+            pathfinder.build_until(
+                0,
+                |_, vertex| vertex.position.metric_distance(&center) <= radius,
+                |vertex| (vertex.position.metric_distance(&center) - radius).max(0.0),
+                &mut path,
+            ),
+            Ok(PathKind::Full)
+        ));
+        let goal = pathfinder.vertex(path[0]).unwrap();
+        assert!(goal.position.metric_distance(&center) <= radius);
+        assert_eq!(*path.last().unwrap(), 0);
+
+        // Start vertex satisfies the goal.
+        assert!(matches!(
+            pathfinder.build_until(5, |index, _| index == 5, |_| 0.0, &mut path),
+            Ok(PathKind::Full)
+        ));
+        assert_eq!(path, vec![5]);
+
+        // Unreachable goal.
+        assert!(matches!(
+            pathfinder.build_until(0, |_, _| false, |_| 0.0, &mut path),
+            Ok(PathKind::Partial)
+        ));
+    }
+
+    #[test]
+    fn test_can_reach() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(3.0, 0.0, 0.0)));
+
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_unidirect(1, 2);
+
+        assert!(pathfinder.can_reach(0, 0));
+        assert!(pathfinder.can_reach(0, 2));
+        assert!(!pathfinder.can_reach(2, 0));
+        assert!(!pathfinder.can_reach(0, 3));
+        assert!(!pathfinder.can_reach(0, 100));
+    }
+
+    #[test]
+    fn test_search_budget() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        // A long chain, that needs more iterations than the old hard-coded limit of 1000.
+        let count = 2000;
+        for i in 0..count {
+            pathfinder.add_vertex(GraphVertex::new(Vector3::new(i as f32, 0.0, 0.0)));
+        }
+        for i in 0..(count - 1) {
+            pathfinder.link_bidirect(i, i + 1);
+        }
+
+        let mut path = Vec::new();
+        assert!(matches!(
+            pathfinder.build_indexed_path(0, count - 1, &mut path),
+            Ok(PathKind::Full)
+        ));
+        assert_eq!(path.len(), count);
+
+        assert!(matches!(
+            pathfinder.build_with_budget(0, count - 1, 10, &mut path),
+            Err(PathError::HitMaxSearchIterations(10))
+        ));
+        assert!(!path.is_empty());
+        assert_eq!(*path.last().unwrap(), 0);
+
+        assert!(matches!(
+            pathfinder.build_with_budget(0, count - 1, count, &mut path),
+            Ok(PathKind::Full)
+        ));
+
+        pathfinder.max_search_iterations = 10;
+        assert!(matches!(
+            pathfinder.build_indexed_path(0, count - 1, &mut path),
+            Err(PathError::HitMaxSearchIterations(10))
+        ));
+
+        pathfinder.max_search_iterations = -1;
+        assert!(matches!(
+            pathfinder.build_indexed_path(0, count - 1, &mut path),
+            Ok(PathKind::Full)
+        ));
+    }
+
+    #[test]
+    fn test_build_with_heuristic() {
+        let size = 6;
+        // A wall with a single gap at the top.
+        let pathfinder = make_grid(size, |x, y| x == 2 && y < size - 1);
+
+        let path_cost = |path: &[usize]| -> f32 {
+            path.windows(2)
+                .map(|pair| {
+                    (pathfinder.vertices[pair[0]].position - pathfinder.vertices[pair[1]].position)
+                        .norm()
+                })
+                .sum()
+        };
+
+        let manhattan = |a: Vector3<f32>, b: Vector3<f32>| (a - b).abs().sum();
+
+        let count = size * size;
+        let sources = (0..count).collect::<Vec<_>>();
+        let optimal = pathfinder.distance_matrix(&sources);
+
+        let mut path = Vec::new();
+        for (from, costs) in optimal.iter().enumerate() {
+            for (to, optimal_cost) in costs.iter().enumerate() {
+                assert!(matches!(
+                    pathfinder.build_indexed_path(from, to, &mut path),
+                    Ok(PathKind::Full)
+                ));
+                assert!(path_cost(&path) <= optimal_cost + 0.001);
+
+                assert!(matches!(
+                    pathfinder.build_with_heuristic(from, to, manhattan, &mut path),
+                    Ok(PathKind::Full)
+                ));
+                assert!(path_cost(&path) <= optimal_cost + 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_travel_cost_is_distance() {
+        //     1
+        //   /   \
+        // 0 ----- 2
+        let mut pathfinder = Graph::<GraphVertex>::new();
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 0.5, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(4.0, 0.0, 0.0)));
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_bidirect(1, 2);
+        pathfinder.link_bidirect(0, 2);
+
+        let a = pathfinder.vertex(0).unwrap();
+        let b = pathfinder.vertex(2).unwrap();
+        assert_eq!(a.travel_cost(2, b), 4.0);
+
+        // The direct link is shorter, but it would be more expensive if the squared distance was
+        // used as the cost (16.0 vs 8.5).
+        let mut path = Vec::new();
+        let (kind, cost) = pathfinder.build_with_cost(0, 2, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Full);
+        assert_eq!(path, vec![2, 0]);
+        assert_eq!(cost, 4.0);
+
+        // Penalty scales the distance.
+        pathfinder.vertex_mut(2).unwrap().g_penalty = 2.0;
+        let a = pathfinder.vertex(0).unwrap();
+        let b = pathfinder.vertex(2).unwrap();
+        assert_eq!(a.travel_cost(2, b), 8.0);
+    }
+
+    #[test]
+    fn test_build_to_nearest() {
+        let size = 6;
+        // A wall with a single gap at the top.
+        let pathfinder = make_grid(size, |x, y| x == 2 && y < size - 1);
+
+        let mut path = Vec::new();
+
+        // The first goal is behind the wall and is closer in straight line, but the second one is
+        // closer by path cost.
+        let from = 2;
+        let behind_wall = 3;
+        let same_side = 2 * size;
+        let result = pathfinder.build_to_nearest(
+            from,
+            &[behind_wall, same_side, same_side, behind_wall],
+            &mut path,
+        );
+        assert!(matches!(result, Ok((PathKind::Full, goal)) if goal == same_side));
+        assert_eq!(path.first(), Some(&same_side));
+        assert_eq!(path.last(), Some(&from));
+
+        // Single goal must behave as a regular search.
+        let mut single = Vec::new();
+        assert!(matches!(
+            pathfinder.build_to_nearest(from, &[behind_wall], &mut path),
+            Ok((PathKind::Full, goal)) if goal == behind_wall
+        ));
+        assert!(matches!(
+            pathfinder.build_indexed_path(from, behind_wall, &mut single),
+            Ok(PathKind::Full)
+        ));
+        assert_eq!(path.len(), single.len());
+
+        assert!(matches!(
+            pathfinder.build_to_nearest(from, &[], &mut path),
+            Err(PathError::Empty)
+        ));
+        assert!(matches!(
+            pathfinder.build_to_nearest(from, &[size * size], &mut path),
+            Err(PathError::InvalidIndex(_))
+        ));
+    }
+
+    #[test]
+    fn test_reachable_within() {
+        let size = 8;
+        let pathfinder = make_grid(size, |_, _| false);
+
+        let from = 3 * size + 3;
+
+        let start = pathfinder.reachable_within(from, 0.0).unwrap();
+        assert_eq!(start.len(), 1);
+        assert_eq!(start[0].0, from);
+
+        // The beginning point is included even if the max cost is negative.
+        assert_eq!(
+            pathfinder.reachable_within(from, -1.0).unwrap(),
+            vec![(from, 0.0)]
+        );
+
+        // Manhattan distance is the exact cost on a grid with unit links.
+        let mut previous = Vec::new();
+        for max_cost in 0..=(2 * size) {
+            let max_cost = max_cost as f32;
+            let reachable = pathfinder.reachable_within(from, max_cost).unwrap();
+
+            assert!(reachable.len() >= previous.len());
+            for (index, _) in previous.iter() {
+                assert!(reachable.iter().any(|(other, _)| other == index));
+            }
+
+            let origin = pathfinder.vertices[from].position;
+            for (index, cost) in reachable.iter() {
+                assert!(*cost <= max_cost);
+                let position = pathfinder.vertices[*index].position;
+                assert!((cost - (position - origin).abs().sum()).abs() < 0.001);
+            }
+
+            previous = reachable;
+        }
+        assert_eq!(previous.len(), size * size);
+
+        assert!(matches!(
+            pathfinder.reachable_within(size * size, 1.0),
+            Err(PathError::InvalidIndex(_))
+        ));
+
+        // Disconnected vertices are not reachable even with an infinite max cost.
+        let mut pathfinder = make_grid(size, |_, _| false);
+        let isolated =
+            pathfinder.add_vertex(GraphVertex::new(Vector3::new(100.0, 100.0, 0.0))) as usize;
+        let reachable = pathfinder.reachable_within(from, f32::INFINITY).unwrap();
+        assert_eq!(reachable.len(), size * size);
+        assert!(reachable
+            .iter()
+            .all(|(index, cost)| *index != isolated && cost.is_finite()));
+    }
+
+    #[test]
+    fn test_components() {
+        let size = 10;
+        // Two islands separated half way along the x-axis.
+        let mut pathfinder = make_grid(size, |x, _| x == (size / 2) - 1);
+
+        let components = pathfinder.components();
+        assert_eq!(components.len(), 2);
+        for component in components.iter() {
+            assert_eq!(component.len(), size * size / 2);
+        }
+        assert!(components[0].iter().all(|index| index % size < size / 2));
+        assert!(components[1].iter().all(|index| index % size >= size / 2));
+        assert_eq!(pathfinder.strongly_connected_components(), components);
+
+        // One-way bridge joins the islands, but only if the direction is ignored.
+        pathfinder.link_unidirect(size / 2 - 1, size / 2);
+        assert_eq!(pathfinder.components().len(), 1);
+        assert_eq!(pathfinder.strongly_connected_components(), components);
+
+        // Simple directed cycle with a tail.
+        let mut pathfinder = Graph::<GraphVertex>::new();
+        for i in 0..4 {
+            pathfinder.add_vertex(GraphVertex::new(Vector3::new(i as f32, 0.0, 0.0)));
+        }
+        pathfinder.link_unidirect(0, 1);
+        pathfinder.link_unidirect(1, 2);
+        pathfinder.link_unidirect(2, 0);
+        pathfinder.link_unidirect(2, 3);
+        assert_eq!(pathfinder.components(), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(
+            pathfinder.strongly_connected_components(),
+            vec![vec![0, 1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn test_build_with_cost() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        //   1 - 2
+        //  /     \
+        // 0       5
+        //  \     /
+        //   3 - 4
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 1.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 1.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, -1.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, -1.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(3.0, 0.0, 0.0)));
+
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_bidirect(1, 2);
+        pathfinder.link_bidirect(2, 5);
+        pathfinder.link_bidirect(0, 3);
+        pathfinder.link_bidirect(3, 4);
+        pathfinder.link_bidirect(4, 5);
+
+        // Make the upper route more expensive.
+        pathfinder.vertex_mut(1).unwrap().g_penalty = 3.0;
+
+        let mut path = Vec::new();
+        let (kind, cost) = pathfinder.build_with_cost(0, 5, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Full);
+        assert_eq!(path, vec![5, 4, 3, 0]);
+        let expected = 2.0f32.sqrt() + 1.0 + 2.0f32.sqrt();
+        assert!((cost - expected).abs() < 0.0001);
+
+        // Now the lower route is more expensive.
+        pathfinder.vertex_mut(1).unwrap().g_penalty = 1.0;
+        pathfinder.vertex_mut(4).unwrap().g_penalty = 5.0;
+        let (kind, cost) = pathfinder.build_with_cost(0, 5, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Full);
+        assert_eq!(path, vec![5, 2, 1, 0]);
+        assert!((cost - expected).abs() < 0.0001);
+
+        let (kind, cost) = pathfinder.build_with_cost(3, 3, &mut path).unwrap();
+        assert_eq!(kind, PathKind::Full);
+        assert_eq!(cost, 0.0);
+    }
+
+    #[test]
+    fn test_weighted_links() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 1.0, 0.0)));
+
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_bidirect(1, 2);
+        pathfinder.link_bidirect(0, 3);
+        pathfinder.link_bidirect(3, 2);
+
+        let mut path = Vec::new();
+        let (_, cost) = pathfinder.build_with_cost(0, 2, &mut path).unwrap();
+        assert_eq!(path, vec![2, 1, 0]);
+        assert_eq!(cost, 2.0);
+
+        // Crossing from 1 to 2 is expensive, but not the other way around.
+        pathfinder.link_unidirect_weighted(1, 2, 10.0);
+        let (_, cost) = pathfinder.build_with_cost(0, 2, &mut path).unwrap();
+        assert_eq!(path, vec![2, 3, 0]);
+        assert!((cost - 2.0 * 2.0f32.sqrt()).abs() < 0.0001);
+
+        let (_, cost) = pathfinder.build_with_cost(2, 0, &mut path).unwrap();
+        assert_eq!(path, vec![0, 1, 2]);
+        assert_eq!(cost, 2.0);
+
+        // Re-linking keeps the explicit cost.
+        pathfinder.link_bidirect(1, 2);
+        assert_eq!(
+            pathfinder.vertex(1).unwrap().edge_costs.get(&2),
+            Some(&10.0)
+        );
+
+        // Costs must follow the indices of the vertices.
+        pathfinder.remove_vertex(0);
+        assert_eq!(
+            pathfinder.vertex(0).unwrap().edge_costs.get(&1),
+            Some(&10.0)
+        );
+        pathfinder.insert_vertex(0, GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        assert_eq!(
+            pathfinder.vertex(1).unwrap().edge_costs.get(&2),
+            Some(&10.0)
+        );
+        pathfinder.remove_vertex(2);
+        assert!(pathfinder.vertex(1).unwrap().edge_costs.is_empty());
+    }
+
+    #[test]
+    fn test_remove_vertices() {
+        let make_graph = || {
+            let size = 6;
+            let mut pathfinder = make_grid(size, |_, _| false);
+            // Explicit costs must be remapped as well.
+            for index in 0..size * (size - 1) {
+                pathfinder.link_unidirect_weighted(index, index + size, 2.0);
+            }
+            pathfinder
+        };
+
+        let indices = [7, 0, 35, 14, 7, 20, 21];
+
+        let mut sequential = make_graph();
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        for index in sorted.into_iter().rev() {
+            sequential.remove_vertex(index);
+        }
+
+        let mut batch = make_graph();
+        batch.remove_vertices(&indices);
+
+        assert_eq!(batch.vertices().len(), 36 - 6);
+        assert_eq!(batch.vertices(), sequential.vertices());
+    }
+
+    #[test]
+    fn test_spatial_index() {
+        let mut rng = rand::thread_rng();
+
+        for flat in [true, false] {
+            let mut pathfinder = Graph::<GraphVertex>::new();
+            for _ in 0..1000 {
+                let z = if flat { 0.0 } else { rng.gen_range(-5.0..5.0) };
+                pathfinder.add_vertex(GraphVertex::new(Vector3::new(
+                    rng.gen_range(-50.0..50.0),
+                    rng.gen_range(-20.0..20.0),
+                    z,
+                )));
+            }
+            // Duplicate position, the lowest index must win.
+            pathfinder.add_vertex(GraphVertex::new(pathfinder.vertices[10].position));
+
+            let points = (0..500)
+                .map(|_| {
+                    Vector3::new(
+                        rng.gen_range(-100.0..100.0),
+                        rng.gen_range(-100.0..100.0),
+                        rng.gen_range(-10.0..10.0),
+                    )
+                })
+                .chain([pathfinder.vertices[10].position])
+                .collect::<Vec<_>>();
+
+            let linear = points
+                .iter()
+                .map(|point| pathfinder.get_closest_vertex_to(*point))
+                .collect::<Vec<_>>();
+
+            pathfinder.rebuild_spatial_index();
+            assert!(pathfinder.spatial_index.is_some());
+
+            for (point, expected) in points.iter().zip(linear) {
+                assert_eq!(pathfinder.get_closest_vertex_to(*point), expected);
+            }
+
+            pathfinder.add_vertex(GraphVertex::new(Vector3::new(1000.0, 0.0, 0.0)));
+            assert!(pathfinder.spatial_index.is_none());
+            assert_eq!(
+                pathfinder.get_closest_vertex_to(Vector3::new(999.0, 0.0, 0.0)),
+                Some(1001)
+            );
+        }
+
+        let mut empty = Graph::<GraphVertex>::new();
+        empty.rebuild_spatial_index();
+        assert_eq!(empty.get_closest_vertex_to(Vector3::default()), None);
+
+        // The spatial index does not affect equality.
+        let mut graph = Graph::<GraphVertex>::new();
+        graph.add_vertex(GraphVertex::new(Vector3::new(1.0, 2.0, 3.0)));
+        let mut indexed = Graph::<GraphVertex>::new();
+        indexed.add_vertex(GraphVertex::new(Vector3::new(1.0, 2.0, 3.0)));
+        indexed.rebuild_spatial_index();
+        assert_eq!(graph, indexed);
+        assert_eq!(format!("{graph:?}"), format!("{indexed:?}"));
+    }
+
+    #[test]
+    fn test_smooth_path() {
+        let mut empty = Vec::new();
+        smooth_path(&mut empty, |_, _| true);
+        assert!(empty.is_empty());
+
+        let mut single = vec![Vector3::new(1.0, 2.0, 3.0)];
+        smooth_path(&mut single, |_, _| true);
+        assert_eq!(single, vec![Vector3::new(1.0, 2.0, 3.0)]);
+
+        // Stair-like path along the grid edges.
+        let stairs = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(2.0, 1.0, 0.0),
+            Vector3::new(2.0, 2.0, 0.0),
+            Vector3::new(3.0, 2.0, 0.0),
+        ];
+
+        // Nothing obstructs the path, so only the end points are left.
+        let mut path = stairs.clone();
+        smooth_path(&mut path, |_, _| true);
+        assert_eq!(path, vec![stairs[0], stairs[5]]);
+
+        // Everything is obstructed, so the path must stay the same.
+        let mut path = stairs.clone();
+        smooth_path(&mut path, |_, _| false);
+        assert_eq!(path, stairs);
+
+        // An obstacle that blocks lines crossing x = 1.5 below y = 1.5.
+        let mut path = stairs.clone();
+        smooth_path(&mut path, |a, b| {
+            let (a, b) = if a.x < b.x { (a, b) } else { (b, a) };
+            if a.x < 1.5 && b.x > 1.5 {
+                let t = (1.5 - a.x) / (b.x - a.x);
+                a.y + (b.y - a.y) * t >= 1.5
+            } else {
+                true
+            }
+        });
+        assert_eq!(path.first(), Some(&stairs[0]));
+        assert_eq!(path.last(), Some(&stairs[5]));
+        assert_eq!(path, vec![stairs[0], stairs[4], stairs[5]]);
+    }
+
+    #[test]
+    fn test_build_indices() {
+        let size = 5;
+        let pathfinder = make_grid(size, |_, _| false);
+
+        let from = 0;
+        let to = 3 * size + 3;
+
+        let mut indices = Vec::new();
+        let mut positions = Vec::new();
+        assert!(matches!(
+            pathfinder.build_indices(from, to, &mut indices),
+            Ok(PathKind::Full)
+        ));
+        assert!(matches!(
+            pathfinder.build_positional_path(from, to, &mut positions),
+            Ok(PathKind::Full)
+        ));
+
+        assert_eq!(indices.first(), Some(&from));
+        assert_eq!(indices.last(), Some(&to));
+        assert!(indices
+            .iter()
+            .map(|i| pathfinder.vertex(*i).unwrap().position)
+            .eq(positions.iter().rev().cloned()));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 0.0, 0.0)));
+
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_unidirect(1, 2);
+
+        let dot = pathfinder.to_dot(|index, vertex| format!("{index}: \"{}\"", vertex.position.x));
+
+        assert_eq!(
+            dot,
+            "digraph {\n    \
+            0 [label=\"0: \\\"0\\\"\"];\n    \
+            1 [label=\"1: \\\"1\\\"\"];\n    \
+            2 [label=\"2: \\\"2\\\"\"];\n    \
+            0 -> 1 [dir=both];\n    \
+            1 -> 2;\n\
+            }"
+        );
+    }
+
+    #[test]
+    fn test_distance_matrix() {
+        let mut pathfinder = Graph::<GraphVertex>::new();
+
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(0.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(1.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(2.0, 0.0, 0.0)));
+        pathfinder.add_vertex(GraphVertex::new(Vector3::new(5.0, 0.0, 0.0)));
+
+        pathfinder.link_bidirect(0, 1);
+        pathfinder.link_bidirect(1, 2);
+
+        let matrix = pathfinder.distance_matrix(&[0, 2]);
+        assert_eq!(matrix[0], vec![0.0, 1.0, 2.0, f32::INFINITY]);
+        assert_eq!(matrix[1], vec![2.0, 1.0, 0.0, f32::INFINITY]);
+
+        let matrix = pathfinder.distance_matrix_to(&[0, 3, 100], &[2, 3]);
+        assert_eq!(matrix[0], vec![2.0, f32::INFINITY]);
+        assert_eq!(matrix[1], vec![f32::INFINITY, 0.0]);
+        assert_eq!(matrix[2], vec![f32::INFINITY, f32::INFINITY]);
+    }
+
     #[ignore = "takes multiple seconds to run"]
     #[test]
     /// Tests A*'s speed when finding a direct path with no obsticles
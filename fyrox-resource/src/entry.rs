@@ -24,7 +24,10 @@
 //! The inner resource might still be in use (have a strong reference to it), the resource data
 //! will be deleted once no one uses the resource.
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 /// Lifetime of orphaned resource in seconds (with only one strong ref which is resource manager itself)
 pub const DEFAULT_RESOURCE_LIFETIME: f32 = 60.0;
@@ -37,6 +40,15 @@ pub struct TimedEntry<T> {
     pub value: T,
     /// Time to live in seconds.
     pub time_to_live: f32,
+    /// Lifetime in seconds, that is used to reset [`Self::time_to_live`]. `None` means that the
+    /// entry is never removed.
+    pub lifetime: Option<f32>,
+    /// A flag, that defines whether the entry was already noticed as having a single owner (the
+    /// container itself). It is reset once the entry gets shared again.
+    pub orphaned: bool,
+    /// A "timestamp" of the last access to the entry. It is a value of a monotonic counter of the
+    /// container, that is used to find least recently used entries.
+    pub last_access: AtomicU64,
 }
 
 impl<T> Deref for TimedEntry<T> {
@@ -61,6 +73,9 @@ where
         Self {
             value: Default::default(),
             time_to_live: DEFAULT_RESOURCE_LIFETIME,
+            lifetime: Some(DEFAULT_RESOURCE_LIFETIME),
+            orphaned: false,
+            last_access: Default::default(),
         }
     }
 }
@@ -73,6 +88,9 @@ where
         Self {
             value: self.value.clone(),
             time_to_live: self.time_to_live,
+            lifetime: self.lifetime,
+            orphaned: self.orphaned,
+            last_access: AtomicU64::new(self.last_access.load(Ordering::Relaxed)),
         }
     }
 }
@@ -87,6 +105,9 @@ mod test {
 
         assert_eq!(t.value, 0);
         assert_eq!(t.time_to_live, DEFAULT_RESOURCE_LIFETIME);
+        assert_eq!(t.lifetime, Some(DEFAULT_RESOURCE_LIFETIME));
+        assert!(!t.orphaned);
+        assert_eq!(t.last_access.load(Ordering::Relaxed), 0);
     }
 
     #[test]
@@ -114,10 +135,19 @@ mod test {
         let t = TimedEntry {
             value: 42,
             time_to_live: 15.0,
+            lifetime: None,
+            orphaned: true,
+            last_access: AtomicU64::new(7),
         };
         let t2 = t.clone();
 
         assert_eq!(t.value, t2.value);
         assert_eq!(t.time_to_live, t2.time_to_live);
+        assert_eq!(t.lifetime, t2.lifetime);
+        assert_eq!(t.orphaned, t2.orphaned);
+        assert_eq!(
+            t.last_access.load(Ordering::Relaxed),
+            t2.last_access.load(Ordering::Relaxed)
+        );
     }
 }
@@ -111,9 +111,14 @@ pub trait ResourceIo: Send + Sync + 'static {
     /// and put the path into a form that is suited for use in methods of this object.
     /// Each file should have exactly one canonical path.
     /// This method should guarantee correct behaviour for relative paths. Symlinks aren't mandatory to
-    /// follow.
+    /// follow. Default implementation normalizes relative paths using [`normalize_path`] and keeps
+    /// absolute paths as is.
     fn canonicalize_path<'a>(&'a self, path: &'a Path) -> Result<PathBuf, FileError> {
-        Ok(path.to_owned())
+        if path.is_absolute() {
+            Ok(path.to_owned())
+        } else {
+            normalize_path(path)
+        }
     }
 
     /// Provides an iterator over the paths present in the provided
@@ -175,6 +180,103 @@ pub trait ResourceIo: Send + Sync + 'static {
 #[derive(Default)]
 pub struct FsResourceIo;
 
+/// Read-only resource IO provider that serves a single file from memory. It is used to load
+/// resources from raw bytes (see [`crate::manager::ResourceManagerState::load_from_bytes`]).
+/// Any attempt to access other files or to modify the file will result in an error.
+#[derive(Debug)]
+pub struct MemoryResourceIo {
+    path: PathBuf,
+    data: Vec<u8>,
+}
+
+impl MemoryResourceIo {
+    /// Creates a new memory IO, that serves the given data under the given path.
+    pub fn new(path: PathBuf, data: Vec<u8>) -> Self {
+        Self { path, data }
+    }
+
+    fn read_only_error(&self) -> FileError {
+        FileError::Custom(format!(
+            "{} is an in-memory file and cannot be modified!",
+            self.path.display()
+        ))
+    }
+}
+
+impl ResourceIo for MemoryResourceIo {
+    fn can_write(&self) -> bool {
+        false
+    }
+
+    fn can_read_directories(&self) -> bool {
+        false
+    }
+
+    fn load_file<'a>(&'a self, path: &'a Path) -> ResourceIoFuture<'a, Result<Vec<u8>, FileError>> {
+        let result = if path == self.path {
+            Ok(self.data.clone())
+        } else {
+            Err(FileError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} does not exist in memory!", path.display()),
+            )))
+        };
+        Box::pin(ready(result))
+    }
+
+    fn write_file<'a>(
+        &'a self,
+        _path: &'a Path,
+        _data: Vec<u8>,
+    ) -> ResourceIoFuture<'a, Result<(), FileError>> {
+        Box::pin(ready(Err(self.read_only_error())))
+    }
+
+    fn write_file_sync(&self, _path: &Path, _data: &[u8]) -> Result<(), FileError> {
+        Err(self.read_only_error())
+    }
+
+    fn move_file<'a>(
+        &'a self,
+        _source: &'a Path,
+        _dest: &'a Path,
+    ) -> ResourceIoFuture<'a, Result<(), FileError>> {
+        Box::pin(ready(Err(self.read_only_error())))
+    }
+
+    fn delete_file<'a>(&'a self, _path: &'a Path) -> ResourceIoFuture<'a, Result<(), FileError>> {
+        Box::pin(ready(Err(self.read_only_error())))
+    }
+
+    fn delete_file_sync(&self, _path: &Path) -> Result<(), FileError> {
+        Err(self.read_only_error())
+    }
+
+    fn copy_file<'a>(
+        &'a self,
+        _source: &'a Path,
+        _dest: &'a Path,
+    ) -> ResourceIoFuture<'a, Result<(), FileError>> {
+        Box::pin(ready(Err(self.read_only_error())))
+    }
+
+    fn is_valid_file_name(&self, _name: &OsStr) -> bool {
+        true
+    }
+
+    fn exists<'a>(&'a self, path: &'a Path) -> ResourceIoFuture<'a, bool> {
+        Box::pin(ready(path == self.path))
+    }
+
+    fn is_file<'a>(&'a self, path: &'a Path) -> ResourceIoFuture<'a, bool> {
+        Box::pin(ready(path == self.path))
+    }
+
+    fn is_dir<'a>(&'a self, _path: &'a Path) -> ResourceIoFuture<'a, bool> {
+        Box::pin(ready(false))
+    }
+}
+
 /// Future for resource io loading
 #[cfg(target_arch = "wasm32")]
 pub type ResourceIoFuture<'a, V> = Pin<Box<dyn Future<Output = V> + 'a>>;
@@ -208,7 +310,11 @@ pub fn normalize_path(path: impl AsRef<Path>) -> Result<PathBuf, FileError> {
             Component::CurDir => {}
             Component::ParentDir => {
                 if !ret.pop() {
-                    panic!("Path may not start with ..");
+                    return Err(format!(
+                        "Invalid path: {:?}, it must not go outside of the project root",
+                        path.as_ref()
+                    )
+                    .into());
                 }
             }
             Component::Normal(c) => {
@@ -425,6 +531,15 @@ mod test {
         assert_eq!(normalize_path(&path).unwrap().as_os_str(), ".");
     }
     #[test]
+    fn test_canonicalize_parent_dir() {
+        let path = PathBuf::from("../foo.png");
+        assert!(normalize_path(&path).is_err());
+        assert!(normalize_path("alpha/../../foo.png").is_err());
+        let rio = MemoryResourceIo::new("foo.png".into(), Vec::new());
+        assert!(rio.canonicalize_path(&path).is_err());
+        assert!(FsResourceIo.canonicalize_path(&path).is_err());
+    }
+    #[test]
     fn test_canonicalize() {
         let rio = FsResourceIo;
         let path = PathBuf::from("src/test.txt");
@@ -90,6 +90,14 @@ pub trait ResourceData: Debug + Visit + Send + Reflect {
     /// Tries to clone the resource data. This method can return `None` if the underlying type is
     /// non-cloneable.
     fn try_clone_box(&self) -> Option<Box<dyn ResourceData>>;
+
+    /// Returns approximate amount of memory (in bytes) occupied by the resource data. It is used for
+    /// memory accounting (see [`crate::manager::ResourceManagerState::total_bytes`]), the value does not need to be
+    /// exact, but it should reflect the "heavy" parts of the data (such as pixels of a texture).
+    /// Default implementation returns `0`.
+    fn byte_size(&self) -> usize {
+        0
+    }
 }
 
 /// Extension trait for a resource data of a particular type, which adds additional functionality,
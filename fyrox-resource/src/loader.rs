@@ -31,13 +31,17 @@ use fyrox_core::io::FileError;
 use fyrox_core::platform::TargetPlatform;
 use fyrox_core::visitor::{Format, Visitor};
 use std::{
-    any::Any,
+    any::{Any, TypeId},
+    collections::BTreeMap,
     future::Future,
     path::{Path, PathBuf},
     pin::Pin,
     sync::Arc,
 };
 
+/// Amount of bytes at the beginning of a file, that is passed to [`ResourceLoader::probe`].
+pub const PROBE_BYTES_COUNT: usize = 64;
+
 #[cfg(target_arch = "wasm32")]
 #[doc(hidden)]
 pub trait BaseResourceLoader: Any {}
@@ -116,9 +120,27 @@ pub trait ResourceLoader: BaseResourceLoader {
             .any(|e| fyrox_core::cmp_strings_case_insensitive(e, ext))
     }
 
+    /// Checks whether the loader recognizes a file by its first bytes (usually by a "magic" header).
+    /// It is used by the resource manager only when there's no loader that supports the extension
+    /// of the file (or the file has no extension at all). `bytes` contains at most
+    /// [`PROBE_BYTES_COUNT`] bytes. The default implementation returns `false`.
+    fn probe(&self, #[allow(unused_variables)] bytes: &[u8]) -> bool {
+        false
+    }
+
     /// Must return a type uuid of the resource data type.
     fn data_type_uuid(&self) -> Uuid;
 
+    /// Returns `true` if the loader wants to receive the file bytes transformed by the byte transform
+    /// of the resource loaders container (see [`ResourceLoadersContainer::set_byte_transform`]). In
+    /// this case, the resource manager reads the file, transforms its bytes and passes an in-memory
+    /// IO to [`Self::load`], that serves only the transformed file. It means that such loaders cannot
+    /// read any other files (for example, dependencies of the resource) through the given IO. The
+    /// default implementation returns `false`.
+    fn accepts_transformed_bytes(&self) -> bool {
+        false
+    }
+
     /// Loads or reloads a resource.
     fn load(&self, path: PathBuf, io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture;
 
@@ -180,10 +202,15 @@ pub type BoxedLoaderFuture = Pin<Box<dyn Future<Output = Result<LoaderPayload, L
 pub type BoxedImportOptionsLoaderFuture =
     Pin<Box<dyn Future<Output = Option<Box<dyn BaseImportOptions>>>>>;
 
+/// A function that transforms the bytes of a resource file before they're passed to a resource
+/// loader. See [`ResourceLoadersContainer::set_byte_transform`] for more info.
+pub type ByteTransform = Arc<dyn Fn(&Path, Vec<u8>) -> Vec<u8> + Send + Sync>;
+
 /// Container for resource loaders.
 #[derive(Default)]
 pub struct ResourceLoadersContainer {
     loaders: Vec<Box<dyn ResourceLoader>>,
+    byte_transform: Option<ByteTransform>,
 }
 
 impl ResourceLoadersContainer {
@@ -232,6 +259,40 @@ impl ResourceLoadersContainer {
         }
     }
 
+    /// Removes a resource loader of the given type `T` from the container and returns it (if any).
+    /// It could be used to disable support of a resource format at runtime.
+    pub fn remove<T>(&mut self) -> Option<T>
+    where
+        T: ResourceLoader,
+    {
+        let pos = self
+            .loaders
+            .iter()
+            .position(|l| (&**l as &dyn Any).is::<T>())?;
+        (self.loaders.remove(pos) as Box<dyn Any>)
+            .downcast::<T>()
+            .ok()
+            .map(|boxed| *boxed)
+    }
+
+    /// Removes a resource loader with the given type id from the container and returns it (if any).
+    /// Unlike [`Self::remove`], this method does not require to know the type of the loader at
+    /// compile time.
+    pub fn remove_by_type_id(&mut self, type_id: TypeId) -> Option<Box<dyn ResourceLoader>> {
+        let pos = self
+            .loaders
+            .iter()
+            .position(|l| Any::type_id(&**l as &dyn Any) == type_id)?;
+        Some(self.loaders.remove(pos))
+    }
+
+    /// Returns type ids of every resource loader in the container.
+    pub fn type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.loaders
+            .iter()
+            .map(|loader| Any::type_id(&**loader as &dyn Any))
+    }
+
     /// Tries to find an instance of a resource loader of the given type `T.
     pub fn find<T>(&self) -> Option<&T>
     where
@@ -252,6 +313,30 @@ impl ResourceLoadersContainer {
             .find_map(|loader| (&mut **loader as &mut dyn Any).downcast_mut())
     }
 
+    /// Sets a function, that transforms the bytes of resource files (for example, decrypts or
+    /// decompresses them) before they're passed to a resource loader. The function receives a path
+    /// of the resource and its raw bytes. The transform is applied only to the resources loaded by
+    /// the loaders that opt in via [`ResourceLoader::accepts_transformed_bytes`].
+    pub fn set_byte_transform<F>(&mut self, transform: F)
+    where
+        F: Fn(&Path, Vec<u8>) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.byte_transform = Some(Arc::new(transform));
+    }
+
+    /// Removes the byte transform (if any), see [`Self::set_byte_transform`].
+    pub fn clear_byte_transform(&mut self) {
+        self.byte_transform = None;
+    }
+
+    /// Returns the byte transform that should be used to load a resource at the given path, if the
+    /// loader of the resource accepts transformed bytes.
+    pub fn byte_transform_for(&self, path: &Path) -> Option<ByteTransform> {
+        self.loader_for(path)
+            .filter(|loader| loader.accepts_transformed_bytes())
+            .and(self.byte_transform.clone())
+    }
+
     /// Returns total amount of resource loaders in the container.
     pub fn len(&self) -> usize {
         self.loaders.len()
@@ -291,9 +376,7 @@ impl ResourceLoadersContainer {
         T: TypedResourceData,
     {
         path.extension().is_some_and(|extension| {
-            self.loaders
-                .iter()
-                .find(|loader| loader.supports_extension(&extension.to_string_lossy()))
+            self.find_loader_for_extension(&extension.to_string_lossy())
                 .is_some_and(|loader| {
                     loader.data_type_uuid() == <T as TypeUuidProvider>::type_uuid()
                 })
@@ -302,12 +385,51 @@ impl ResourceLoadersContainer {
 
     /// Checks if there's a loader for the given path.
     pub fn loader_for(&self, path: &Path) -> Option<&dyn ResourceLoader> {
-        path.extension().and_then(|extension| {
-            self.loaders
-                .iter()
-                .find(|loader| loader.supports_extension(&extension.to_string_lossy()))
-                .map(|l| &**l)
-        })
+        path.extension()
+            .and_then(|extension| self.find_loader_for_extension(&extension.to_string_lossy()))
+    }
+
+    /// Tries to find a loader, that supports the given extension (comparison is case-insensitive).
+    ///
+    /// # Priority
+    ///
+    /// If there are multiple loaders, that support the extension, the one that was added first
+    /// wins. Replacing a loader (via [`Self::set`] or [`Self::try_replace`]) keeps its position.
+    /// Use [`Self::conflicting_extensions`] to detect such ambiguous registrations.
+    pub fn find_loader_for_extension(&self, ext: &str) -> Option<&dyn ResourceLoader> {
+        self.loaders
+            .iter()
+            .find(|loader| loader.supports_extension(ext))
+            .map(|l| &**l)
+    }
+
+    /// Returns a list of extensions (in lowercase, sorted), that are supported by more than one
+    /// loader, along with type ids of such loaders (in the order of priority). It could be useful
+    /// to detect ambiguous registrations at startup. See [`Self::find_loader_for_extension`] for
+    /// more info about the priority.
+    pub fn conflicting_extensions(&self) -> Vec<(String, Vec<TypeId>)> {
+        let mut map = BTreeMap::<String, Vec<TypeId>>::new();
+        for loader in self.loaders.iter() {
+            let type_id = Any::type_id(&**loader as &dyn Any);
+            for extension in loader.extensions() {
+                let type_ids = map.entry(extension.to_lowercase()).or_default();
+                if !type_ids.contains(&type_id) {
+                    type_ids.push(type_id);
+                }
+            }
+        }
+        map.into_iter()
+            .filter(|(_, type_ids)| type_ids.len() > 1)
+            .collect()
+    }
+
+    /// Tries to find a loader, that recognizes a file by its first bytes. See
+    /// [`ResourceLoader::probe`] for more info.
+    pub fn loader_for_bytes(&self, bytes: &[u8]) -> Option<&dyn ResourceLoader> {
+        self.loaders
+            .iter()
+            .find(|loader| loader.probe(bytes))
+            .map(|l| &**l)
     }
 }
 
@@ -332,6 +454,80 @@ mod test {
         }
     }
 
+    struct PngLoader;
+
+    impl ResourceLoader for PngLoader {
+        fn extensions(&self) -> &[&str] {
+            &["png", "jpg"]
+        }
+
+        fn data_type_uuid(&self) -> Uuid {
+            Default::default()
+        }
+
+        fn load(&self, _path: PathBuf, _io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture {
+            todo!()
+        }
+    }
+
+    struct OtherPngLoader;
+
+    impl ResourceLoader for OtherPngLoader {
+        fn extensions(&self) -> &[&str] {
+            &["PNG", "bmp"]
+        }
+
+        fn data_type_uuid(&self) -> Uuid {
+            Default::default()
+        }
+
+        fn load(&self, _path: PathBuf, _io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn resource_loader_container_conflicting_extensions() {
+        let mut container = ResourceLoadersContainer::new();
+        container.set(PngLoader);
+        container.set(OtherPngLoader);
+        container.set(MyResourceLoader);
+
+        let is = |loader: Option<&dyn ResourceLoader>, type_id: TypeId| {
+            loader.is_some_and(|loader| Any::type_id(loader as &dyn Any) == type_id)
+        };
+
+        // The first added loader wins.
+        assert!(is(
+            container.find_loader_for_extension("png"),
+            TypeId::of::<PngLoader>()
+        ));
+        assert!(is(
+            container.find_loader_for_extension("bmp"),
+            TypeId::of::<OtherPngLoader>()
+        ));
+        assert!(is(
+            container.loader_for(Path::new("foo.Png")),
+            TypeId::of::<PngLoader>()
+        ));
+        assert!(container.find_loader_for_extension("tga").is_none());
+
+        assert_eq!(
+            container.conflicting_extensions(),
+            vec![(
+                "png".to_string(),
+                vec![TypeId::of::<PngLoader>(), TypeId::of::<OtherPngLoader>()]
+            )]
+        );
+
+        // Replacing keeps the priority.
+        container.set(PngLoader);
+        assert!(is(
+            container.find_loader_for_extension("png"),
+            TypeId::of::<PngLoader>()
+        ));
+    }
+
     #[test]
     fn resource_loader_container_new() {
         let container = ResourceLoadersContainer::new();
@@ -378,6 +574,46 @@ mod test {
         assert_eq!(res, Some(&mut MyResourceLoader));
     }
 
+    #[test]
+    fn resource_loader_container_remove() {
+        let mut container = ResourceLoadersContainer::new();
+        assert_eq!(container.remove::<MyResourceLoader>(), None);
+
+        container.set(PngLoader);
+        container.set(MyResourceLoader);
+        assert_eq!(container.len(), 2);
+
+        assert_eq!(
+            container.remove::<MyResourceLoader>(),
+            Some(MyResourceLoader)
+        );
+        assert_eq!(container.len(), 1);
+        assert_eq!(container.find::<MyResourceLoader>(), None);
+        assert!(container.find::<PngLoader>().is_some());
+    }
+
+    #[test]
+    fn resource_loader_container_remove_by_type_id() {
+        let mut container = ResourceLoadersContainer::new();
+        assert!(container
+            .remove_by_type_id(TypeId::of::<MyResourceLoader>())
+            .is_none());
+
+        container.set(PngLoader);
+        container.set(MyResourceLoader);
+        assert_eq!(
+            container.type_ids().collect::<Vec<_>>(),
+            vec![TypeId::of::<PngLoader>(), TypeId::of::<MyResourceLoader>()]
+        );
+
+        assert!(container
+            .remove_by_type_id(TypeId::of::<MyResourceLoader>())
+            .is_some());
+        assert_eq!(container.len(), 1);
+        assert_eq!(container.find::<MyResourceLoader>(), None);
+        assert!(container.find::<PngLoader>().is_some());
+    }
+
     #[test]
     fn resource_loader_container_getters() {
         let mut container = ResourceLoadersContainer::new();
@@ -28,18 +28,19 @@ use crate::{
         append_extension, err,
         futures::future::join_all,
         info,
+        instant::Instant,
         io::FileError,
         log::Log,
         notify, ok_or_continue,
         parking_lot::{Mutex, MutexGuard},
-        task::TaskPool,
+        task::{TaskPool, TaskPriority},
         watcher::FileSystemWatcher,
         SafeLock, TypeUuidProvider, Uuid,
     },
     entry::{TimedEntry, DEFAULT_RESOURCE_LIFETIME},
     event::{ResourceEvent, ResourceEventBroadcaster},
-    io::ResourceIo,
-    loader::{ResourceLoader, ResourceLoadersContainer},
+    io::{MemoryResourceIo, ResourceIo},
+    loader::{BoxedLoaderFuture, ResourceLoader, ResourceLoadersContainer, PROBE_BYTES_COUNT},
     metadata::ResourceMetadata,
     options::OPTIONS_EXTENSION,
     registry::{RegistryUpdate, ResourceRegistry, ResourceRegistryRefMut, ResourceRegistryStatus},
@@ -47,7 +48,7 @@ use crate::{
     untyped::ResourceKind,
     Resource, TypedResourceData, UntypedResource,
 };
-use fxhash::FxHashSet;
+use fxhash::{FxHashMap, FxHashSet};
 use fyrox_core::{
     futures::executor::block_on, make_relative_path, notify::Event, ok_or_return, some_or_continue,
     some_or_return,
@@ -57,7 +58,10 @@ use std::{
     io::Error,
     marker::PhantomData,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 
@@ -79,8 +83,51 @@ impl ResourceWaitContext {
         }
         true
     }
+
+    /// Returns a pair `(loaded, total)` of amounts of completely loaded resources and total amount
+    /// of resources in the context. It could be used to show a progress bar on a loading screen.
+    /// Resources that failed to load are not counted as loaded, use [`Self::failed`] to get them.
+    pub fn progress(&self) -> (usize, usize) {
+        let loaded = self.resources.iter().filter(|r| r.is_ok()).count();
+        (loaded, self.resources.len())
+    }
+
+    /// Returns a list of the resources in the context that failed to load.
+    pub fn failed(&self) -> Vec<UntypedResource> {
+        self.resources
+            .iter()
+            .filter(|r| r.is_failed_to_load())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a slice of the resources in the context.
+    pub fn resources(&self) -> &[UntypedResource] {
+        &self.resources
+    }
+
+    /// Asynchronously waits until all resources are loaded (or failed to load) and returns the
+    /// loading results in the same order as the resources in the context.
+    pub async fn wait(&self) -> Vec<Result<UntypedResource, LoadError>> {
+        join_all(self.resources.iter().cloned()).await
+    }
 }
 
+/// A callback that is called when a resource managed by the resource manager loses its last
+/// external owner. See [`ResourceManagerState::set_on_last_owner_dropped`] for more info.
+pub type LastOwnerDroppedCallback = Box<dyn Fn(&UntypedResource) + Send>;
+
+/// Priority of a resource loading task. See [`ResourceManager::request_with_priority`] for more info.
+pub type LoadPriority = TaskPriority;
+
+/// A callback that is called once when a resource finishes loading (either successfully or with
+/// an error). See [`ResourceManagerState::on_loaded`] for more info.
+pub type LoadedCallback = Box<dyn FnOnce(Result<(), LoadError>) + Send>;
+
+/// Default amount of time the file must stay unmodified before the resource manager reloads the
+/// respective resource. See [`ResourceManagerState::set_reload_debounce_interval`] for more info.
+pub const DEFAULT_RELOAD_DEBOUNCE_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Internal state of the resource manager.
 pub struct ResourceManagerState {
     /// A set of resource loaders. Use this field to register your own resource loader.
@@ -100,6 +147,13 @@ pub struct ResourceManagerState {
     resources: Vec<TimedEntry<UntypedResource>>,
     task_pool: Arc<TaskPool>,
     watcher: Option<FileSystemWatcher>,
+    on_last_owner_dropped: Option<LastOwnerDroppedCallback>,
+    loaded_callbacks: Vec<(UntypedResource, LoadedCallback)>,
+    memory_budget: Option<usize>,
+    access_clock: AtomicU64,
+    redirects: FxHashMap<PathBuf, PathBuf>,
+    reload_debounce_interval: Duration,
+    pending_reloads: FxHashMap<PathBuf, Instant>,
 }
 
 /// Resource manager controls loading and lifetime of resource in the engine. Resource manager can hold
@@ -140,6 +194,8 @@ pub enum ResourceRegistrationError {
     UnableToCreateMetadata,
 }
 
+impl std::error::Error for ResourceRegistrationError {}
+
 impl Display for ResourceRegistrationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -449,7 +505,7 @@ impl ResourceManager {
             let has_loader_for_extension = state
                 .loaders
                 .safe_lock()
-                .is_extension_matches_type::<T>(path.as_ref());
+                .is_extension_matches_type::<T>(&state.resolve_redirects(path.as_ref()));
 
             if !has_loader_for_extension {
                 return None;
@@ -491,7 +547,7 @@ impl ResourceManager {
             let has_loader_for_extension = state
                 .loaders
                 .safe_lock()
-                .is_extension_matches_type::<T>(path);
+                .is_extension_matches_type::<T>(&state.resolve_redirects(path));
 
             if !has_loader_for_extension {
                 panic!(
@@ -581,13 +637,32 @@ impl ResourceManager {
     /// This method will panic, if type UUID of `T` does not match the actual type UUID of the resource. If this
     /// is undesirable, use [`Self::try_request`] instead.
     pub fn request<T>(&self, path: impl AsRef<Path>) -> Resource<T>
+    where
+        T: TypedResourceData,
+    {
+        self.request_with_priority(path, LoadPriority::Normal)
+    }
+
+    /// The same as [`Self::request`], but allows to specify the priority of the loading task. When
+    /// there are many resources waiting to be loaded, the ones with higher priority are loaded first.
+    /// For example, a skybox visible to the player could be requested with [`LoadPriority::High`]
+    /// priority, while background props could use [`LoadPriority::Low`] priority.
+    ///
+    /// ## Panic
+    ///
+    /// This method will panic, if type UUID of `T` does not match the actual type UUID of the resource.
+    pub fn request_with_priority<T>(
+        &self,
+        path: impl AsRef<Path>,
+        priority: LoadPriority,
+    ) -> Resource<T>
     where
         T: TypedResourceData,
     {
         let path = path.as_ref();
         let mut state = self.state();
 
-        let untyped = state.request(path);
+        let untyped = state.request_with_priority(path, priority);
 
         let data_type_uuid_matches = untyped
             .type_uuid_non_blocking()
@@ -597,7 +672,7 @@ impl ResourceManager {
             let has_loader_for_extension = state
                 .loaders
                 .safe_lock()
-                .is_extension_matches_type::<T>(path);
+                .is_extension_matches_type::<T>(&state.resolve_redirects(path));
 
             if !has_loader_for_extension {
                 panic!(
@@ -615,6 +690,46 @@ impl ResourceManager {
         }
     }
 
+    /// The same as [`Self::request`], but blocks the current thread until the resource is fully loaded
+    /// (or failed to load). Useful for tools and tests. Returns the loading error if the resource ended
+    /// up in [`ResourceState::LoadError`] state.
+    ///
+    /// ## Platform-specific
+    ///
+    /// WebAssembly does not allow blocking the main thread, so this method always returns an error
+    /// on this platform. Use `.await` on the result of [`Self::request`] instead.
+    ///
+    /// ## Panic
+    ///
+    /// This method will panic, if type UUID of `T` does not match the actual type UUID of the resource.
+    pub fn request_blocking<T>(&self, path: impl AsRef<Path>) -> Result<Resource<T>, LoadError>
+    where
+        T: TypedResourceData,
+    {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = path;
+            Err(LoadError::new(
+                "Blocking resource requests are not supported on WebAssembly!",
+            ))
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            block_on(self.request::<T>(path))
+        }
+    }
+
+    /// Registers a callback, that will be called once when the given resource finishes loading
+    /// (either successfully or with an error). See [`ResourceManagerState::on_loaded`] for more
+    /// info.
+    pub fn on_loaded<F>(&self, resource: UntypedResource, callback: F)
+    where
+        F: FnOnce(Result<(), LoadError>) + Send + 'static,
+    {
+        self.state().on_loaded(resource, callback)
+    }
+
     /// Requests that given resource should begin loading, if not already loading or loaded.
     /// This method is non-blocking, instead it modifies the given resource and returns.
     /// Loading of the resource is managed automatically in a separate thread (or thread pool) on PC,
@@ -712,7 +827,7 @@ impl ResourceManager {
             || state
                 .loaders
                 .safe_lock()
-                .is_extension_matches_type::<T>(path.as_ref())
+                .is_extension_matches_type::<T>(&state.resolve_redirects(path.as_ref()))
         {
             Some(Resource {
                 untyped,
@@ -746,6 +861,33 @@ impl ResourceManager {
         self.state().request(path)
     }
 
+    /// Requests every resource from the given manifest of `(path, uuid)` pairs and returns a wait
+    /// context bundling the resources. The context can be polled using
+    /// [`ResourceWaitContext::is_all_loaded`] or awaited using [`ResourceWaitContext::wait`]. It
+    /// could be useful to preload all the resources used by a level at once.
+    ///
+    /// If a path is not yet registered in the resource registry, it will be registered with the
+    /// given uuid. Existing registrations are kept as is.
+    pub fn preload<P>(&self, paths: &[(P, Uuid)]) -> ResourceWaitContext
+    where
+        P: AsRef<Path>,
+    {
+        let mut state = self.state();
+        let resources = paths
+            .iter()
+            .map(|(path, uuid)| {
+                if let Ok(path) = state.resource_io.canonicalize_path(path.as_ref()) {
+                    let mut registry = state.resource_registry.safe_lock();
+                    if registry.path_to_uuid(&path).is_none() {
+                        registry.modify().register(*uuid, path);
+                    }
+                }
+                state.request(path)
+            })
+            .collect();
+        ResourceWaitContext { resources }
+    }
+
     /// Tries to update the registry if possible on the current platform, and if not - try to load
     /// an existing one. Some platforms do not have a file system, so the registry must be prepared
     /// on a platform that **does** have it and then saved to be loaded later on. For example,
@@ -924,6 +1066,13 @@ impl ResourceManagerState {
             resource_registry: Arc::new(Mutex::new(ResourceRegistry::new(io.clone()))),
             task_pool,
             resource_io: io,
+            on_last_owner_dropped: None,
+            loaded_callbacks: Default::default(),
+            memory_budget: None,
+            access_clock: Default::default(),
+            redirects: Default::default(),
+            reload_debounce_interval: DEFAULT_RELOAD_DEBOUNCE_INTERVAL,
+            pending_reloads: Default::default(),
         }
     }
 
@@ -1018,6 +1167,20 @@ impl ResourceManagerState {
         self.watcher = watcher;
     }
 
+    /// Sets the amount of time a modified file must stay unmodified before the respective resource
+    /// is reloaded. Some editors emit multiple modification events for a single save, this interval
+    /// allows coalescing such events into a single reload and prevents reloading half-written
+    /// files. Default value is [`DEFAULT_RELOAD_DEBOUNCE_INTERVAL`].
+    pub fn set_reload_debounce_interval(&mut self, interval: Duration) {
+        self.reload_debounce_interval = interval;
+    }
+
+    /// Returns current reload debounce interval. See [`Self::set_reload_debounce_interval`] for
+    /// more info.
+    pub fn reload_debounce_interval(&self) -> Duration {
+        self.reload_debounce_interval
+    }
+
     /// Returns total amount of registered resources.
     pub fn count_registered_resources(&self) -> usize {
         self.resources.len()
@@ -1028,12 +1191,19 @@ impl ResourceManagerState {
     /// only on external resources, or if your game doing some heavy calculations this value
     /// can be combined with progress of your tasks.
     pub fn loading_progress(&self) -> usize {
-        let registered = self.count_registered_resources();
-        if registered > 0 {
-            self.count_loaded_resources() * 100 / registered
-        } else {
-            100
-        }
+        let (loaded, registered) = self.loading_counters();
+        (loaded * 100).checked_div(registered).unwrap_or(100)
+    }
+
+    /// Returns a pair `(loaded, registered)` of amounts of completely loaded resources and total
+    /// amount of registered resources. It could be used to show raw loading progress, for example
+    /// "12 / 40 assets". Resources that failed to load are not counted as loaded, use
+    /// [`Self::count_failed_resources`] to get their amount.
+    pub fn loading_counters(&self) -> (usize, usize) {
+        (
+            self.count_loaded_resources(),
+            self.count_registered_resources(),
+        )
     }
 
     fn try_get_event(&self) -> Option<Event> {
@@ -1043,8 +1213,10 @@ impl ResourceManagerState {
     /// Handle events in the file system relating to adding, removing, or modifying resources.
     /// This may involve updating the registry to reflect changes to the resources, and it may
     /// involve creating new meta files for resources that are missing meta files.
+    ///
+    /// Modified files are reloaded only after they stay unmodified for the reload debounce interval
+    /// (see [`Self::set_reload_debounce_interval`]), so this method should be called periodically.
     pub fn process_filesystem_events(&mut self) {
-        let mut modified_files = FxHashSet::default();
         while let Some(mut evt) = self.try_get_event() {
             if evt.need_rescan() {
                 info!("Filesystem watcher has forced a rescan!");
@@ -1068,8 +1240,9 @@ impl ResourceManagerState {
                         self.on_create_event(evt.paths.get(1));
                     }
                     EventKind::Modify(ModifyKind::Any | ModifyKind::Data(_)) => {
-                        let path = evt.paths.get_mut(0).map(std::mem::take);
-                        modified_files.insert(path);
+                        if let Some(path) = evt.paths.get_mut(0).map(std::mem::take) {
+                            self.on_modify_event(path, Instant::now());
+                        }
                     }
                     EventKind::Remove(RemoveKind::Any | RemoveKind::File) => {
                         self.on_remove_event(evt.paths.first())
@@ -1078,8 +1251,25 @@ impl ResourceManagerState {
                 }
             }
         }
+        self.flush_pending_reloads(Instant::now());
+    }
+
+    fn on_modify_event(&mut self, path: PathBuf, now: Instant) {
+        // Every new event for the same path restarts the countdown.
+        self.pending_reloads.insert(path, now);
+    }
+
+    fn flush_pending_reloads(&mut self, now: Instant) {
+        let interval = self.reload_debounce_interval;
+        let modified_files = self
+            .pending_reloads
+            .iter()
+            .filter(|(_, last_modified)| now.duration_since(**last_modified) >= interval)
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
         for path in modified_files {
-            self.on_file_content_event(path.as_ref())
+            self.pending_reloads.remove(&path);
+            self.on_file_content_event(Some(&path))
         }
     }
 
@@ -1178,10 +1368,6 @@ impl ResourceManagerState {
         let path = some_or_return!(path);
         let mut relative_path = ok_or_return!(fyrox_core::make_relative_path(path));
         let ext = some_or_return!(relative_path.extension());
-        // For the purposes of updating the registry, only the removal of meta files is relevant.
-        if ext != ResourceMetadata::EXTENSION {
-            return;
-        }
         // An event for a deleted file does not guarantee that the file does not exist.
         // It might have been created again between being deleted and us receiving the event.
         // If the file exists now, ignore the remove event.
@@ -1197,6 +1383,19 @@ impl ResourceManagerState {
         {
             return;
         }
+        // For the purposes of updating the registry, only the removal of meta files is relevant.
+        // Removal of a resource file is only reported to the subscribers. The resource itself
+        // keeps its current state, because many editors save files by deleting and re-creating
+        // them, and the following create event will reload the resource.
+        if ext != ResourceMetadata::EXTENSION {
+            drop(registry);
+            if self.find_by_resource_path(&relative_path).is_some() {
+                info!("File {relative_path:?} of a loaded resource was removed or renamed!");
+                self.event_broadcaster
+                    .broadcast(ResourceEvent::Removed(relative_path));
+            }
+            return;
+        }
         // Remove the meta extension from the path to get the path of the resource.
         relative_path.set_extension("");
         // Check whether the resource file exists, and if not then there is nothing more to do.
@@ -1289,6 +1488,119 @@ impl ResourceManagerState {
         }
     }
 
+    /// Sets a callback that will be called from [`Self::update`] the first time a resource loses
+    /// its last external owner (i.e. the only owner left is the resource manager itself). The
+    /// callback is called before the time-to-live countdown of the resource starts, which could be
+    /// used to perform immediate clean up of expensive resources, instead of waiting for
+    /// [`DEFAULT_RESOURCE_LIFETIME`] seconds. The callback will be called again only if the resource
+    /// gets shared and then loses its last external owner once more.
+    ///
+    /// Keep in mind, that the callback is called while the resource manager state is locked, so
+    /// any attempt to access the resource manager from the callback will result in a deadlock.
+    pub fn set_on_last_owner_dropped(&mut self, callback: Option<LastOwnerDroppedCallback>) {
+        self.on_last_owner_dropped = callback;
+    }
+
+    /// Sets the amount of memory (in bytes), that could be occupied by the loaded resources (see
+    /// [`Self::total_bytes`]). If the total size exceeds the budget, [`Self::update`] evicts unused
+    /// resources (the ones that do not have external owners) in least-recently-used order until
+    /// the total size fits the budget. Resources with infinite lifetime (see
+    /// [`Self::set_resource_lifetime`]) are never evicted. `None` (default) means that there's no
+    /// budget and unused resources are removed only when their time-to-live runs out.
+    pub fn set_memory_budget(&mut self, budget: Option<usize>) {
+        self.memory_budget = budget;
+    }
+
+    /// Returns current memory budget. See [`Self::set_memory_budget`] for more info.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    fn touch(&self, entry: &TimedEntry<UntypedResource>) {
+        let time = self.access_clock.fetch_add(1, Ordering::Relaxed) + 1;
+        entry.last_access.store(time, Ordering::Relaxed);
+    }
+
+    fn evict_over_budget(&mut self) {
+        let budget = some_or_return!(self.memory_budget);
+        let mut total_bytes = self.total_bytes();
+        if total_bytes <= budget {
+            return;
+        }
+
+        let mut candidates = self
+            .resources
+            .iter()
+            .filter(|entry| entry.value.use_count() <= 1 && entry.lifetime.is_some())
+            .filter_map(|entry| {
+                let byte_size = match entry.value.lock().state {
+                    ResourceState::Ok { ref data } => data.0.byte_size(),
+                    _ => 0,
+                };
+                (byte_size > 0).then(|| {
+                    (
+                        entry.last_access.load(Ordering::Relaxed),
+                        entry.value.resource_uuid(),
+                        byte_size,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(last_access, _, _)| *last_access);
+
+        let mut evicted = FxHashSet::default();
+        for (_, uuid, byte_size) in candidates {
+            if total_bytes <= budget {
+                break;
+            }
+            total_bytes -= byte_size;
+            evicted.insert(uuid);
+        }
+
+        let registry = self.resource_registry.safe_lock();
+        let event_broadcaster = &self.event_broadcaster;
+        self.resources.retain(|entry| {
+            let resource_uuid = entry.value.resource_uuid();
+            if !evicted.contains(&resource_uuid) {
+                return true;
+            }
+            if let Some(path) = registry.uuid_to_path(resource_uuid) {
+                info!("Resource {path:?} evicted because the memory budget was exceeded!");
+                event_broadcaster.broadcast(ResourceEvent::Removed(path.to_path_buf()));
+            }
+            false
+        });
+    }
+
+    /// Registers a callback, that will be called once when the given resource finishes loading
+    /// (either successfully or with an error). It is an alternative to `.await`ing the resource,
+    /// which could be more convenient for event-driven code. The callback is called from
+    /// [`Self::update`], even if the resource is already loaded at the moment of registration.
+    /// Resources in [`ResourceState::Unloaded`] state are considered as not loaded yet.
+    ///
+    /// Keep in mind, that the callback is called while the resource manager state is locked, so
+    /// any attempt to access the resource manager from the callback will result in a deadlock.
+    pub fn on_loaded<F>(&mut self, resource: UntypedResource, callback: F)
+    where
+        F: FnOnce(Result<(), LoadError>) + Send + 'static,
+    {
+        self.loaded_callbacks.push((resource, Box::new(callback)));
+    }
+
+    fn process_loaded_callbacks(&mut self) {
+        for (resource, callback) in std::mem::take(&mut self.loaded_callbacks) {
+            let result = match resource.lock().state {
+                ResourceState::Ok { .. } => Some(Ok(())),
+                ResourceState::LoadError { ref error, .. } => Some(Err(error.clone())),
+                ResourceState::Unloaded | ResourceState::Pending { .. } => None,
+            };
+            match result {
+                Some(result) => callback(result),
+                None => self.loaded_callbacks.push((resource, callback)),
+            }
+        }
+    }
+
     /// Update resource containers and do hot-reloading.
     ///
     /// Resources are removed if they're not used
@@ -1297,11 +1609,25 @@ impl ResourceManagerState {
     /// Normally, this is called from `Engine::update()`.
     /// You should only call this manually if you don't use that method.
     pub fn update(&mut self, dt: f32) {
+        self.process_loaded_callbacks();
+
         self.resources.retain_mut(|resource| {
             // One usage means that the resource has single owner, and that owner
             // is this container. Such resources have limited life time, if the time
             // runs out before it gets shared again, the resource will be deleted.
             if resource.value.use_count() <= 1 {
+                if !resource.orphaned {
+                    resource.orphaned = true;
+                    if let Some(callback) = self.on_last_owner_dropped.as_ref() {
+                        callback(&resource.value);
+                    }
+                }
+
+                if resource.lifetime.is_none() {
+                    // Pinned resources are never removed.
+                    return true;
+                }
+
                 resource.time_to_live -= dt;
                 if resource.time_to_live <= 0.0 {
                     let registry = self.resource_registry.safe_lock();
@@ -1319,25 +1645,56 @@ impl ResourceManagerState {
                 }
             } else {
                 // Make sure to reset timer if a resource is used by more than one owner.
-                resource.time_to_live = DEFAULT_RESOURCE_LIFETIME;
+                resource.time_to_live = resource.lifetime.unwrap_or(DEFAULT_RESOURCE_LIFETIME);
+                resource.orphaned = false;
 
                 // Keep resource alive while it has more than one owner.
                 true
             }
         });
+
+        self.evict_over_budget();
     }
 
     fn add_resource_and_notify(&mut self, resource: UntypedResource) {
         self.event_broadcaster
             .broadcast(ResourceEvent::Added(resource.clone()));
 
-        self.resources.push(TimedEntry {
+        let entry = TimedEntry {
             value: resource,
             time_to_live: DEFAULT_RESOURCE_LIFETIME,
-        });
+            lifetime: Some(DEFAULT_RESOURCE_LIFETIME),
+            orphaned: false,
+            last_access: Default::default(),
+        };
+        self.touch(&entry);
+        self.resources.push(entry);
+    }
+
+    /// Overrides the lifetime of the given resource, which defines how long (in seconds) the resource
+    /// is kept alive after it lost its last external owner (see [`DEFAULT_RESOURCE_LIFETIME`]). `None`
+    /// means that the resource is never removed from the resource manager, which is useful for
+    /// resources that are used all the time, but not always have external owners (for example, a
+    /// shared shader). Returns `false` if the resource is not managed by the resource manager.
+    pub fn set_resource_lifetime(&mut self, resource: &UntypedResource, ttl: Option<f32>) -> bool {
+        match self
+            .resources
+            .iter_mut()
+            .find(|entry| &entry.value == resource)
+        {
+            Some(entry) => {
+                entry.lifetime = ttl;
+                if let Some(ttl) = ttl {
+                    entry.time_to_live = ttl;
+                }
+                true
+            }
+            None => false,
+        }
     }
 
-    /// Tries to find a resource by its path. Returns None if no resource was found.
+    /// Tries to find a resource by its UUID. Returns None if no resource was found. Unlike paths,
+    /// UUIDs stay the same when resource files are moved or renamed.
     ///
     /// # Complexity
     ///
@@ -1346,18 +1703,28 @@ impl ResourceManagerState {
         self.resources
             .iter()
             .find(|entry| entry.value.resource_uuid() == uuid)
-            .map(|entry| &entry.value)
+            .map(|entry| {
+                self.touch(entry);
+                &entry.value
+            })
     }
 
-    /// Tries to find a resource by a path. Returns None if no resource was found.
+    /// Tries to find a resource by a path. Returns None if no resource was found. The path is
+    /// canonicalized first, so logically identical paths (for example, `./foo.png` and `foo.png`)
+    /// will point to the same resource.
     ///
     /// # Complexity
     ///
     /// O(n)
     pub fn find_by_path(&self, path: &Path) -> Option<&UntypedResource> {
+        let path = self
+            .resource_io
+            .canonicalize_path(path)
+            .unwrap_or_else(|_| path.to_path_buf());
         let registry = self.resource_registry.safe_lock();
         self.resources.iter().find_map(|entry| {
-            if registry.uuid_to_path(entry.resource_uuid()) == Some(path) {
+            if registry.uuid_to_path(entry.resource_uuid()) == Some(path.as_path()) {
+                self.touch(entry);
                 return Some(&entry.value);
             }
             None
@@ -1379,6 +1746,24 @@ impl ResourceManagerState {
         self.resources.iter().map(|entry| &entry.value)
     }
 
+    /// Creates an iterator over the resources in the manager, that have the given data type UUID.
+    /// Only fully loaded resources (in [`ResourceState::Ok`] state) are yielded, because the type
+    /// of resource data is unknown until it is loaded.
+    pub fn iter_of_type(&self, type_uuid: Uuid) -> impl Iterator<Item = &UntypedResource> {
+        self.iter()
+            .filter(move |resource| resource.type_uuid() == Some(type_uuid))
+    }
+
+    /// Returns all the loaded resources of the given type. See [`Self::iter_of_type`] for more info.
+    pub fn resources_of<T>(&self) -> Vec<Resource<T>>
+    where
+        T: TypedResourceData,
+    {
+        self.iter()
+            .filter_map(|resource| resource.try_cast::<T>())
+            .collect()
+    }
+
     /// Immediately destroys all resources in the manager that are not used anywhere else.
     pub fn destroy_unused_resources(&mut self) {
         self.resources
@@ -1395,6 +1780,41 @@ impl ResourceManagerState {
         self.resources.iter().filter(|r| r.is_ok()).count()
     }
 
+    /// Returns total amount of memory (in bytes) occupied by the data of fully loaded resources. See
+    /// [`crate::ResourceData::byte_size`] for more info.
+    pub fn total_bytes(&self) -> usize {
+        self.resources
+            .iter()
+            .map(|r| match r.value.lock().state {
+                ResourceState::Ok { ref data } => data.0.byte_size(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Returns total amount of resources that failed to load.
+    pub fn count_failed_resources(&self) -> usize {
+        self.resources
+            .iter()
+            .filter(|r| r.is_failed_to_load())
+            .count()
+    }
+
+    /// Returns the path and the error message (if any) of every resource that failed to load. It
+    /// could be used to show a list of broken resources to a user.
+    pub fn failed_resources(&self) -> Vec<(PathBuf, Option<String>)> {
+        self.resources
+            .iter()
+            .filter_map(|r| match r.value.lock().state {
+                ResourceState::LoadError {
+                    ref path,
+                    ref error,
+                } => Some((path.clone(), error.0.as_ref().map(|e| e.to_string()))),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Returns a set of resource handled by this container.
     pub fn resources(&self) -> Vec<UntypedResource> {
         self.resources.iter().map(|t| t.value.clone()).collect()
@@ -1427,6 +1847,47 @@ impl ResourceManagerState {
         }
     }
 
+    /// Adds a redirect from one path to another, so any request (or search) of a resource at `from`
+    /// path will be resolved to a resource at `to` path. Both paths will share the same resource
+    /// instance. It could be useful for content packs and modding. Redirects can be chained
+    /// (`a -> b -> c`), loops are detected and reported to the log. Returns the previous target of
+    /// the redirect (if any).
+    pub fn add_redirect(&mut self, from: PathBuf, to: PathBuf) -> Option<PathBuf> {
+        let from = self.resource_io.canonicalize_path(&from).unwrap_or(from);
+        self.redirects.insert(from, to)
+    }
+
+    /// Removes a redirect, that was previously added by [`Self::add_redirect`]. Returns the target
+    /// of the redirect (if any).
+    pub fn remove_redirect(&mut self, from: &Path) -> Option<PathBuf> {
+        let from = self
+            .resource_io
+            .canonicalize_path(from)
+            .unwrap_or_else(|_| from.to_path_buf());
+        self.redirects.remove(&from)
+    }
+
+    /// Follows the redirects (see [`Self::add_redirect`]) starting from the given path and returns
+    /// the final path. The path is returned as is, if there's no redirect for it.
+    pub fn resolve_redirects(&self, path: &Path) -> PathBuf {
+        let mut current = path.to_path_buf();
+        let mut visited = FxHashSet::default();
+        loop {
+            let key = self
+                .resource_io
+                .canonicalize_path(&current)
+                .unwrap_or_else(|_| current.clone());
+            let Some(target) = self.redirects.get(&key) else {
+                return current;
+            };
+            if !visited.insert(key) {
+                err!("A loop was detected when resolving redirects of {path:?} path!");
+                return current;
+            }
+            current = target.clone();
+        }
+    }
+
     /// Searches the resource manager and the registry to find a resource with the given path,
     /// including built-in resources. If no resource is found, a new UUID is generated and the
     /// path is added to the registry and an unloaded resource is returned.
@@ -1439,7 +1900,8 @@ impl ResourceManagerState {
     where
         P: AsRef<Path>,
     {
-        let path = path.as_ref();
+        let path = self.resolve_redirects(path.as_ref());
+        let path = path.as_path();
         if let Some(built_in_resource) = self.built_in_resources.get(path) {
             return built_in_resource.resource.clone();
         }
@@ -1474,14 +1936,31 @@ impl ResourceManagerState {
     where
         P: AsRef<Path>,
     {
-        let path = path.as_ref();
+        self.request_with_priority(path, LoadPriority::Normal)
+    }
+
+    /// The same as [`Self::request`], but allows to specify the priority of the loading task. When
+    /// there are many resources waiting to be loaded, the ones with higher priority are loaded first.
+    /// The priority affects only new loading tasks, it does not change the priority of a resource
+    /// that is already loading.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is invalid, such as if it includes a directory that does not exist
+    /// or contains invalid characters.
+    pub fn request_with_priority<P>(&mut self, path: P, priority: LoadPriority) -> UntypedResource
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.resolve_redirects(path.as_ref());
+        let path = path.as_path();
         if let Some(built_in_resource) = self.built_in_resources.get(path) {
             return built_in_resource.resource.clone();
         }
 
         let path = self.resource_io.canonicalize_path(path).unwrap();
 
-        self.find_or_load(path)
+        self.find_or_load(path, priority)
     }
 
     /// Tries to load the resource for the given UUID.
@@ -1497,7 +1976,10 @@ impl ResourceManagerState {
         self.resources
             .iter()
             .find(move |entry| registry.uuid_to_path(entry.resource_uuid()) == Some(path_to_search))
-            .map(|entry| &entry.value)
+            .map(|entry| {
+                self.touch(entry);
+                &entry.value
+            })
     }
 
     /// If a resource exists for the given path, return it.
@@ -1505,14 +1987,14 @@ impl ResourceManagerState {
     /// a resource, begin loading, and return the resource.
     /// If the given path does not correspond to any registered UUID,
     /// create and return an error resource.
-    fn find_or_load(&mut self, path: PathBuf) -> UntypedResource {
+    fn find_or_load(&mut self, path: PathBuf, priority: LoadPriority) -> UntypedResource {
         match self.find_by_resource_path(&path) {
             Some(existing) => existing.clone(),
-            None => self.load_resource(path),
+            None => self.load_resource(path, priority),
         }
     }
 
-    fn load_resource(&mut self, path: PathBuf) -> UntypedResource {
+    fn load_resource(&mut self, path: PathBuf, priority: LoadPriority) -> UntypedResource {
         let mut registry = self.resource_registry.safe_lock();
         let uuid = if let Some(uuid) = registry.path_to_uuid(&path) {
             uuid
@@ -1524,20 +2006,107 @@ impl ResourceManagerState {
         drop(registry);
         let resource = UntypedResource::new_pending(uuid, ResourceKind::External);
         self.add_resource_and_notify(resource.clone());
-        self.spawn_loading_task(resource.clone(), false);
+        self.spawn_loading_task_with_priority(resource.clone(), false, priority);
         resource
     }
 
+    /// Loads a resource from the given raw bytes using the loader of the given type `L` and registers
+    /// the result under the given `virtual_path`. The loader must be registered in the resource
+    /// manager, otherwise the returned resource will be in the error state. It could be useful for
+    /// tests or for resources delivered over network, that does not exist in the file system.
+    ///
+    /// The loader has access only to the given bytes, any attempt to read other files (for example,
+    /// dependencies of the resource) will fail. If there's already a resource registered under the
+    /// given path, its data will be replaced with the new one.
+    pub fn load_from_bytes<L>(&mut self, bytes: Vec<u8>, virtual_path: PathBuf) -> UntypedResource
+    where
+        L: ResourceLoader,
+    {
+        let virtual_path = self
+            .resource_io
+            .canonicalize_path(&virtual_path)
+            .unwrap_or(virtual_path);
+        let io = Arc::new(MemoryResourceIo::new(virtual_path.clone(), bytes));
+        let loader_future = self
+            .loaders
+            .safe_lock()
+            .find::<L>()
+            .map(|loader| loader.load(virtual_path.clone(), io));
+
+        let mut registry = self.resource_registry.safe_lock();
+        let uuid = if let Some(uuid) = registry.path_to_uuid(&virtual_path) {
+            uuid
+        } else {
+            let uuid = Uuid::new_v4();
+            registry.modify().register(uuid, virtual_path.clone());
+            uuid
+        };
+        drop(registry);
+
+        let mut resource = if let Some(existing) = self.find_by_uuid(uuid) {
+            let mut existing = existing.clone();
+            existing.make_pending();
+            existing
+        } else {
+            let resource = UntypedResource::new_pending(uuid, ResourceKind::External);
+            self.add_resource_and_notify(resource.clone());
+            resource
+        };
+
+        let Some(loader_future) = loader_future else {
+            let error = format!(
+                "There's no resource loader of type {} to load {virtual_path:?} resource!",
+                std::any::type_name::<L>()
+            );
+            resource.commit_error(virtual_path, error);
+            return resource;
+        };
+
+        let event_broadcaster = self.event_broadcaster.clone();
+        let result = resource.clone();
+        self.task_pool.spawn_task(async move {
+            match loader_future.await {
+                Ok(data) => {
+                    resource.commit(ResourceState::Ok {
+                        data: ResourceDataWrapper(data.0),
+                    });
+
+                    event_broadcaster.broadcast_loaded_or_reloaded(resource, false);
+
+                    Log::info(format!(
+                        "Resource {} was loaded successfully from memory!",
+                        virtual_path.display()
+                    ));
+                }
+                Err(error) => {
+                    info!("Resource {virtual_path:?} failed to load from memory. Reason: {error}");
+                    resource.commit_error(virtual_path, error);
+                }
+            }
+        });
+
+        result
+    }
+
     /// Add a task to the task pool to load the given resource.
     /// Panic if the given resource is unregistered or embedded.
-    fn spawn_loading_task(&self, mut resource: UntypedResource, reload: bool) {
+    fn spawn_loading_task(&self, resource: UntypedResource, reload: bool) {
+        self.spawn_loading_task_with_priority(resource, reload, LoadPriority::Normal)
+    }
+
+    fn spawn_loading_task_with_priority(
+        &self,
+        mut resource: UntypedResource,
+        reload: bool,
+        priority: LoadPriority,
+    ) {
         let event_broadcaster = self.event_broadcaster.clone();
         let loaders = self.loaders.clone();
         let registry = self.resource_registry.clone();
         let io = self.resource_io.clone();
         let registry_status = registry.safe_lock().status_flag();
 
-        self.task_pool.spawn_task(async move {
+        let task = async move {
             // Wait until the registry is fully loaded.
             let registry_status = registry_status.await;
 
@@ -1564,10 +2133,39 @@ impl ResourceManagerState {
             };
 
             // Try to find a loader for the resource.
-            let loader_future = loaders
-                .safe_lock()
-                .loader_for(&path)
-                .map(|loader| loader.load(path.clone(), io));
+            let byte_transform = loaders.safe_lock().byte_transform_for(&path);
+            let mut loader_future = if let Some(byte_transform) = byte_transform {
+                match io.load_file(&path).await {
+                    Ok(bytes) => {
+                        let bytes = byte_transform(&path, bytes);
+                        let memory_io = Arc::new(MemoryResourceIo::new(path.clone(), bytes));
+                        loaders
+                            .safe_lock()
+                            .loader_for(&path)
+                            .map(|loader| loader.load(path.clone(), memory_io))
+                    }
+                    Err(err) => {
+                        let future: BoxedLoaderFuture =
+                            Box::pin(async move { Err(LoadError::new(err)) });
+                        Some(future)
+                    }
+                }
+            } else {
+                loaders
+                    .safe_lock()
+                    .loader_for(&path)
+                    .map(|loader| loader.load(path.clone(), io.clone()))
+            };
+
+            // If there's no loader for the extension, try to recognize the file by its content.
+            if loader_future.is_none() {
+                if let Some(bytes) = read_probe_bytes(&*io, &path).await {
+                    loader_future = loaders
+                        .safe_lock()
+                        .loader_for_bytes(&bytes)
+                        .map(|loader| loader.load(path.clone(), io));
+                }
+            }
 
             if let Some(loader_future) = loader_future {
                 match loader_future.await {
@@ -1595,25 +2193,30 @@ impl ResourceManagerState {
                         if reload {
                             if resource.is_ok() {
                                 info!("Resource {path:?} failed to reload, keeping the existing version. Reason: {error}");
-                            }
-                            else
-                            {
+                            } else {
                                 info!("Resource {path:?} failed to reload. Reason: {error}");
                                 resource.commit_error(path.to_path_buf(), error);
                             }
-                        }
-                        else
-                        {
+                        } else {
                             info!("Resource {path:?} failed to load. Reason: {error}");
                             resource.commit_error(path.to_path_buf(), error);
                         }
                     }
                 }
             } else {
-                let error = format!("There's no resource loader for {path:?} resource!",);
+                let error = match path.extension() {
+                    Some(extension) => format!(
+                        "There's no resource loader for extension '{}' of {path:?} resource!",
+                        extension.to_string_lossy()
+                    ),
+                    None => format!("There's no resource loader for {path:?} resource!"),
+                };
+                err!("{error}");
                 resource.commit_error(path, error);
             }
-        });
+        };
+
+        self.task_pool.spawn_task_with_priority(task, priority);
     }
 
     /// Tries to fetch a path of the given untyped resource. The path may be missing in a few cases:
@@ -1776,6 +2379,24 @@ impl ResourceManagerState {
         resources
     }
 
+    /// Tries to load again every resource that failed to load previously. Returns a list of resources
+    /// that will be reloaded. You can use the list to wait until all resources are loading. Unlike
+    /// [`Self::reload_resources`], it does not touch resources that are loaded or still loading.
+    pub fn retry_failed(&mut self) -> Vec<UntypedResource> {
+        let resources = self
+            .resources
+            .iter()
+            .filter(|r| r.value.is_failed_to_load())
+            .map(|r| r.value.clone())
+            .collect::<Vec<_>>();
+
+        for resource in resources.iter().cloned() {
+            self.reload_resource(resource);
+        }
+
+        resources
+    }
+
     /// Wait until all resources are loaded (or failed to load).
     pub fn get_wait_context(&self) -> ResourceWaitContext {
         ResourceWaitContext {
@@ -2041,6 +2662,21 @@ impl ResourceManagerState {
     }
 }
 
+/// Reads first [`PROBE_BYTES_COUNT`] bytes of the file at the given path (or less, if the file is
+/// smaller).
+async fn read_probe_bytes(io: &dyn ResourceIo, path: &Path) -> Option<Vec<u8>> {
+    use std::io::Read;
+
+    let mut reader = io.file_reader(path).await.ok()?;
+    let mut bytes = Vec::with_capacity(PROBE_BYTES_COUNT);
+    reader
+        .by_ref()
+        .take(PROBE_BYTES_COUNT as u64)
+        .read_to_end(&mut bytes)
+        .ok()?;
+    Some(bytes)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -2055,7 +2691,12 @@ mod test {
         visitor::{Visit, VisitResult, Visitor},
         TypeUuidProvider,
     };
-    use std::{error::Error, fs::File, time::Duration};
+    use std::{
+        error::Error,
+        fs::File,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
 
     #[derive(Debug, Default, Clone, Reflect, Visit)]
     struct Stub {}
@@ -2098,15 +2739,46 @@ mod test {
         }
     }
 
-    fn new_resource_manager() -> ResourceManagerState {
-        ResourceManagerState::new(Arc::new(FsResourceIo), Arc::new(Default::default()))
+    #[derive(Debug, Default, Clone, Reflect, Visit)]
+    struct OtherStub {}
+
+    impl TypeUuidProvider for OtherStub {
+        fn type_uuid() -> Uuid {
+            uuid!("3b1a7a0e-5d0c-4d8e-9c6b-2f2d1c6a8e41")
+        }
     }
 
-    fn remove_file_if_exists(path: &Path) -> std::io::Result<()> {
-        match std::fs::remove_file(path) {
-            Ok(()) => Ok(()),
-            Err(e) => match e.kind() {
-                std::io::ErrorKind::NotFound => Ok(()),
+    impl ResourceData for OtherStub {
+        fn type_uuid(&self) -> Uuid {
+            <Self as TypeUuidProvider>::type_uuid()
+        }
+
+        fn save(&mut self, _path: &Path) -> Result<(), Box<dyn Error>> {
+            Err("Saving is not supported!".to_string().into())
+        }
+
+        fn can_be_saved(&self) -> bool {
+            false
+        }
+
+        fn try_clone_box(&self) -> Option<Box<dyn ResourceData>> {
+            Some(Box::new(self.clone()))
+        }
+
+        fn byte_size(&self) -> usize {
+            128
+        }
+    }
+
+    fn new_resource_manager() -> ResourceManagerState {
+        ResourceManagerState::new(Arc::new(FsResourceIo), Arc::new(Default::default()))
+    }
+
+    fn remove_file_if_exists(path: &Path) -> std::io::Result<()> {
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(e) => match e.kind() {
+                std::io::ErrorKind::NotFound => Ok(()),
                 _ => Err(e),
             },
         }
@@ -2116,9 +2788,10 @@ mod test {
     fn resource_wait_context_is_all_loaded() {
         assert!(ResourceWaitContext::default().is_all_loaded());
 
+        let pending = UntypedResource::new_pending(Default::default(), ResourceKind::External);
         let cx = ResourceWaitContext {
             resources: vec![
-                UntypedResource::new_pending(Default::default(), ResourceKind::External),
+                pending.clone(),
                 UntypedResource::new_load_error(
                     ResourceKind::External,
                     Default::default(),
@@ -2127,6 +2800,36 @@ mod test {
             ],
         };
         assert!(!cx.is_all_loaded());
+
+        pending.commit_ok(Stub {});
+        assert!(cx.is_all_loaded());
+    }
+
+    #[test]
+    fn resource_wait_context_progress() {
+        let cx = ResourceWaitContext::default();
+        assert_eq!(cx.progress(), (0, 0));
+        assert!(cx.failed().is_empty());
+
+        let pending = UntypedResource::new_pending(Default::default(), ResourceKind::External);
+        let failed = UntypedResource::new_load_error(
+            ResourceKind::External,
+            Default::default(),
+            LoadError::default(),
+        );
+        let cx = ResourceWaitContext {
+            resources: vec![
+                pending.clone(),
+                UntypedResource::new_ok(Uuid::new_v4(), Default::default(), Stub {}),
+                failed.clone(),
+            ],
+        };
+        assert_eq!(cx.progress(), (1, 3));
+        assert_eq!(cx.failed(), vec![failed.clone()]);
+
+        pending.commit_ok(Stub {});
+        assert_eq!(cx.progress(), (2, 3));
+        assert_eq!(cx.failed(), vec![failed]);
     }
 
     #[test]
@@ -2141,6 +2844,74 @@ mod test {
         assert!(state.is_empty());
     }
 
+    #[test]
+    fn resource_manager_state_failed_resources() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let mut state = new_resource_manager();
+        assert!(state.failed_resources().is_empty());
+
+        assert!(state
+            .register(
+                UntypedResource::new_ok(Uuid::new_v4(), Default::default(), Stub {}),
+                "ok.bar",
+            )
+            .is_ok());
+        assert!(state
+            .register(
+                UntypedResource::new_load_error(
+                    ResourceKind::External,
+                    PathBuf::from("broken.bar"),
+                    LoadError::new("Broken")
+                ),
+                "broken.bar",
+            )
+            .is_ok());
+
+        assert_eq!(
+            state.failed_resources(),
+            vec![(PathBuf::from("broken.bar"), Some("Broken".to_string()))]
+        );
+    }
+
+    #[test]
+    fn resource_manager_state_retry_failed() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let mut state = new_resource_manager();
+        // Keep the loading tasks waiting for the registry, so the resources stay in pending state.
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loading();
+
+        let ok = UntypedResource::new_ok(Uuid::new_v4(), Default::default(), Stub {});
+        assert!(state.register(ok.clone(), "ok.bar").is_ok());
+        let failed = UntypedResource::new_load_error(
+            ResourceKind::External,
+            PathBuf::from("broken.bar"),
+            LoadError::new("Broken"),
+        );
+        assert!(state.register(failed.clone(), "broken.bar").is_ok());
+
+        let retried = state.retry_failed();
+        assert_eq!(retried, vec![failed.clone()]);
+        assert!(failed.is_loading());
+        assert!(ok.is_ok());
+    }
+
+    #[test]
+    fn resource_manager_state_find_by_uuid() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let mut state = new_resource_manager();
+
+        let uuid = Uuid::new_v4();
+        let resource = UntypedResource::new_ok(uuid, Default::default(), Stub {});
+        assert!(state.register(resource.clone(), "find_by_uuid.bar").is_ok());
+
+        assert_eq!(state.find_by_uuid(uuid), Some(&resource));
+        assert!(state.find_by_uuid(Uuid::new_v4()).is_none());
+    }
+
     #[test]
     fn resource_manager_state_set_watcher() {
         let mut state = new_resource_manager();
@@ -2196,6 +2967,7 @@ mod test {
         let mut state = new_resource_manager();
 
         assert_eq!(state.loading_progress(), 100);
+        assert_eq!(state.loading_counters(), (0, 0));
 
         state
             .register(
@@ -2205,6 +2977,22 @@ mod test {
             .unwrap();
 
         assert_eq!(state.loading_progress(), 100);
+        assert_eq!(state.loading_counters(), (1, 1));
+
+        state
+            .register(
+                UntypedResource::new_load_error(
+                    ResourceKind::External,
+                    Default::default(),
+                    LoadError::new("Broken"),
+                ),
+                "broken.bar",
+            )
+            .unwrap();
+
+        assert_eq!(state.loading_progress(), 50);
+        assert_eq!(state.loading_counters(), (1, 2));
+        assert_eq!(state.count_failed_resources(), 1);
     }
 
     #[test]
@@ -2254,6 +3042,115 @@ mod test {
         assert_eq!(state.len(), 0);
     }
 
+    #[test]
+    fn resource_manager_state_on_last_owner_dropped() {
+        let mut state = new_resource_manager();
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        state.set_on_last_owner_dropped(Some(Box::new(move |_| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        })));
+
+        let resource = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        state.add_resource_and_notify(resource.clone());
+
+        state.update(0.0);
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+
+        drop(resource);
+        state.update(0.0);
+        state.update(0.0);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        // Share the resource again and drop it once more.
+        let resource = state.resources()[0].clone();
+        state.update(0.0);
+        drop(resource);
+        state.update(0.0);
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        assert_eq!(state.len(), 1);
+    }
+
+    #[test]
+    fn resource_manager_state_resources_of() {
+        let mut state = new_resource_manager();
+
+        let stubs = [
+            UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {}),
+            UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {}),
+        ];
+        let other = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, OtherStub {});
+        let pending = UntypedResource::new_pending(Uuid::new_v4(), ResourceKind::External);
+        state.add_resource_and_notify(stubs[0].clone());
+        state.add_resource_and_notify(other.clone());
+        state.add_resource_and_notify(pending);
+        state.add_resource_and_notify(stubs[1].clone());
+
+        let found = state
+            .iter_of_type(<Stub as TypeUuidProvider>::type_uuid())
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(found, stubs);
+
+        let found = state.resources_of::<OtherStub>();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].untyped, other);
+
+        assert_eq!(state.resources_of::<Stub>().len(), 2);
+    }
+
+    #[test]
+    fn resource_manager_state_set_resource_lifetime() {
+        let mut state = new_resource_manager();
+
+        let pinned = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        let short = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        let other = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        state.add_resource_and_notify(pinned.clone());
+        state.add_resource_and_notify(short.clone());
+
+        assert!(state.set_resource_lifetime(&pinned, None));
+        assert!(state.set_resource_lifetime(&short, Some(1.0)));
+        assert!(!state.set_resource_lifetime(&other, None));
+
+        let pinned_uuid = pinned.resource_uuid();
+        let short_uuid = short.resource_uuid();
+        drop(pinned);
+        drop(short);
+
+        state.update(0.5);
+        assert_eq!(state.len(), 2);
+
+        state.update(0.6);
+        assert_eq!(state.len(), 1);
+        assert!(state.find_by_uuid(short_uuid).is_none());
+
+        for _ in 0..10 {
+            state.update(DEFAULT_RESOURCE_LIFETIME);
+        }
+        assert_eq!(state.len(), 1);
+        assert!(state.find_by_uuid(pinned_uuid).is_some());
+    }
+
+    #[test]
+    fn resource_manager_state_load_from_bytes() {
+        let mut state = new_resource_manager();
+
+        let path = PathBuf::from("virtual/stub.txt");
+
+        // No loader is registered.
+        let resource = state.load_from_bytes::<Stub>(b"foo".to_vec(), path.clone());
+        assert!(resource.is_failed_to_load());
+
+        state.add_loader(Stub {});
+        let resource = state.load_from_bytes::<Stub>(b"foo".to_vec(), path.clone());
+        assert!(block_on(resource.clone()).is_ok());
+        assert!(resource.is_ok());
+        assert_eq!(state.len(), 1);
+        assert_eq!(state.resource_path(&resource), Some(path));
+    }
+
     #[test]
     fn resource_manager_state_request() {
         let mut state = new_resource_manager();
@@ -2271,6 +3168,29 @@ mod test {
         assert!(!res.is_loading());
     }
 
+    #[test]
+    fn resource_manager_state_normalized_paths() {
+        let mut state = new_resource_manager();
+
+        let foo = state.find("foo.txt");
+        assert_eq!(state.find("./foo.txt"), foo);
+        assert_eq!(state.find("foo.txt/"), foo);
+        assert_eq!(state.find_by_path(Path::new("./foo.txt")), Some(&foo));
+
+        let bar = state.find("dir/bar.txt");
+        assert_ne!(bar, foo);
+        assert_eq!(state.find("dir\\bar.txt"), bar);
+        assert_eq!(state.find("./dir/../dir/bar.txt"), bar);
+        assert_eq!(state.find("dir/bar.txt/"), bar);
+
+        let resource = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        state.register(resource.clone(), "./baz.txt").unwrap();
+        assert_eq!(state.request("baz.txt"), resource);
+        assert_eq!(state.request("./baz.txt"), resource);
+
+        assert_eq!(state.len(), 3);
+    }
+
     #[test]
     fn resource_manager_state_get_wait_context() {
         let mut state = new_resource_manager();
@@ -2334,6 +3254,406 @@ mod test {
         assert_eq!(res, resource);
     }
 
+    #[test]
+    fn resource_manager_request_blocking() {
+        let manager = ResourceManager::new(Arc::new(FsResourceIo), Arc::new(Default::default()));
+        manager.add_loader(Stub {});
+
+        let resource = UntypedResource::new_ok(Uuid::new_v4(), Default::default(), Stub {});
+        assert!(manager
+            .register(resource.clone(), PathBuf::from("blocking.txt"))
+            .is_ok());
+
+        let res = manager.request_blocking::<Stub>("blocking.txt").unwrap();
+        assert!(res.is_ok());
+        assert_eq!(res.untyped, resource);
+
+        let resource = UntypedResource::new_load_error(
+            ResourceKind::External,
+            Default::default(),
+            LoadError::new("Broken"),
+        );
+        assert!(manager
+            .register(resource, PathBuf::from("blocking_broken.txt"))
+            .is_ok());
+
+        let err = manager
+            .request_blocking::<Stub>("blocking_broken.txt")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Broken");
+    }
+
+    struct MagicLoader;
+
+    impl ResourceLoader for MagicLoader {
+        fn extensions(&self) -> &[&str] {
+            &[]
+        }
+
+        fn probe(&self, bytes: &[u8]) -> bool {
+            bytes.starts_with(b"STUB")
+        }
+
+        fn data_type_uuid(&self) -> Uuid {
+            <Stub as TypeUuidProvider>::type_uuid()
+        }
+
+        fn load(&self, _path: PathBuf, _io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture {
+            Box::pin(async move { Ok(LoaderPayload::new(Stub::default())) })
+        }
+    }
+
+    const XOR_KEY: u8 = 0x5A;
+
+    struct XorLoader;
+
+    impl ResourceLoader for XorLoader {
+        fn extensions(&self) -> &[&str] {
+            &["xor"]
+        }
+
+        fn data_type_uuid(&self) -> Uuid {
+            <Stub as TypeUuidProvider>::type_uuid()
+        }
+
+        fn accepts_transformed_bytes(&self) -> bool {
+            true
+        }
+
+        fn load(&self, path: PathBuf, io: Arc<dyn ResourceIo>) -> BoxedLoaderFuture {
+            Box::pin(async move {
+                let bytes = io.load_file(&path).await.map_err(LoadError::new)?;
+                if bytes == b"STUB" {
+                    Ok(LoaderPayload::new(Stub::default()))
+                } else {
+                    Err(LoadError::new("Invalid data!"))
+                }
+            })
+        }
+    }
+
+    #[test]
+    fn resource_manager_state_byte_transform() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let encrypted = b"STUB".iter().map(|b| b ^ XOR_KEY).collect::<Vec<_>>();
+        std::fs::write("data/encrypted.xor", encrypted).unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(XorLoader);
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        // Without the transform the loader receives raw bytes.
+        let resource = state.request("data/encrypted.xor");
+        assert!(block_on(resource.clone()).is_err());
+
+        state
+            .loaders
+            .safe_lock()
+            .set_byte_transform(|_, bytes| bytes.into_iter().map(|b| b ^ XOR_KEY).collect());
+        state.reload_resource(resource.clone());
+        assert!(block_on(resource.clone()).is_ok());
+        assert!(resource.is_ok());
+    }
+
+    #[test]
+    fn resource_manager_state_loader_by_magic_bytes() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        std::fs::write("data/magic_stub", b"STUB and some data").unwrap();
+        std::fs::write("data/magic_unknown", b"UNKNOWN").unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(MagicLoader);
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        let resource = state.request("data/magic_stub");
+        assert!(block_on(resource.clone()).is_ok());
+        assert!(resource.is_ok());
+
+        let resource = state.request("data/magic_unknown");
+        assert!(block_on(resource.clone()).is_err());
+    }
+
+    #[test]
+    fn resource_manager_state_file_created_and_removed() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let path = PathBuf::from("data/watched_stub.txt");
+        std::fs::write(&path, b"stub").unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(Stub {});
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        let resource = state.request(&path);
+        assert!(block_on(resource.clone()).is_ok());
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        state.event_broadcaster.add(sender);
+
+        // Re-creation of a file of a loaded resource must reload it.
+        state.on_create_event(Some(&path));
+        assert!(block_on(resource.clone()).is_ok());
+        assert!(matches!(
+            receiver.recv_timeout(Duration::from_secs(5)),
+            Ok(ResourceEvent::Reloaded(reloaded)) if reloaded == resource
+        ));
+
+        // Removal of the file must be reported.
+        std::fs::remove_file(&path).unwrap();
+        state.on_remove_event(Some(&path));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(ResourceEvent::Removed(removed)) if removed == make_relative_path(&path).unwrap()
+        ));
+
+        // The file still exists, the event must be ignored.
+        std::fs::write(&path, b"stub").unwrap();
+        state.on_remove_event(Some(&path));
+        assert!(receiver.try_recv().is_err());
+        remove_file_if_exists(&path).unwrap();
+        remove_file_if_exists(&append_extension(&path, ResourceMetadata::EXTENSION)).unwrap();
+    }
+
+    #[test]
+    fn resource_manager_state_reload_debounce() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let path = PathBuf::from("data/debounced_stub.txt");
+        std::fs::write(&path, b"stub").unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(Stub {});
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+        assert_eq!(
+            state.reload_debounce_interval(),
+            DEFAULT_RELOAD_DEBOUNCE_INTERVAL
+        );
+        state.set_reload_debounce_interval(Duration::from_millis(100));
+
+        let resource = state.request(&path);
+        assert!(block_on(resource.clone()).is_ok());
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        state.event_broadcaster.add(sender);
+
+        let start = Instant::now();
+        state.on_modify_event(path.clone(), start);
+        state.on_modify_event(path.clone(), start + Duration::from_millis(50));
+
+        // The file was modified recently, nothing must be reloaded.
+        state.flush_pending_reloads(start + Duration::from_millis(100));
+        assert_eq!(state.pending_reloads.len(), 1);
+
+        state.flush_pending_reloads(start + Duration::from_millis(150));
+        assert!(state.pending_reloads.is_empty());
+        assert!(matches!(
+            receiver.recv_timeout(Duration::from_secs(5)),
+            Ok(ResourceEvent::Reloaded(reloaded)) if reloaded == resource
+        ));
+
+        state.flush_pending_reloads(start + Duration::from_millis(500));
+        assert!(receiver.recv_timeout(Duration::from_millis(200)).is_err());
+
+        remove_file_if_exists(&path).unwrap();
+    }
+
+    #[test]
+    fn resource_manager_state_on_loaded() {
+        let mut state = new_resource_manager();
+
+        let resource = UntypedResource::new_pending(Uuid::new_v4(), ResourceKind::External);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        state.on_loaded(resource.clone(), move |result| {
+            assert!(result.is_ok());
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        state.update(0.0);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        resource.commit_ok(Stub {});
+        state.update(0.0);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // The callback must be called only once.
+        state.update(0.0);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let mut resource = UntypedResource::new_pending(Uuid::new_v4(), ResourceKind::External);
+        let calls_clone = calls.clone();
+        state.on_loaded(resource.clone(), move |result| {
+            assert_eq!(result.unwrap_err().to_string(), "Broken");
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        resource.commit_error(PathBuf::from("broken.txt"), "Broken".to_string());
+        state.update(0.0);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn resource_manager_preload() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let paths = [
+            (PathBuf::from("data/preload_a.txt"), Uuid::new_v4()),
+            (PathBuf::from("data/preload_b.txt"), Uuid::new_v4()),
+        ];
+        for (path, _) in paths.iter() {
+            std::fs::write(path, b"stub").unwrap();
+        }
+
+        let manager = ResourceManager::new(Arc::new(FsResourceIo), Arc::new(Default::default()));
+        manager.add_loader(Stub {});
+        manager
+            .state()
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        let cx = manager.preload(&paths);
+        assert_eq!(cx.resources().len(), 2);
+        for (resource, (_, uuid)) in cx.resources().iter().zip(paths.iter()) {
+            assert_eq!(resource.resource_uuid(), *uuid);
+        }
+
+        let results = block_on(cx.wait());
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(cx.is_all_loaded());
+    }
+
+    #[test]
+    fn resource_manager_state_total_bytes() {
+        let mut state = new_resource_manager();
+        assert_eq!(state.total_bytes(), 0);
+
+        let stub = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, Stub {});
+        let other = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, OtherStub {});
+        let pending = UntypedResource::new_pending(Uuid::new_v4(), ResourceKind::External);
+        state.register(stub, "bytes_stub.txt").unwrap();
+        state.register(other, "bytes_other.txt").unwrap();
+        state
+            .register(pending.clone(), "bytes_pending.txt")
+            .unwrap();
+        assert_eq!(state.total_bytes(), 128);
+
+        pending.commit_ok(OtherStub {});
+        assert_eq!(state.total_bytes(), 256);
+    }
+
+    #[test]
+    fn resource_manager_state_memory_budget() {
+        let mut state = new_resource_manager();
+        assert_eq!(state.memory_budget(), None);
+
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        for (uuid, path) in [
+            (a, "budget_a.txt"),
+            (b, "budget_b.txt"),
+            (c, "budget_c.txt"),
+        ] {
+            let resource = UntypedResource::new_ok(uuid, ResourceKind::External, OtherStub {});
+            state.register(resource, path).unwrap();
+        }
+        let used = UntypedResource::new_ok(Uuid::new_v4(), ResourceKind::External, OtherStub {});
+        state.register(used.clone(), "budget_used.txt").unwrap();
+
+        // Access `a`, so `b` becomes the least recently used one.
+        assert!(state.find_by_path(Path::new("budget_a.txt")).is_some());
+
+        // No budget - nothing is evicted.
+        state.update(0.0);
+        assert_eq!(state.total_bytes(), 512);
+
+        state.set_memory_budget(Some(300));
+        state.update(0.0);
+        assert_eq!(state.total_bytes(), 256);
+        assert!(state.find_by_uuid(b).is_none());
+        assert!(state.find_by_uuid(c).is_none());
+        assert!(state.find_by_uuid(a).is_some());
+        // The resource is still in use and cannot be evicted.
+        assert!(state.find_by_uuid(used.resource_uuid()).is_some());
+    }
+
+    #[test]
+    fn resource_manager_state_no_loader() {
+        std::fs::create_dir_all("data").expect("Could not create data directory.");
+        let path = PathBuf::from("data/no_loader.unknownext");
+        std::fs::write(&path, b"stub").unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(Stub {});
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        let resource = state.request(&path);
+        let error = block_on(resource.clone()).unwrap_err();
+        assert!(error.to_string().contains("extension 'unknownext'"));
+        assert!(resource.is_failed_to_load());
+        assert!(state.get_wait_context().is_all_loaded());
+    }
+
+    #[test]
+    fn resource_manager_state_redirects() {
+        std::fs::create_dir_all("data/mods").expect("Could not create data directory.");
+        std::fs::write("data/mods/hero.txt", b"stub").unwrap();
+
+        let mut state = new_resource_manager();
+        state.add_loader(Stub {});
+        state
+            .resource_registry
+            .safe_lock()
+            .status_flag()
+            .mark_as_loaded();
+
+        assert!(state
+            .add_redirect("data/hero.txt".into(), "data/mods/hero.txt".into())
+            .is_none());
+        assert!(state
+            .add_redirect("data/./hero_alias.txt".into(), "data/hero.txt".into())
+            .is_none());
+
+        let target = state.request("data/mods/hero.txt");
+        assert_eq!(state.request("data/hero.txt"), target);
+        assert_eq!(state.request("data/hero_alias.txt"), target);
+        assert_eq!(state.find("data/hero_alias.txt"), target);
+        assert!(block_on(target).is_ok());
+
+        // Loops must not hang.
+        state.add_redirect("data/loop_a.txt".into(), "data/loop_b.txt".into());
+        state.add_redirect("data/loop_b.txt".into(), "data/loop_a.txt".into());
+        assert_eq!(
+            state.resolve_redirects(Path::new("data/loop_a.txt")),
+            PathBuf::from("data/loop_a.txt")
+        );
+
+        assert_eq!(
+            state.remove_redirect(Path::new("data/hero.txt")),
+            Some(PathBuf::from("data/mods/hero.txt"))
+        );
+        assert_eq!(
+            state.resolve_redirects(Path::new("data/hero_alias.txt")),
+            PathBuf::from("data/hero.txt")
+        );
+    }
+
     #[test]
     fn display_for_resource_registration_error() {
         assert_eq!(
@@ -2365,4 +3685,10 @@ mod test {
             "UnableToRegister"
         );
     }
+
+    #[test]
+    fn test_resource_registration_error_is_error() {
+        let error: Box<dyn Error> = Box::new(ResourceRegistrationError::AlreadyRegistered);
+        assert_eq!(error.to_string(), "A resource is already registered!");
+    }
 }
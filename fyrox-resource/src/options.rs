@@ -28,6 +28,7 @@ use fyrox_core::io::FileError;
 use fyrox_core::reflect::Reflect;
 use ron::ser::PrettyConfig;
 use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::{Display, Formatter};
 use std::io::{ErrorKind, Write};
 use std::{fs::File, path::Path};
 
@@ -41,23 +42,69 @@ pub trait BaseImportOptions: Reflect {
     fn save(&self, path: &Path) -> bool;
 }
 
+/// An error that may occur during import options saving.
+#[derive(Debug)]
+pub enum ImportOptionsSaveError {
+    /// The options file cannot be created or written.
+    Io(std::io::Error),
+    /// The options cannot be serialized.
+    Serialize(ron::Error),
+}
+
+impl std::error::Error for ImportOptionsSaveError {}
+
+impl Display for ImportOptionsSaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportOptionsSaveError::Io(err) => {
+                write!(f, "Unable to write options file. Reason: {err}")
+            }
+            ImportOptionsSaveError::Serialize(err) => {
+                write!(f, "Unable to serialize options. Reason: {err}")
+            }
+        }
+    }
+}
+
 /// A trait for resource import options. It provides generic functionality shared over all types of import options.
 pub trait ImportOptions:
     BaseImportOptions + Serialize + DeserializeOwned + Default + Clone
 {
-    /// Saves import options into a specified file.
+    /// Saves import options into a specified file and returns the cause of the error, if any.
+    ///
+    /// The options are written to a temporary file next to the specified one first, and then the
+    /// temporary file is renamed to the specified one. This way an interrupted save never leaves
+    /// a truncated options file.
+    fn save_detailed(&self, path: &Path) -> Result<(), ImportOptionsSaveError> {
+        let string = ron::ser::to_string_pretty(self, PrettyConfig::default())
+            .map_err(ImportOptionsSaveError::Serialize)?;
+
+        let temp_path = append_extension(path, "tmp");
+        let result = File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(string.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|_| std::fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result.map_err(ImportOptionsSaveError::Io)
+    }
+
+    /// Saves import options into a specified file. Any error is logged, use [`Self::save_detailed`]
+    /// if you need to know the cause of the error.
     fn save_internal(&self, path: &Path) -> bool {
-        fn write<T: Serialize>(this: &T, path: &Path) -> std::io::Result<()> {
-            let mut file = File::create(path)?;
-            let string = ron::ser::to_string_pretty(this, PrettyConfig::default())
-                .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
-            file.write_all(string.as_bytes())?;
-            Ok(())
+        match self.save_detailed(path) {
+            Ok(()) => true,
+            Err(err) => {
+                Log::err(format!(
+                    "Unable to save import options to {}. {err}",
+                    path.display()
+                ));
+                false
+            }
         }
-        let result = write(self, path);
-        let is_ok = result.is_ok();
-        Log::verify(result);
-        is_ok
     }
 }
 
@@ -70,39 +117,71 @@ where
     }
 }
 
-/// Tries to load import settings for a resource. It is not part of ImportOptions trait because
-/// `async fn` is not yet supported for traits.
-pub async fn try_get_import_settings<T>(resource_path: &Path, io: &dyn ResourceIo) -> Option<T>
+/// An error that may occur during import settings loading.
+#[derive(Debug)]
+pub enum ImportSettingsError {
+    /// The settings file exists, but it cannot be read.
+    Io(FileError),
+    /// The settings file is malformed.
+    Parse(ron::error::SpannedError),
+}
+
+impl std::error::Error for ImportSettingsError {}
+
+impl Display for ImportSettingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportSettingsError::Io(err) => {
+                write!(f, "Unable to load options file. Reason: {err}")
+            }
+            ImportSettingsError::Parse(err) => {
+                write!(f, "Malformed options file. Reason: {err}")
+            }
+        }
+    }
+}
+
+/// Tries to load import settings for a resource. Returns `Ok(None)` if there's no options file for
+/// the resource, which is a normal situation - default import options should be used in this case.
+/// Any other error (IO or parsing) is returned as is, so it can be shown to the user.
+pub async fn try_get_import_settings_detailed<T>(
+    resource_path: &Path,
+    io: &dyn ResourceIo,
+) -> Result<Option<T>, ImportSettingsError>
 where
     T: ImportOptions,
 {
     let settings_path = append_extension(resource_path, OPTIONS_EXTENSION);
 
     match io.load_file(settings_path.as_ref()).await {
-        Ok(bytes) => match ron::de::from_bytes::<T>(&bytes) {
-            Ok(options) => Some(options),
-            Err(e) => {
-                Log::warn(format!(
-                    "Malformed options file {:?}, fallback to defaults! Reason: {}",
-                    settings_path, e
-                ));
-
-                None
-            }
-        },
+        Ok(bytes) => ron::de::from_bytes::<T>(&bytes)
+            .map(Some)
+            .map_err(ImportSettingsError::Parse),
         Err(e) => {
-            // Missing options file is a normal situation, the engine will use default import options
-            // instead. Any other error indicates a real issue that needs to be highlighted to the
-            // user.
             if let FileError::Io(ref err) = e {
                 if err.kind() == ErrorKind::NotFound {
-                    return None;
+                    return Ok(None);
                 }
             }
 
+            Err(ImportSettingsError::Io(e))
+        }
+    }
+}
+
+/// Tries to load import settings for a resource. It is not part of ImportOptions trait because
+/// `async fn` is not yet supported for traits. Any error is logged and `None` is returned, use
+/// [`try_get_import_settings_detailed`] if you need to know the cause of the error.
+pub async fn try_get_import_settings<T>(resource_path: &Path, io: &dyn ResourceIo) -> Option<T>
+where
+    T: ImportOptions,
+{
+    match try_get_import_settings_detailed(resource_path, io).await {
+        Ok(options) => options,
+        Err(e) => {
             Log::warn(format!(
-                "Unable to load options file {:?}, fallback to defaults! Reason: {}",
-                settings_path, e
+                "Unable to load import options for {:?}, fallback to defaults! {}",
+                resource_path, e
             ));
 
             None
@@ -122,3 +201,95 @@ where
         .await
         .map(|options| Box::new(options) as Box<dyn BaseImportOptions>)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        core::reflect::prelude::*,
+        io::MemoryResourceIo,
+        options::{
+            try_get_import_settings_detailed, ImportOptions, ImportOptionsSaveError,
+            ImportSettingsError,
+        },
+    };
+    use fyrox_core::futures::executor::block_on;
+    use serde::{ser::Error, Deserialize, Serialize, Serializer};
+    use std::path::{Path, PathBuf};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Reflect)]
+    struct TestOptions {
+        value: u32,
+    }
+
+    impl ImportOptions for TestOptions {}
+
+    #[derive(Clone, Debug, Default, Deserialize, Reflect)]
+    struct FailingOptions;
+
+    impl Serialize for FailingOptions {
+        fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(S::Error::custom("failing options"))
+        }
+    }
+
+    impl ImportOptions for FailingOptions {}
+
+    fn load(data: &str) -> Result<Option<TestOptions>, ImportSettingsError> {
+        let io = MemoryResourceIo::new(PathBuf::from("test.res.options"), data.as_bytes().to_vec());
+        block_on(try_get_import_settings_detailed(Path::new("test.res"), &io))
+    }
+
+    #[test]
+    fn test_missing_import_settings() {
+        let io = MemoryResourceIo::new(PathBuf::from("other.res.options"), Vec::new());
+        let result = block_on(try_get_import_settings_detailed::<TestOptions>(
+            Path::new("test.res"),
+            &io,
+        ));
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[test]
+    fn test_valid_import_settings() {
+        assert_eq!(
+            load("(value: 42)").unwrap(),
+            Some(TestOptions { value: 42 })
+        );
+    }
+
+    #[test]
+    fn test_corrupt_import_settings() {
+        assert!(matches!(
+            load("(value: \"foo\""),
+            Err(ImportSettingsError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_save_to_unwritable_path() {
+        let options = TestOptions { value: 1 };
+        let result = options.save_detailed(Path::new("non_existent_directory/test.res.options"));
+        assert!(matches!(result, Err(ImportOptionsSaveError::Io(_))));
+        assert!(!options.save_internal(Path::new("non_existent_directory/test.res.options")));
+    }
+
+    #[test]
+    fn test_failed_save_keeps_original_file() {
+        let dir = Path::new("./test_output_import_options");
+        std::fs::create_dir_all(dir).unwrap();
+        let path = dir.join("test.res.options");
+
+        let options = TestOptions { value: 123 };
+        options.save_detailed(&path).unwrap();
+        let original = std::fs::read_to_string(&path).unwrap();
+        assert!(!dir.join("test.res.options.tmp").exists());
+
+        assert!(matches!(
+            FailingOptions.save_detailed(&path),
+            Err(ImportOptionsSaveError::Serialize(_))
+        ));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
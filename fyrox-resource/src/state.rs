@@ -150,6 +150,10 @@ impl Reflect for ResourceDataWrapper {
         self.deref_mut().set(value)
     }
 
+    fn type_source_path(&self) -> &'static str {
+        file!()
+    }
+
     fn assembly_name(&self) -> &'static str {
         env!("CARGO_PKG_NAME")
     }
@@ -216,6 +220,26 @@ impl Reflect for ResourceDataWrapper {
         self.deref_mut().as_hash_map_mut(func)
     }
 
+    fn as_enum(&self, func: &mut dyn FnMut(Option<&dyn ReflectEnum>)) {
+        self.deref().as_enum(func)
+    }
+
+    fn as_enum_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectEnum>)) {
+        self.deref_mut().as_enum_mut(func)
+    }
+
+    fn as_option(&self, func: &mut dyn FnMut(Option<&dyn ReflectOption>)) {
+        self.deref().as_option(func)
+    }
+
+    fn as_option_mut(&mut self, func: &mut dyn FnMut(Option<&mut dyn ReflectOption>)) {
+        self.deref_mut().as_option_mut(func)
+    }
+
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        self.deref().reflect_partial_eq(other)
+    }
+
     fn as_handle(&self, func: &mut dyn FnMut(Option<&dyn ReflectHandle>)) {
         self.deref().as_handle(func)
     }
@@ -483,7 +483,7 @@ impl AudioBusGraph {
         }
     }
 
-    pub(crate) fn end_render(&mut self, output_device_buffer: &mut [(f32, f32)]) {
+    pub(crate) fn end_render(&mut self, output_device_buffer: &mut [(f32, f32)], master_gain: f32) {
         let mut leafs = Vec::new();
         for (handle, bus) in self.buses.pair_iter_mut() {
             bus.apply_effects();
@@ -500,8 +500,13 @@ impl AudioBusGraph {
                 let leaf_ref = ctx.try_get_mut(leaf).expect("Malformed bus graph!");
 
                 let input_buffer = leaf_ref.ping_pong_buffer.input_ref();
-                let leaf_gain = leaf_ref.gain;
                 let mut parent_buffer = ctx.try_get_mut(leaf_ref.parent_bus);
+                // Master gain is applied only once, when the root bus writes to the output buffer.
+                let leaf_gain = if parent_buffer.is_some() {
+                    leaf_ref.gain
+                } else {
+                    leaf_ref.gain * master_gain
+                };
                 let output_buffer = parent_buffer
                     .as_mut()
                     .map(|parent| parent.ping_pong_buffer.input_mut())
@@ -549,7 +554,7 @@ mod test {
             *right = 1.0;
         }
 
-        graph.end_render(&mut output_buffer);
+        graph.end_render(&mut output_buffer, 1.0);
 
         assert_eq!(output_buffer[0], (2.0, 2.0));
     }
@@ -568,11 +573,35 @@ mod test {
             *right = 1.0;
         }
 
-        graph.end_render(&mut output_buffer);
+        graph.end_render(&mut output_buffer, 1.0);
 
         assert_eq!(output_buffer[0], (1.0, 1.0));
     }
 
+    #[test]
+    fn test_master_gain() {
+        let mut output_buffer = [(0.0f32, 0.0f32)];
+
+        let mut graph = AudioBusGraph::new();
+
+        let bus1 = graph.add_bus(AudioBus::new("Bus1".to_string()), graph.root);
+        let bus2 = graph.add_bus(AudioBus::new("Bus2".to_string()), bus1);
+
+        graph.begin_render(output_buffer.len());
+
+        for bus in [graph.root, bus1, bus2] {
+            for (left, right) in graph.buses[bus].input_buffer() {
+                *left = 1.0;
+                *right = 1.0;
+            }
+        }
+
+        graph.end_render(&mut output_buffer, 0.5);
+
+        // The gain is applied only once - at the root bus.
+        assert_eq!(output_buffer[0], (1.5, 1.5));
+    }
+
     #[test]
     fn test_multi_bus_data_flow_with_effects() {
         let mut output_buffer = [(0.0f32, 0.0f32)];
@@ -602,7 +631,7 @@ mod test {
             *right = 1.0;
         }
 
-        graph.end_render(&mut output_buffer);
+        graph.end_render(&mut output_buffer, 1.0);
 
         assert_eq!(output_buffer[0], (0.75, 0.75));
     }
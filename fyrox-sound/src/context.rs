@@ -125,8 +125,11 @@ pub struct SerializationOptions {
     pub skip_bus_graph: bool,
 }
 
+/// Default speed of sound in units per second (the speed of sound in the air).
+pub const DEFAULT_SPEED_OF_SOUND: f32 = 343.0;
+
 /// Internal state of context.
-#[derive(Default, Debug, Clone, Reflect)]
+#[derive(Debug, Clone, Reflect)]
 pub struct State {
     sources: Pool<SoundSource>,
     listener: Listener,
@@ -135,12 +138,31 @@ pub struct State {
     bus_graph: AudioBusGraph,
     distance_model: DistanceModel,
     paused: bool,
+    speed_of_sound: f32,
+    master_gain: f32,
     /// A set of flags, that can be used to define what should be skipped during the
     /// serialization of a sound context.
     #[reflect(hidden)]
     pub serialization_options: SerializationOptions,
 }
 
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            sources: Default::default(),
+            listener: Default::default(),
+            render_duration: Default::default(),
+            renderer: Default::default(),
+            bus_graph: Default::default(),
+            distance_model: Default::default(),
+            paused: Default::default(),
+            speed_of_sound: DEFAULT_SPEED_OF_SOUND,
+            master_gain: 1.0,
+            serialization_options: Default::default(),
+        }
+    }
+}
+
 impl State {
     /// Extracts a source from the context and reserves its handle. It is used to temporarily take
     /// ownership over source, and then put node back using given ticket.
@@ -185,6 +207,33 @@ impl State {
         self.distance_model
     }
 
+    /// Sets new speed of sound (in units per second) of the environment.
+    ///
+    /// # Notes
+    ///
+    /// The value is informational only: it is stored and serialized with the context, but the
+    /// renderer does not use it yet (there's no Doppler effect or propagation delay simulation).
+    pub fn set_speed_of_sound(&mut self, speed_of_sound: f32) {
+        self.speed_of_sound = speed_of_sound;
+    }
+
+    /// Returns current speed of sound (in units per second) of the environment. See
+    /// [`DEFAULT_SPEED_OF_SOUND`] for the default value.
+    pub fn speed_of_sound(&self) -> f32 {
+        self.speed_of_sound
+    }
+
+    /// Sets new master gain of the context. It scales the output of the whole context and could be
+    /// used to mute the context (by setting the gain to zero) or to control its overall volume.
+    pub fn set_master_gain(&mut self, master_gain: f32) {
+        self.master_gain = master_gain;
+    }
+
+    /// Returns current master gain of the context. Default value is `1.0`.
+    pub fn master_gain(&self) -> f32 {
+        self.master_gain
+    }
+
     /// Normalizes given frequency using context's sampling rate. Normalized frequency then can be used
     /// to create filters.
     pub fn normalize_frequency(&self, f: f32) -> f32 {
@@ -314,7 +363,8 @@ impl State {
                 }
             }
 
-            self.bus_graph.end_render(output_device_buffer);
+            self.bus_graph
+                .end_render(output_device_buffer, self.master_gain);
         }
 
         self.render_duration = fyrox_core::instant::Instant::now() - last_time;
@@ -344,6 +394,8 @@ impl SoundContext {
                 bus_graph: AudioBusGraph::new(),
                 distance_model: DistanceModel::InverseDistance,
                 paused: false,
+                speed_of_sound: DEFAULT_SPEED_OF_SOUND,
+                master_gain: 1.0,
                 serialization_options: Default::default(),
             }))),
         }
@@ -402,6 +454,9 @@ impl Visit for State {
         self.renderer.visit("Renderer", &mut region)?;
         self.paused.visit("Paused", &mut region)?;
         self.distance_model.visit("DistanceModel", &mut region)?;
+        // Backward compatibility.
+        let _ = self.speed_of_sound.visit("SpeedOfSound", &mut region);
+        let _ = self.master_gain.visit("MasterGain", &mut region);
 
         Ok(())
     }
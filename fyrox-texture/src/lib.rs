@@ -344,6 +344,10 @@ impl ResourceData for Texture {
     fn try_clone_box(&self) -> Option<Box<dyn ResourceData>> {
         Some(Box::new(self.clone()))
     }
+
+    fn byte_size(&self) -> usize {
+        self.bytes.len()
+    }
 }
 
 impl Visit for Texture {
@@ -97,6 +97,22 @@ impl MessageData for CurveEditorMessage {
     }
 }
 
+impl CurveEditorMessage {
+    /// Returns `true` if the message modifies the curves of the editor on user's behalf. Such
+    /// messages are ignored by read-only curve editors.
+    fn is_editing(&self) -> bool {
+        matches!(
+            self,
+            Self::ChangeSelectedKeysKind(_)
+                | Self::ChangeSelectedKeysValue(_)
+                | Self::ChangeSelectedKeysLocation(_)
+                | Self::RemoveSelection
+                | Self::PasteSelection
+                | Self::AddKey(_)
+        )
+    }
+}
+
 /// Highlight zone in values space.
 #[derive(Clone, Debug, PartialEq, Visit, Reflect, Default)]
 pub struct HighlightZone {
@@ -447,6 +463,8 @@ pub struct CurveEditor {
     #[visit(skip)]
     #[reflect(hidden)]
     clipboard: Vec<(Vector2<f32>, CurveKeyKind)>,
+    #[visit(optional)]
+    read_only: bool,
 }
 
 impl ConstructorProvider<UiNode, UserInterface> for CurveEditor {
@@ -564,7 +582,7 @@ impl Control for CurveEditor {
             if let Some(msg) = message.data::<WidgetMessage>() {
                 match msg {
                     WidgetMessage::KeyUp(code) => match code {
-                        KeyCode::Delete => self.remove_selection(ui),
+                        KeyCode::Delete if !self.read_only => self.remove_selection(ui),
                         KeyCode::KeyF => self.zoom_to_fit(&ui.sender()),
                         _ => (),
                     },
@@ -664,7 +682,9 @@ impl Control for CurveEditor {
                                 }
                             }
                         } else if state.left == ButtonState::Pressed {
-                            if let Some(selection) = self.selection.as_ref() {
+                            if let Some(selection) =
+                                self.selection.as_ref().filter(|_| !self.read_only)
+                            {
                                 match selection {
                                     Selection::Keys { keys } => {
                                         self.operation_context = Some(OperationContext::DragKeys {
@@ -838,6 +858,10 @@ impl Control for CurveEditor {
                     _ => {}
                 }
             } else if let Some(msg) = message.data_for::<CurveEditorMessage>(self.handle) {
+                if msg.is_editing() && self.read_only {
+                    return;
+                }
+
                 match msg {
                     CurveEditorMessage::SyncBackground(curves) => {
                         self.background_curves =
@@ -1067,6 +1091,16 @@ fn round_to_step(x: f32, step: f32) -> f32 {
 }
 
 impl CurveEditor {
+    /// Returns `true` if the curves of the editor cannot be modified by a user, `false` - otherwise.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns a reference to the curves that are currently edited.
+    pub fn curves(&self) -> &CurvesContainer {
+        &self.curves
+    }
+
     #[allow(clippy::let_and_return)] // Improves readability
     fn set_view_position(&mut self, position: Vector2<f32>) {
         self.curve_transform
@@ -1327,7 +1361,7 @@ impl CurveEditor {
     }
 
     fn send_curves(&self, ui: &UserInterface) {
-        ui.send(
+        ui.post(
             self.handle,
             CurveEditorMessage::Sync(self.curves.to_native()),
         );
@@ -1674,6 +1708,7 @@ pub struct CurveEditorBuilder {
     min_zoom: Vector2<f32>,
     max_zoom: Vector2<f32>,
     highlight_zones: Vec<HighlightZone>,
+    read_only: bool,
 }
 
 impl CurveEditorBuilder {
@@ -1691,6 +1726,7 @@ impl CurveEditorBuilder {
             min_zoom: Vector2::new(0.001, 0.001),
             max_zoom: Vector2::new(1000.0, 1000.0),
             highlight_zones: Default::default(),
+            read_only: false,
         }
     }
 
@@ -1750,6 +1786,13 @@ impl CurveEditorBuilder {
         self
     }
 
+    /// Defines whether the curves could be modified by a user or not. Read-only curve editor still
+    /// allows to select keys, to move and to zoom the view. Default is `false`.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
     pub fn build(mut self, ctx: &mut BuildContext) -> Handle<UiNode> {
         let background_curve_brush = ctx.style.get_or_default::<Brush>(Style::BRUSH_LIGHT);
         let key_brush = Brush::Solid(Color::opaque(140, 140, 140));
@@ -1957,6 +2000,7 @@ impl CurveEditorBuilder {
             highlight_zones: self.highlight_zones,
             zoom_to_fit_timer: None,
             clipboard: Default::default(),
+            read_only: self.read_only,
             background_curve_brush,
         };
 
@@ -196,6 +196,11 @@ impl FileSelectorBuilder {
         self
     }
 
+    pub fn with_opt_filter(mut self, filter: Option<Filter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     pub fn with_path<P: AsRef<Path>>(mut self, path: P) -> Self {
         path.as_ref().clone_into(&mut self.path);
         self
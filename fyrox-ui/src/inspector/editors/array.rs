@@ -141,6 +141,7 @@ where
 
             let metadata = FieldMetadata {
                 name: &name,
+                aliases: &[],
                 display_name: &display_name,
                 read_only: property_info.read_only,
                 immutable_collection: property_info.immutable_collection,
@@ -409,6 +410,7 @@ where
 
                 let metadata = FieldMetadata {
                     name: &name,
+                    aliases: &[],
                     display_name: &display_name,
                     read_only: property_info.read_only,
                     immutable_collection: property_info.immutable_collection,
@@ -241,6 +241,7 @@ where
             let proxy_property_info = FieldRef {
                 metadata: &FieldMetadata {
                     name: &name,
+                    aliases: &[],
                     display_name: &display_name,
                     read_only: property_info.read_only,
                     immutable_collection: property_info.immutable_collection,
@@ -580,6 +581,7 @@ where
                     let proxy_property_info = FieldRef {
                         metadata: &FieldMetadata {
                             name: &name,
+                            aliases: &[],
                             display_name: &display_name,
                             read_only: property_info.read_only,
                             immutable_collection: property_info.immutable_collection,
@@ -19,7 +19,8 @@
 // SOFTWARE.
 
 use crate::{
-    core::{algebra::Vector2, math::curve::Curve},
+    brush::Brush,
+    core::{algebra::Vector2, color::Color, math::curve::Curve, uuid::Uuid},
     curve::{CurveEditorBuilder, CurveEditorMessage},
     inspector::{
         editors::{
@@ -53,6 +54,7 @@ impl PropertyEditorDefinition for CurvePropertyEditorDefinition {
                 .with_margin(Thickness::uniform(1.0)),
         )
         .with_curves(vec![value.clone()])
+        .with_read_only(ctx.property_info.read_only)
         .build(ctx.build_context);
         ctx.build_context
             .inner()
@@ -84,3 +86,238 @@ impl PropertyEditorDefinition for CurvePropertyEditorDefinition {
         None
     }
 }
+
+/// Property editor for a collection of curves (`Vec<Curve>`), for example, separate channels of
+/// some value. All the curves are shown in a single curve editor, each with its own color.
+#[derive(Debug)]
+pub struct MultiCurvePropertyEditorDefinition;
+
+fn curve_colors(curves: &[Curve]) -> Vec<(Uuid, Brush)> {
+    curves
+        .iter()
+        .enumerate()
+        .map(|(index, curve)| {
+            (
+                curve.id,
+                Brush::Solid(Color::COLORS[(3 + index) % Color::COLORS.len()]),
+            )
+        })
+        .collect()
+}
+
+impl PropertyEditorDefinition for MultiCurvePropertyEditorDefinition {
+    fn value_type_id(&self) -> TypeId {
+        TypeId::of::<Vec<Curve>>()
+    }
+
+    fn create_instance(
+        &self,
+        ctx: PropertyEditorBuildContext,
+    ) -> Result<PropertyEditorInstance, InspectorError> {
+        let value = ctx.property_info.cast_value::<Vec<Curve>>()?;
+        let editor = CurveEditorBuilder::new(
+            WidgetBuilder::new()
+                .with_min_size(Vector2::new(0.0, 200.0))
+                .with_margin(Thickness::uniform(1.0)),
+        )
+        .with_curves(value.clone())
+        .with_read_only(ctx.property_info.read_only)
+        .build(ctx.build_context);
+        ctx.build_context
+            .inner()
+            .send(editor, CurveEditorMessage::Colorize(curve_colors(value)));
+        ctx.build_context
+            .inner()
+            .send(editor, CurveEditorMessage::ZoomToFit { after_layout: true });
+        Ok(PropertyEditorInstance::Simple { editor })
+    }
+
+    fn create_message(
+        &self,
+        ctx: PropertyEditorMessageContext,
+    ) -> Result<Option<UiMessage>, InspectorError> {
+        let value = ctx.property_info.cast_value::<Vec<Curve>>()?;
+        Ok(Some(UiMessage::for_widget(
+            ctx.instance,
+            CurveEditorMessage::Sync(value.clone()),
+        )))
+    }
+
+    fn translate_message(&self, ctx: PropertyEditorTranslationContext) -> Option<PropertyChanged> {
+        if ctx.message.direction() == MessageDirection::FromWidget {
+            if let Some(CurveEditorMessage::Sync(value)) = ctx.message.data() {
+                return Some(PropertyChanged {
+                    name: ctx.name.to_string(),
+                    value: FieldKind::object(value.clone()),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        core::{
+            algebra::Vector2,
+            math::curve::{Curve, CurveKey, CurveKeyKind},
+            pool::Handle,
+            reflect::{FieldMetadata, FieldRef, FieldValue},
+        },
+        curve::{CurveEditor, CurveEditorMessage},
+        inspector::{
+            editors::{
+                curve::{CurvePropertyEditorDefinition, MultiCurvePropertyEditorDefinition},
+                PropertyEditorBuildContext, PropertyEditorDefinition,
+                PropertyEditorDefinitionContainer, PropertyEditorInstance,
+                PropertyEditorTranslationContext,
+            },
+            FieldKind, PropertyFilter,
+        },
+        message::{MessageDirection, MouseButton},
+        widget::WidgetMessage,
+        UiNode, UserInterface,
+    };
+    use fyrox_graph::BaseSceneGraph;
+    use std::sync::Arc;
+
+    fn create_editor(
+        ui: &mut UserInterface,
+        definition: &dyn PropertyEditorDefinition,
+        name: &str,
+        value: &dyn FieldValue,
+        read_only: bool,
+    ) -> Handle<UiNode> {
+        let metadata = FieldMetadata {
+            read_only,
+            ..FieldMetadata::new(name)
+        };
+        let property_info = FieldRef {
+            metadata: &metadata,
+            value,
+        };
+
+        let instance = definition
+            .create_instance(PropertyEditorBuildContext {
+                build_context: &mut ui.build_ctx(),
+                property_info: &property_info,
+                environment: None,
+                definition_container: Arc::new(PropertyEditorDefinitionContainer::empty()),
+                sync_flag: 0,
+                layer_index: 0,
+                generate_property_string_values: false,
+                filter: PropertyFilter::default(),
+                name_column_width: 150.0,
+                base_path: Default::default(),
+                has_parent_object: false,
+            })
+            .unwrap();
+
+        let PropertyEditorInstance::Simple { editor } = instance else {
+            unreachable!()
+        };
+        editor
+    }
+
+    fn is_read_only(ui: &mut UserInterface, read_only: bool) -> bool {
+        let editor = create_editor(
+            ui,
+            &CurvePropertyEditorDefinition,
+            "curve",
+            &Curve::default(),
+            read_only,
+        );
+        ui.node(editor)
+            .cast::<CurveEditor>()
+            .unwrap()
+            .is_read_only()
+    }
+
+    #[test]
+    fn test_read_only_curve() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        assert!(is_read_only(&mut ui, true));
+        assert!(!is_read_only(&mut ui, false));
+    }
+
+    #[test]
+    fn test_multi_curve_sync() {
+        let screen_size = Vector2::new(100.0, 100.0);
+        let mut ui = UserInterface::new(screen_size);
+
+        // Keys are placed far enough from each other to be picked separately.
+        let curves = (0..3)
+            .map(|i| {
+                Curve::from(vec![CurveKey::new(
+                    20.0 * i as f32,
+                    0.0,
+                    CurveKeyKind::Linear,
+                )])
+            })
+            .collect::<Vec<_>>();
+        let editor = create_editor(
+            &mut ui,
+            &MultiCurvePropertyEditorDefinition,
+            "curves",
+            &curves,
+            false,
+        );
+        let curve_editor = ui.node(editor).cast::<CurveEditor>().unwrap();
+        assert_eq!(curve_editor.curves().to_native(), curves);
+
+        // Calculate the layout and the curve transform, so the keys could be picked by the mouse.
+        ui.update(screen_size, 0.0, &Default::default());
+        while ui.poll_message().is_some() {}
+        ui.draw();
+
+        // Select the key of a single channel and change its value via the editor.
+        let key = &curves[1].keys[0];
+        let pos = ui
+            .node(editor)
+            .cast::<CurveEditor>()
+            .unwrap()
+            .point_to_screen_space(Vector2::new(key.location, key.value));
+        ui.send(
+            editor,
+            WidgetMessage::MouseDown {
+                pos,
+                button: MouseButton::Left,
+            },
+        );
+        ui.send(editor, CurveEditorMessage::ChangeSelectedKeysValue(10.0));
+
+        let mut property_changed = None;
+        while let Some(message) = ui.poll_message() {
+            if message.destination() == editor
+                && message.direction() == MessageDirection::FromWidget
+            {
+                if let Some(changed) = MultiCurvePropertyEditorDefinition.translate_message(
+                    PropertyEditorTranslationContext {
+                        environment: None,
+                        name: "curves",
+                        message: &message,
+                        definition_container: Arc::new(PropertyEditorDefinitionContainer::empty()),
+                    },
+                ) {
+                    property_changed = Some(changed);
+                }
+            }
+        }
+        let property_changed = property_changed.unwrap();
+        assert_eq!(property_changed.name, "curves");
+
+        // Only the edited channel must be changed in the result.
+        let FieldKind::Object(value) = property_changed.value else {
+            unreachable!()
+        };
+        let mut result = None;
+        value.cast_clone::<Vec<Curve>>(&mut |curves| result = curves);
+        let result = result.unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], curves[0]);
+        assert_eq!(result[1].keys[0].value, 10.0);
+        assert_eq!(result[1].id, curves[1].id);
+        assert_eq!(result[2], curves[2]);
+    }
+}
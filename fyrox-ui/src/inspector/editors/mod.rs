@@ -33,7 +33,7 @@ use crate::{
         color::Color,
         color_gradient::ColorGradient,
         math::{curve::Curve, Rect, SmoothAngle},
-        parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+        parking_lot::{MappedRwLockReadGuard, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
         pool::Handle,
         reflect::prelude::*,
         sstorage::ImmutableString,
@@ -56,7 +56,7 @@ use crate::{
             char::CharPropertyEditorDefinition,
             collection::{CollectionItem, VecCollectionPropertyEditorDefinition},
             color::{ColorGradientPropertyEditorDefinition, ColorPropertyEditorDefinition},
-            curve::CurvePropertyEditorDefinition,
+            curve::{CurvePropertyEditorDefinition, MultiCurvePropertyEditorDefinition},
             enumeration::{EnumPropertyEditorDefinition, InspectableEnum},
             immutable_string::ImmutableStringPropertyEditorDefinition,
             inherit::InheritablePropertyEditorDefinition,
@@ -554,6 +554,8 @@ impl PropertyEditorDefinitionContainer {
         // Curve
         container.insert(CurvePropertyEditorDefinition);
         container.insert(InheritablePropertyEditorDefinition::<Curve>::new());
+        container.insert(MultiCurvePropertyEditorDefinition);
+        container.insert(InheritablePropertyEditorDefinition::<Vec<Curve>>::new());
 
         // UI
         container.register_inheritable_styleable_enum::<Brush, _>();
@@ -901,6 +903,21 @@ impl PropertyEditorDefinitionContainer {
             .is_none());
     }
 
+    /// Tries to find a property editor definition for the given type id. The definitions are stored
+    /// in a hash map, so the lookup is O(1). It could be used by custom property editors to delegate
+    /// editing of some parts of a value to the built-in editors.
+    pub fn find_definition(
+        &self,
+        type_id: TypeId,
+    ) -> Option<MappedRwLockReadGuard<dyn PropertyEditorDefinition>> {
+        RwLockReadGuard::try_map(self.definitions.read(), |definitions| {
+            definitions
+                .get(&type_id)
+                .map(|entry| &*entry.property_editor)
+        })
+        .ok()
+    }
+
     /// Direct read-only access to all the editor definitions.
     pub fn definitions(
         &self,
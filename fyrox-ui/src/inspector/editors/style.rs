@@ -515,6 +515,7 @@ where
             let proxy_property_info = FieldRef {
                 metadata: &FieldMetadata {
                     name: property_info.name,
+                    aliases: property_info.aliases,
                     display_name: property_info.display_name,
                     read_only: property_info.read_only,
                     immutable_collection: property_info.immutable_collection,
@@ -600,6 +601,7 @@ where
             let proxy_property_info = FieldRef {
                 metadata: &FieldMetadata {
                     name: property_info.name,
+                    aliases: property_info.aliases,
                     display_name: property_info.display_name,
                     read_only: property_info.read_only,
                     immutable_collection: property_info.immutable_collection,
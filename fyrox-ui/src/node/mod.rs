@@ -299,6 +299,10 @@ impl Reflect for UiNode {
         self.0.deref().doc()
     }
 
+    fn type_source_path(&self) -> &'static str {
+        self.0.deref().type_source_path()
+    }
+
     fn assembly_name(&self) -> &'static str {
         self.0.deref().assembly_name()
     }
@@ -311,6 +315,10 @@ impl Reflect for UiNode {
         self.0.deref().fields_ref(func)
     }
 
+    fn field_count(&self) -> usize {
+        self.0.deref().field_count()
+    }
+
     fn fields_mut(&mut self, func: &mut dyn FnMut(&mut [FieldMut])) {
         self.0.deref_mut().fields_mut(func)
     }
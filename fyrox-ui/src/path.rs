@@ -27,18 +27,22 @@ use crate::message::MessageData;
 use crate::{
     button::{ButtonBuilder, ButtonMessage},
     core::{pool::Handle, reflect::prelude::*, type_traits::prelude::*, visitor::prelude::*},
-    file_browser::{FileSelectorBuilder, FileSelectorMessage},
+    file_browser::{FileSelectorBuilder, FileSelectorMessage, Filter},
     grid::{Column, GridBuilder, Row},
-    message::UiMessage,
+    message::{MessageDirection, UiMessage},
+    style::{resource::StyleResourceExt, Style},
     text::TextMessage,
-    text_box::TextBoxBuilder,
+    text_box::{TextBoxBuilder, TextCommitMode},
     widget::{Widget, WidgetBuilder, WidgetMessage},
     window::{WindowBuilder, WindowMessage, WindowTitle},
     BuildContext, Control, Thickness, UiNode, UserInterface,
 };
-use fyrox_core::uuid_provider;
 use fyrox_core::variable::InheritableVariable;
-use fyrox_graph::constructor::{ConstructorProvider, GraphNodeConstructor};
+use fyrox_core::{log::Log, make_relative_path_to, uuid_provider};
+use fyrox_graph::{
+    constructor::{ConstructorProvider, GraphNodeConstructor},
+    BaseSceneGraph,
+};
 use std::{
     ops::{Deref, DerefMut},
     path::Path,
@@ -50,9 +54,32 @@ use std::{
 pub enum PathEditorMessage {
     /// A message, that is used to set new value of the editor or to receive changes from the editor.
     Path(PathBuf),
+    /// A message, that is used to set a new filter of the file selector of the editor. The filter
+    /// is applied to the current file selector (if any) and to every new one.
+    Filter(Option<Filter>),
+    /// A message, that is sent by the editor when a new path did not pass the validation (see
+    /// [`PathEditorBuilder::with_existence_check`] and [`PathEditorBuilder::with_kind`]). The path
+    /// is still accepted by the editor, but its text field is highlighted with the error brush.
+    ValidationFailed(PathBuf),
+    /// A message, that is sent by the editor when its file selector was closed without selecting a
+    /// path (either by the "Cancel" button or by the close button of the window). The path of the
+    /// editor stays the same. Could also be sent to the editor to close its file selector.
+    SelectionCancelled,
 }
 impl MessageData for PathEditorMessage {}
 
+/// Defines which kind of path is considered valid by the [`PathEditor`].
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Visit, Reflect)]
+pub enum PathKind {
+    /// Any path is valid.
+    #[default]
+    Any,
+    /// Only paths to existing files are valid.
+    File,
+    /// Only paths to existing directories are valid.
+    Directory,
+}
+
 /// Path editor is a simple widget that has a text box, that shows the current path and a "..." button, that opens a file
 /// selector.
 ///
@@ -75,6 +102,51 @@ impl MessageData for PathEditorMessage {}
 ///
 /// To receive the changes, listen to [`PathEditorMessage::Path`] and check for its direction, it should be [`MessageDirection::FromWidget`].
 /// To set a new path value, send [`PathEditorMessage::Path`] message, but with [`MessageDirection::ToWidget`].
+///
+/// ## Validation
+///
+/// By default, the editor accepts any path. Use [`PathEditorBuilder::with_existence_check`] and/or
+/// [`PathEditorBuilder::with_kind`] to enable validation. When a new path does not pass the validation,
+/// the text field of the editor is highlighted with the error brush and the editor sends
+/// [`PathEditorMessage::ValidationFailed`] message.
+///
+/// When the file selector is cancelled, the editor closes it and sends
+/// [`PathEditorMessage::SelectionCancelled`] message.
+///
+/// ## Base directory
+///
+/// Use [`PathEditorBuilder::with_base_dir`] to make the editor store and emit paths relative to some
+/// directory (for example, the root directory of a project). Absolute paths (for example, the ones
+/// selected in the file selector) are converted to relative, absolute paths outside of the base
+/// directory are kept absolute. Relative paths are resolved against the base directory when the
+/// file selector is opened or when the path is validated.
+///
+/// ## Manual entry
+///
+/// By default, the text field of the editor is read-only and a path can only be selected using the file
+/// selector. Use [`PathEditorBuilder::with_manual_entry`] to allow users to type (or paste) a path
+/// directly. Typed path is committed when the text field loses focus or when Enter is pressed, it is
+/// then handled exactly the same as a path selected in the file selector.
+///
+/// ## Filtering
+///
+/// It is possible to restrict the set of files, that can be selected in the file selector, by using
+/// [`PathEditorBuilder::with_filter`] or [`PathEditorMessage::Filter`]:
+///
+/// ```rust
+/// # use fyrox_ui::{
+/// #     core::pool::Handle, file_browser::Filter, path::PathEditorBuilder, widget::WidgetBuilder,
+/// #     BuildContext, UiNode,
+/// # };
+/// #
+/// fn create_scene_path_editor(ctx: &mut BuildContext) -> Handle<UiNode> {
+///     PathEditorBuilder::new(WidgetBuilder::new())
+///         .with_filter(Filter::new(|path| {
+///             path.is_dir() || path.extension().is_some_and(|ext| ext == "rgs")
+///         }))
+///         .build(ctx)
+/// }
+/// ```
 #[derive(Default, Clone, Visit, Reflect, Debug, ComponentProvider)]
 #[reflect(derived_type = "UiNode")]
 pub struct PathEditor {
@@ -88,6 +160,68 @@ pub struct PathEditor {
     pub selector: InheritableVariable<Handle<UiNode>>,
     /// Current path.
     pub path: InheritableVariable<PathBuf>,
+    /// Current filter of the file selector.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    pub filter: Option<Filter>,
+    /// If `true`, then the editor checks whether the path exists or not.
+    #[visit(optional)]
+    pub existence_check: InheritableVariable<bool>,
+    /// Kind of paths, that are considered valid.
+    #[visit(optional)]
+    pub kind: InheritableVariable<PathKind>,
+    /// Optional base directory. If set, the editor stores and emits paths relative to this directory.
+    #[visit(optional)]
+    pub base_dir: InheritableVariable<Option<PathBuf>>,
+}
+
+impl PathEditor {
+    /// Checks whether the given path passes the validation rules of the editor. Relative paths are
+    /// resolved against the base directory (if any).
+    pub fn is_valid_path(&self, path: &Path) -> bool {
+        is_valid_path(
+            &resolve_path(self.base_dir.as_deref(), path),
+            *self.existence_check,
+            *self.kind,
+        )
+    }
+}
+
+fn resolve_path(base_dir: Option<&Path>, path: &Path) -> PathBuf {
+    match base_dir {
+        Some(base_dir) => base_dir.join(path),
+        None => path.to_path_buf(),
+    }
+}
+
+fn relative_to_base_dir(base_dir: Option<&Path>, path: &Path) -> PathBuf {
+    match base_dir {
+        Some(base_dir) if path.is_absolute() => match make_relative_path_to(path, base_dir) {
+            Ok(relative_path) => relative_path,
+            Err(err) => {
+                Log::warn(format!(
+                    "Unable to make {} path relative to {} base directory, the path will be \
+                    kept absolute. Reason: {err}",
+                    path.display(),
+                    base_dir.display()
+                ));
+                path.to_path_buf()
+            }
+        },
+        _ => path.to_path_buf(),
+    }
+}
+
+fn is_valid_path(path: &Path, existence_check: bool, kind: PathKind) -> bool {
+    if existence_check && !path.exists() {
+        return false;
+    }
+
+    match kind {
+        PathKind::Any => true,
+        PathKind::File => path.is_file(),
+        PathKind::Directory => path.is_dir(),
+    }
 }
 
 impl ConstructorProvider<UiNode, UserInterface> for PathEditor {
@@ -120,12 +254,13 @@ impl Control for PathEditor {
                         .open(false)
                         .with_title(WindowTitle::text("Select a Path")),
                     )
+                    .with_opt_filter(self.filter.clone())
                     .build(&mut ui.build_ctx()),
                 );
 
                 ui.send(
                     *self.selector,
-                    FileSelectorMessage::Path((*self.path).clone()),
+                    FileSelectorMessage::Path(resolve_path(self.base_dir.as_deref(), &self.path)),
                 );
                 ui.send(
                     *self.selector,
@@ -136,7 +271,21 @@ impl Control for PathEditor {
                 );
                 ui.send(*self.selector, FileSelectorMessage::FocusCurrentPath);
             }
+        } else if let Some(TextMessage::Text(text)) = message.data() {
+            if message.destination() == *self.text_field
+                && message.direction() == MessageDirection::FromWidget
+                && Path::new(text.as_str()) != *self.path
+            {
+                // The text field is editable only when manual entry is enabled.
+                ui.send(self.handle, PathEditorMessage::Path(text.into()));
+            }
         } else if let Some(PathEditorMessage::Path(path)) = message.data_for(self.handle) {
+            // The selector is removed in `preview_message` on commit, forget its handle.
+            if self.selector.is_some() && ui.try_get_node(*self.selector).is_none() {
+                self.selector.set_value_and_mark_modified(Handle::NONE);
+            }
+
+            let path = &relative_to_base_dir(self.base_dir.as_deref(), path);
             if &*self.path != path {
                 self.path.set_value_and_mark_modified(path.clone());
 
@@ -144,6 +293,43 @@ impl Control for PathEditor {
                     *self.text_field,
                     TextMessage::Text(path.to_string_lossy().to_string()),
                 );
+
+                let is_valid = self.is_valid_path(path);
+                let brush = if is_valid {
+                    Style::BRUSH_TEXT
+                } else {
+                    Style::BRUSH_ERROR
+                };
+                ui.send(
+                    *self.text_field,
+                    WidgetMessage::Foreground(ui.style().property(brush)),
+                );
+
+                ui.send_message(UiMessage::from_widget(
+                    self.handle,
+                    PathEditorMessage::Path(path.clone()),
+                ));
+
+                if !is_valid {
+                    ui.send_message(UiMessage::from_widget(
+                        self.handle,
+                        PathEditorMessage::ValidationFailed(path.clone()),
+                    ));
+                }
+            }
+        } else if let Some(PathEditorMessage::Filter(filter)) = message.data_for(self.handle) {
+            if &self.filter != filter {
+                self.filter.clone_from(filter);
+
+                if self.selector.is_some() {
+                    ui.send(*self.selector, FileSelectorMessage::Filter(filter.clone()));
+                }
+                ui.send_message(message.reverse());
+            }
+        } else if let Some(PathEditorMessage::SelectionCancelled) = message.data_for(self.handle) {
+            if self.selector.is_some() {
+                ui.send(*self.selector, WidgetMessage::Remove);
+                self.selector.set_value_and_mark_modified(Handle::NONE);
                 ui.send_message(message.reverse());
             }
         }
@@ -151,10 +337,18 @@ impl Control for PathEditor {
 
     fn preview_message(&self, ui: &UserInterface, message: &mut UiMessage) {
         if let Some(FileSelectorMessage::Commit(path)) = message.data() {
-            if message.destination() == *self.selector && &*self.path != path {
+            if message.destination() == *self.selector {
+                // The selector is removed before it receives its own close message, so the close
+                // message below is only handled when nothing was committed.
                 ui.send(*self.selector, WidgetMessage::Remove);
                 ui.send(self.handle, PathEditorMessage::Path(path.clone()));
             }
+        } else if let Some(WindowMessage::Close) = message.data() {
+            if message.destination() == *self.selector
+                && message.direction() == MessageDirection::ToWidget
+            {
+                ui.send(self.handle, PathEditorMessage::SelectionCancelled);
+            }
         }
     }
 }
@@ -163,6 +357,11 @@ impl Control for PathEditor {
 pub struct PathEditorBuilder {
     widget_builder: WidgetBuilder,
     path: PathBuf,
+    filter: Option<Filter>,
+    existence_check: bool,
+    kind: PathKind,
+    manual_entry: bool,
+    base_dir: Option<PathBuf>,
 }
 
 impl PathEditorBuilder {
@@ -171,6 +370,11 @@ impl PathEditorBuilder {
         Self {
             widget_builder,
             path: Default::default(),
+            filter: None,
+            existence_check: false,
+            kind: PathKind::Any,
+            manual_entry: false,
+            base_dir: None,
         }
     }
 
@@ -180,21 +384,64 @@ impl PathEditorBuilder {
         self
     }
 
+    /// Sets the desired filter of the file selector, that is used to select a path. Only the paths,
+    /// that pass the filter, will be shown in the selector.
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Enables or disables the check for path existence. If enabled, the editor marks nonexistent
+    /// paths as invalid. Disabled by default.
+    pub fn with_existence_check(mut self, existence_check: bool) -> Self {
+        self.existence_check = existence_check;
+        self
+    }
+
+    /// Sets the kind of paths, that are considered valid. Default is [`PathKind::Any`].
+    pub fn with_kind(mut self, kind: PathKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Allows or disallows manual path entry in the text field of the editor. Disabled by default.
+    pub fn with_manual_entry(mut self, manual_entry: bool) -> Self {
+        self.manual_entry = manual_entry;
+        self
+    }
+
+    /// Sets the base directory of the editor. If set, the editor stores and emits paths relative to
+    /// this directory. See [`PathEditor`] docs for more info.
+    pub fn with_base_dir(mut self, base_dir: PathBuf) -> Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
     /// Finishes widget building and adds it to the user interface returning a handle to the instance.
-    pub fn build(self, ctx: &mut BuildContext) -> Handle<UiNode> {
+    pub fn build(mut self, ctx: &mut BuildContext) -> Handle<UiNode> {
+        self.path = relative_to_base_dir(self.base_dir.as_deref(), &self.path);
+
         let text_field;
         let select;
         let grid = GridBuilder::new(
             WidgetBuilder::new()
                 .with_child({
-                    text_field = TextBoxBuilder::new(
-                        WidgetBuilder::new()
-                            .on_column(0)
-                            .with_margin(Thickness::uniform(1.0)),
-                    )
-                    .with_text(self.path.to_string_lossy())
-                    .with_editable(false)
-                    .build(ctx);
+                    let mut text_field_builder = WidgetBuilder::new()
+                        .on_column(0)
+                        .with_margin(Thickness::uniform(1.0));
+                    if !is_valid_path(
+                        &resolve_path(self.base_dir.as_deref(), &self.path),
+                        self.existence_check,
+                        self.kind,
+                    ) {
+                        text_field_builder = text_field_builder
+                            .with_foreground(ctx.style.property(Style::BRUSH_ERROR));
+                    }
+                    text_field = TextBoxBuilder::new(text_field_builder)
+                        .with_text(self.path.to_string_lossy())
+                        .with_editable(self.manual_entry)
+                        .with_text_commit_mode(TextCommitMode::LostFocusPlusEnter)
+                        .build(ctx);
                     text_field
                 })
                 .with_child({
@@ -224,6 +471,10 @@ impl PathEditorBuilder {
             select: select.into(),
             selector: Default::default(),
             path: self.path.into(),
+            filter: self.filter,
+            existence_check: self.existence_check.into(),
+            kind: self.kind.into(),
+            base_dir: self.base_dir.into(),
         };
         ctx.add_node(UiNode::new(canvas))
     }
@@ -231,11 +482,210 @@ impl PathEditorBuilder {
 
 #[cfg(test)]
 mod test {
-    use crate::path::PathEditorBuilder;
-    use crate::{test::test_widget_deletion, widget::WidgetBuilder};
+    use crate::path::{is_valid_path, PathEditor, PathEditorBuilder, PathEditorMessage, PathKind};
+    use crate::{
+        button::ButtonMessage,
+        core::{algebra::Vector2, pool::Handle},
+        file_browser::{FileBrowser, FileSelector, FileSelectorMessage, Filter},
+        message::{ButtonState, KeyCode, MessageDirection, UiMessage},
+        test::test_widget_deletion,
+        widget::{WidgetBuilder, WidgetMessage},
+        window::WindowMessage,
+        OsEvent, UiNode, UserInterface,
+    };
+    use fyrox_graph::BaseSceneGraph;
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn test_deletion() {
         test_widget_deletion(|ctx| PathEditorBuilder::new(WidgetBuilder::new()).build(ctx));
     }
+
+    #[test]
+    fn test_validation() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let editor = PathEditorBuilder::new(WidgetBuilder::new())
+            .with_existence_check(true)
+            .with_kind(PathKind::File)
+            .build(&mut ui.build_ctx());
+
+        let path = PathBuf::from("this/path/does/not/exist.rgs");
+        ui.send(editor, PathEditorMessage::Path(path.clone()));
+
+        let mut validation_failed = false;
+        while let Some(message) = ui.poll_message() {
+            if let Some(PathEditorMessage::ValidationFailed(failed_path)) = message.data() {
+                assert_eq!(message.destination(), editor);
+                assert_eq!(message.direction(), MessageDirection::FromWidget);
+                assert_eq!(failed_path, &path);
+                validation_failed = true;
+            }
+        }
+        assert!(validation_failed);
+
+        // The path is accepted anyway.
+        let path_editor = ui.node(editor).cast::<PathEditor>().unwrap();
+        assert_eq!(*path_editor.path, path);
+        assert!(!path_editor.is_valid_path(Path::new(".")));
+        assert!(is_valid_path(Path::new("."), true, PathKind::Directory));
+    }
+
+    #[test]
+    fn test_manual_entry() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let editor = PathEditorBuilder::new(WidgetBuilder::new())
+            .with_manual_entry(true)
+            .build(&mut ui.build_ctx());
+
+        let text_field = *ui.node(editor).cast::<PathEditor>().unwrap().text_field;
+        ui.send(text_field, WidgetMessage::Focus);
+        while ui.poll_message().is_some() {}
+
+        for (button, text) in [(KeyCode::KeyA, "a"), (KeyCode::KeyB, "b")] {
+            ui.process_os_event(&OsEvent::KeyboardInput {
+                button,
+                state: ButtonState::Pressed,
+                text: text.to_string(),
+            });
+            while ui.poll_message().is_some() {}
+        }
+
+        ui.process_os_event(&OsEvent::KeyboardInput {
+            button: KeyCode::Enter,
+            state: ButtonState::Pressed,
+            text: Default::default(),
+        });
+
+        let mut committed = false;
+        while let Some(message) = ui.poll_message() {
+            if let Some(PathEditorMessage::Path(path)) = message.data() {
+                if message.direction() == MessageDirection::FromWidget {
+                    assert_eq!(message.destination(), editor);
+                    assert_eq!(path, Path::new("ab"));
+                    committed = true;
+                }
+            }
+        }
+        assert!(committed);
+        assert_eq!(
+            *ui.node(editor).cast::<PathEditor>().unwrap().path,
+            PathBuf::from("ab")
+        );
+    }
+
+    #[test]
+    fn test_base_dir() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let editor = PathEditorBuilder::new(WidgetBuilder::new())
+            .with_base_dir("src".into())
+            .with_existence_check(true)
+            .build(&mut ui.build_ctx());
+
+        let mut send_path = |path: PathBuf| {
+            ui.send(editor, PathEditorMessage::Path(path));
+            let mut emitted = None;
+            while let Some(message) = ui.poll_message() {
+                if let Some(PathEditorMessage::Path(path)) = message.data() {
+                    if message.direction() == MessageDirection::FromWidget {
+                        emitted = Some(path.clone());
+                    }
+                }
+            }
+            emitted
+        };
+
+        // Absolute path inside the base directory is converted to relative.
+        let inside = Path::new("src/path.rs").canonicalize().unwrap();
+        assert_eq!(send_path(inside), Some(PathBuf::from("path.rs")));
+
+        // Absolute path outside the base directory is kept as is.
+        let outside = Path::new("Cargo.toml").canonicalize().unwrap();
+        assert_eq!(send_path(outside.clone()), Some(outside));
+
+        // Relative paths are resolved against the base directory.
+        assert_eq!(send_path("lib.rs".into()), Some(PathBuf::from("lib.rs")));
+        let path_editor = ui.node(editor).cast::<PathEditor>().unwrap();
+        assert!(path_editor.is_valid_path(Path::new("lib.rs")));
+        assert!(!path_editor.is_valid_path(Path::new("Cargo.toml")));
+    }
+
+    #[test]
+    fn test_filter_forwarding() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let filter = Filter::new(|path| path.extension().is_some_and(|ext| ext == "rgs"));
+        let editor = PathEditorBuilder::new(WidgetBuilder::new())
+            .with_filter(filter.clone())
+            .build(&mut ui.build_ctx());
+
+        let select = *ui.node(editor).cast::<PathEditor>().unwrap().select;
+        ui.send(select, ButtonMessage::Click);
+        while ui.poll_message().is_some() {}
+
+        let path_editor = ui.node(editor).cast::<PathEditor>().unwrap();
+        assert_eq!(path_editor.filter, Some(filter.clone()));
+        let selector = ui
+            .node(*path_editor.selector)
+            .cast::<FileSelector>()
+            .unwrap();
+        let browser = ui.node(selector.browser).cast::<FileBrowser>().unwrap();
+        assert_eq!(browser.filter, Some(filter));
+    }
+
+    #[test]
+    fn test_selection_cancelled() {
+        let mut ui = UserInterface::new(Vector2::new(100.0, 100.0));
+        let editor = PathEditorBuilder::new(WidgetBuilder::new())
+            .with_path("foo.rgs")
+            .build(&mut ui.build_ctx());
+
+        // Opens the selector, closes it with the given message and returns whether the selection
+        // was cancelled along with the resulting path.
+        let mut close_selector = |close: &dyn Fn(Handle<UiNode>) -> UiMessage| {
+            let select = *ui.node(editor).cast::<PathEditor>().unwrap().select;
+            ui.send(select, ButtonMessage::Click);
+            while ui.poll_message().is_some() {}
+
+            let selector = *ui.node(editor).cast::<PathEditor>().unwrap().selector;
+            assert!(selector.is_some());
+            ui.send_message(close(selector));
+
+            let mut cancelled = false;
+            while let Some(message) = ui.poll_message() {
+                if let Some(PathEditorMessage::SelectionCancelled) = message.data() {
+                    if message.direction() == MessageDirection::FromWidget {
+                        assert_eq!(message.destination(), editor);
+                        cancelled = true;
+                    }
+                }
+            }
+            assert!(ui.try_get_node(selector).is_none());
+            let path_editor = ui.node(editor).cast::<PathEditor>().unwrap();
+            assert!(path_editor.selector.is_none());
+            (cancelled, path_editor.path.clone_inner())
+        };
+
+        let foo = PathBuf::from("foo.rgs");
+
+        // "Cancel" button.
+        assert_eq!(
+            close_selector(&|selector| UiMessage::for_widget(
+                selector,
+                FileSelectorMessage::Cancel
+            )),
+            (true, foo.clone())
+        );
+        // Close button of the window.
+        assert_eq!(
+            close_selector(&|selector| UiMessage::for_widget(selector, WindowMessage::Close)),
+            (true, foo)
+        );
+        // Committed selection is not cancelled.
+        assert_eq!(
+            close_selector(&|selector| UiMessage::for_widget(
+                selector,
+                FileSelectorMessage::Commit("bar.rgs".into())
+            )),
+            (false, PathBuf::from("bar.rgs"))
+        );
+    }
 }